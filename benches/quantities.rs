@@ -0,0 +1,150 @@
+//! Benchmarks of common hot paths, comparing `tiny-uom` with `uom` and raw `f32`.
+//!
+//! Run them using `cargo bench`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use tiny_uom::registry::UnitRegistry;
+use tiny_uom::values::{m, s};
+use tiny_uom::Quantity;
+use uom::si::f32 as uom_si;
+use uom::si::{length::meter, time::second, velocity::kilometer_per_hour, velocity::mile_per_hour};
+
+type Length = Quantity<1, 0, 0, 0, 0, 0, 0>;
+type Velocity = Quantity<1, 0, -1, 0, 0, 0, 0>;
+type Acceleration = Quantity<1, 0, -2, 0, 0, 0, 0>;
+
+const N: usize = 1024;
+
+#[allow(clippy::cast_precision_loss)]
+fn values() -> Vec<f32> {
+    (0..N).map(|i| i as f32 * 0.25).collect()
+}
+
+fn sum(c: &mut Criterion) {
+    let raw = values();
+    let tiny = raw.iter().map(|&x| x * m).collect::<Vec<Length>>();
+    let uom = raw
+        .iter()
+        .map(|&x| uom_si::Length::new::<meter>(x))
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("sum");
+    group.bench_function("f32", |b| {
+        b.iter(|| black_box(&raw).iter().fold(0.0, |sum, &x| sum + x));
+    });
+    group.bench_function("tiny-uom", |b| {
+        b.iter(|| black_box(&tiny).iter().fold(0.0 * m, |sum, &x| sum + x));
+    });
+    group.bench_function("uom", |b| {
+        b.iter(|| {
+            black_box(&uom)
+                .iter()
+                .fold(uom_si::Length::new::<meter>(0.0), |sum, &x| sum + x)
+        });
+    });
+    group.finish();
+}
+
+/// One explicit Euler step of bodies in one dimension, pulled toward the origin
+/// with an acceleration of `k^2 * x`.
+fn step(c: &mut Criterion) {
+    let raw = values();
+    let dt = 0.01;
+
+    let mut group = c.benchmark_group("step");
+    group.bench_function("f32", |b| {
+        let mut position = raw.clone();
+        let mut velocity = raw.clone();
+        let k2 = black_box(1.0);
+        b.iter(|| {
+            for (x, v) in position.iter_mut().zip(&mut velocity) {
+                *v -= *x * k2 * dt;
+                *x += *v * dt;
+            }
+            black_box(&position);
+        });
+    });
+    group.bench_function("tiny-uom", |b| {
+        let mut position = raw.iter().map(|&x| x * m).collect::<Vec<Length>>();
+        let mut velocity = raw.iter().map(|&x| x * (m / s)).collect::<Vec<Velocity>>();
+        let k2 = black_box(Quantity::<0, 0, -2, 0, 0, 0, 0>::new(1.0));
+        let dt = dt * s;
+        b.iter(|| {
+            for (x, v) in position.iter_mut().zip(&mut velocity) {
+                let acceleration: Acceleration = x.mul_quantity(k2);
+                *v -= acceleration.mul_quantity::<_, Velocity>(dt);
+                *x += v.mul_quantity::<_, Length>(dt);
+            }
+            black_box(&position);
+        });
+    });
+    group.bench_function("uom", |b| {
+        let mut position = raw
+            .iter()
+            .map(|&x| uom_si::Length::new::<meter>(x))
+            .collect::<Vec<_>>();
+        let mut velocity = raw
+            .iter()
+            .map(|&x| uom_si::Velocity::new::<uom::si::velocity::meter_per_second>(x))
+            .collect::<Vec<_>>();
+        let k = uom_si::Frequency::new::<uom::si::frequency::hertz>(1.0);
+        let k2 = black_box(k * k);
+        let dt = uom_si::Time::new::<second>(dt);
+        b.iter(|| {
+            for (x, v) in position.iter_mut().zip(&mut velocity) {
+                *v -= *x * k2 * dt;
+                *x += *v * dt;
+            }
+            black_box(&position);
+        });
+    });
+    group.finish();
+}
+
+fn convert(c: &mut Criterion) {
+    let registry = UnitRegistry::builtin();
+
+    let mut group = c.benchmark_group("convert");
+    group.bench_function("f32", |b| {
+        b.iter(|| black_box(65.0_f32) * 1.609_344);
+    });
+    group.bench_function("tiny-uom", |b| {
+        b.iter(|| registry.convert(black_box(65.0), "mi/h", "km/h"));
+    });
+    group.bench_function("tiny-uom parse", |b| {
+        b.iter(|| black_box("65 mi/h").parse::<Velocity>());
+    });
+    group.bench_function("uom", |b| {
+        b.iter(|| {
+            uom_si::Velocity::new::<mile_per_hour>(black_box(65.0)).get::<kilometer_per_hour>()
+        });
+    });
+    group.finish();
+}
+
+fn format(c: &mut Criterion) {
+    let mut group = c.benchmark_group("format");
+    group.bench_function("f32", |b| {
+        b.iter(|| black_box(9.81_f32).to_string());
+    });
+    group.bench_function("tiny-uom", |b| {
+        b.iter(|| black_box(9.81 * (m / s)).to_string());
+    });
+    group.bench_function("uom", |b| {
+        let velocity = uom_si::Velocity::new::<uom::si::velocity::meter_per_second>(9.81);
+        b.iter(|| {
+            format!(
+                "{}",
+                black_box(velocity).into_format_args(
+                    uom::si::velocity::meter_per_second,
+                    uom::fmt::DisplayStyle::Abbreviation
+                )
+            )
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, sum, step, convert, format);
+criterion_main!(benches);
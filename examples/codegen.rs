@@ -0,0 +1,88 @@
+//! Pairs of functions doing the same arithmetic on quantities and on raw `f32`.
+//!
+//! `tests/codegen.rs` compiles this example to LLVM IR and checks that every
+//! `quantity_*` function compiles to the same code as its `f32_*` twin.
+#![allow(clippy::missing_panics_doc, clippy::must_use_candidate)]
+
+use tiny_uom::values::m;
+use tiny_uom::Quantity;
+
+type Length = Quantity<1, 0, 0, 0, 0, 0, 0>;
+type Time = Quantity<0, 0, 1, 0, 0, 0, 0>;
+type Velocity = Quantity<1, 0, -1, 0, 0, 0, 0>;
+
+#[no_mangle]
+pub fn quantity_add(a: Length, b: Length) -> Length {
+    a + b
+}
+
+#[no_mangle]
+pub fn f32_add(a: f32, b: f32) -> f32 {
+    a + b
+}
+
+#[no_mangle]
+pub fn quantity_sub_assign(a: &mut Length, b: Length) {
+    *a -= b;
+}
+
+#[no_mangle]
+pub fn f32_sub_assign(a: &mut f32, b: f32) {
+    *a -= b;
+}
+
+#[no_mangle]
+pub fn quantity_scale(a: Length, b: f32) -> Length {
+    -(b * a / 2.0)
+}
+
+#[no_mangle]
+pub fn f32_scale(a: f32, b: f32) -> f32 {
+    -(b * a / 2.0)
+}
+
+#[no_mangle]
+pub fn quantity_velocity(a: Length, b: Time) -> Velocity {
+    a / b
+}
+
+#[no_mangle]
+pub fn f32_velocity(a: f32, b: f32) -> f32 {
+    a / b
+}
+
+#[no_mangle]
+pub fn quantity_sum(values: &[Length]) -> Length {
+    values.iter().fold(0.0 * m, |sum, &x| sum + x)
+}
+
+#[no_mangle]
+pub fn f32_sum(values: &[f32]) -> f32 {
+    values.iter().fold(0.0, |sum, &x| sum + x)
+}
+
+#[no_mangle]
+pub fn quantity_lerp(a: Length, b: Length, t: f32) -> Length {
+    a + (b - a) * t
+}
+
+#[no_mangle]
+pub fn f32_lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+#[no_mangle]
+pub fn quantity_speeds(lengths: &[Length], time: Time, out: &mut [Velocity]) {
+    for (v, &x) in out.iter_mut().zip(lengths) {
+        *v = x / time;
+    }
+}
+
+#[no_mangle]
+pub fn f32_speeds(lengths: &[f32], time: f32, out: &mut [f32]) {
+    for (v, &x) in out.iter_mut().zip(lengths) {
+        *v = x / time;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+//! Convert values between arbitrary unit expressions, which must only succeed if their dimensions match.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tiny_uom::registry::UnitRegistry;
+
+fuzz_target!(|input: (f64, &str, &str)| {
+    let (value, from, to) = input;
+    let registry = UnitRegistry::builtin();
+    if registry.convert(value, from, to).is_ok() {
+        let (_, from_unit) = registry.parse_unit(from).unwrap();
+        let (_, to_unit) = registry.parse_unit(to).unwrap();
+        assert_eq!(from_unit, to_unit, "`{from}` was converted to `{to}`");
+    }
+});
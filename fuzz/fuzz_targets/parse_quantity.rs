@@ -0,0 +1,19 @@
+//! Parse arbitrary strings as quantities, which must only succeed if the dimension matches.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tiny_uom::registry::UnitRegistry;
+use tiny_uom::Quantity;
+
+type Velocity = Quantity<1, 0, -1, 0, 0, 0, 0>;
+
+fuzz_target!(|src: &str| {
+    if src.parse::<Velocity>().is_ok() {
+        let src = src.trim();
+        let unit = src
+            .split_once(char::is_whitespace)
+            .map_or("1", |(_, unit)| unit);
+        let (_, parsed) = UnitRegistry::builtin().parse_unit(unit).unwrap();
+        assert_eq!(parsed, Velocity::UNIT, "`{src}` was parsed as a velocity");
+    }
+});
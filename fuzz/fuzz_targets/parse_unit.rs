@@ -0,0 +1,14 @@
+//! Parse arbitrary unit expressions, and check that formatting the parsed unit parses back to it.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tiny_uom::registry::UnitRegistry;
+
+fuzz_target!(|expr: &str| {
+    let registry = UnitRegistry::builtin();
+    if let Ok((_, unit)) = registry.parse_unit(expr) {
+        let (factor, parsed) = registry.parse_unit(&unit.to_string()).unwrap();
+        assert_eq!(parsed, unit, "`{expr}` was formatted as `{unit}`");
+        assert_eq!(factor, 1.0);
+    }
+});
@@ -0,0 +1,250 @@
+//! Procedural macros for [`tiny-uom`](https://docs.rs/tiny-uom).
+//!
+//! Use them through the `derive` feature of `tiny-uom`, which re-exports them.
+#![deny(
+    rust_2021_compatibility,
+    warnings,
+    clippy::pedantic,
+    missing_docs,
+    missing_debug_implementations,
+    rustdoc::broken_intra_doc_links,
+    unsafe_code
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitStr, Type};
+
+mod qty;
+
+/// A field of the struct and the unit it was annotated with.
+struct Field {
+    ident: Ident,
+    ty: Type,
+    unit: Option<LitStr>,
+    getter: Option<Ident>,
+}
+
+/// Derive unit-annotated constructors and getters for a struct of quantities.
+///
+/// See the documentation of the re-export in `tiny-uom` for details.
+#[proc_macro_derive(QuantityStruct, attributes(quantity))]
+pub fn derive_quantity_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Create a quantity from a literal with a unit, like `qty!(9.81 m/s^2)`.
+///
+/// See the documentation of the re-export in `tiny-uom` for details.
+#[proc_macro]
+pub fn qty(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as qty::Literal).expand().into()
+}
+
+/// Check at compile time that an expression has the dimension of a unit, like `assert_dim!(force, "kg*m/s^2")`.
+///
+/// See the documentation of the re-export in `tiny-uom` for details.
+#[proc_macro]
+pub fn assert_dim(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as qty::AssertDim).expand().into()
+}
+
+/// Turn a unit expression like `km/h` into a part of an identifier like `km_per_h`.
+fn unit_ident(unit: &LitStr) -> syn::Result<String> {
+    let mut out = String::new();
+    for c in unit.value().chars() {
+        match c {
+            '/' => out.push_str("_per_"),
+            '*' | '·' | ' ' => out.push('_'),
+            '^' | '(' | ')' => {}
+            'µ' | 'μ' => out.push('u'),
+            c if c.is_ascii_alphanumeric() || c == '_' => out.push(c),
+            _ => {
+                return Err(syn::Error::new(
+                    unit.span(),
+                    "unit can not be used in a getter name, set one using `getter = \"...\"`",
+                ))
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn parse_field(field: &syn::Field) -> syn::Result<Field> {
+    let ident = field
+        .ident
+        .clone()
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "expected a named field"))?;
+    let mut unit = None;
+    let mut getter = None;
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("quantity")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("unit") {
+                unit = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else if meta.path.is_ident("getter") {
+                let name = meta.value()?.parse::<LitStr>()?;
+                getter = Some(name.parse::<Ident>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `unit` or `getter`"))
+            }
+        })?;
+    }
+
+    let getter = match (&unit, getter) {
+        (_, Some(getter)) => Some(getter),
+        (Some(unit), None) => Some(format_ident!("{}_as_{}", ident, unit_ident(unit)?)),
+        (None, None) => None,
+    };
+    Ok(Field {
+        ident,
+        ty: field.ty.clone(),
+        unit,
+        getter,
+    })
+}
+
+/// The factor of the unit of a field, checked against the type of the field at compile time.
+fn unit_factor(ty: &Type, unit: &LitStr) -> TokenStream2 {
+    quote_spanned! {unit.span()=>
+        const {
+            ::tiny_uom::macro_support::ConstUnit::parse(#unit).factor_of(<#ty>::UNIT)
+        }
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`QuantityStruct` can only be derived for structs",
+        ));
+    };
+    let Fields::Named(named) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`QuantityStruct` requires named fields",
+        ));
+    };
+
+    let mut serde = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("quantity")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("serde") {
+                serde = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `serde`"))
+            }
+        })?;
+    }
+
+    let fields = named
+        .named
+        .iter()
+        .map(parse_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let params = fields.iter().map(|f| &f.ident);
+    let values = fields.iter().map(
+        |Field {
+             ident, ty, unit, ..
+         }| {
+            if let Some(unit) = unit {
+                let factor = unit_factor(ty, unit);
+                quote! { #ident: ::tiny_uom::macro_support::from_unit(#ident, #factor) }
+            } else {
+                quote! { #ident: <#ty>::new(#ident) }
+            }
+        },
+    );
+    let getters = fields.iter().filter_map(|f| {
+        let (ident, getter, unit) = (&f.ident, f.getter.as_ref()?, f.unit.as_ref()?);
+        let factor = unit_factor(&f.ty, unit);
+        let doc = format!("Returns `{ident}` in `{}`.", unit.value());
+        Some(quote! {
+            #[doc = #doc]
+            #[must_use]
+            #[allow(non_snake_case)]
+            #vis fn #getter(&self) -> f32 {
+                ::tiny_uom::macro_support::to_unit(self.#ident, #factor)
+            }
+        })
+    });
+    let constructor_doc = format!(
+        "Create a `{name}` from raw values in the units of its fields.\n\n\
+         Fields without a `#[quantity(unit = \"...\")]` attribute use SI units."
+    );
+
+    let serde = if serde {
+        expand_serde(input, &fields)?
+    } else {
+        TokenStream2::new()
+    };
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #constructor_doc]
+            #[must_use]
+            #vis fn from_units(#(#params: f32),*) -> Self {
+                Self { #(#values),* }
+            }
+
+            #(#getters)*
+        }
+
+        #serde
+    })
+}
+
+/// Implement `Serialize` and `Deserialize` using `tiny_uom::serde::with_unit` for every field.
+fn expand_serde(input: &DeriveInput, fields: &[Field]) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`#[quantity(serde)]` does not support generic structs",
+        ));
+    }
+
+    let name = &input.ident;
+    let name_str = name.to_string();
+    let idents = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+    let types = fields.iter().map(|f| &f.ty);
+
+    Ok(quote! {
+        const _: () = {
+            #[derive(::serde::Serialize, ::serde::Deserialize)]
+            #[serde(rename = #name_str)]
+            struct Repr {
+                #(
+                    #[serde(with = "::tiny_uom::serde::with_unit")]
+                    #idents: #types,
+                )*
+            }
+
+            impl ::serde::Serialize for #name {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                    let repr = Repr { #(#idents: self.#idents),* };
+                    ::serde::Serialize::serialize(&repr, serializer)
+                }
+            }
+
+            impl<'de> ::serde::Deserialize<'de> for #name {
+                fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                    let repr = <Repr as ::serde::Deserialize>::deserialize(deserializer)?;
+                    ::core::result::Result::Ok(Self { #(#idents: repr.#idents),* })
+                }
+            }
+        };
+    })
+}
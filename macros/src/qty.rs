@@ -0,0 +1,176 @@
+//! Parsing and expansion of the `qty!` and `assert_dim!` macros.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    Ident, Lit, LitFloat, LitInt, LitStr, Token,
+};
+
+/// A literal followed by a unit expression, like `9.81 m/s^2`.
+pub(crate) struct Literal {
+    negative: bool,
+    value: Lit,
+    unit: Option<Expr>,
+}
+
+/// An expression followed by the unit of its dimension as a string, like `force, "kg*m/s^2"`.
+pub(crate) struct AssertDim {
+    expr: syn::Expr,
+    unit: LitStr,
+}
+
+/// A unit expression made of symbols, products, quotients and integer powers.
+enum Expr {
+    Symbol(String),
+    One,
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, i8),
+}
+
+impl Parse for Literal {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let negative = input.parse::<Option<Token![-]>>()?.is_some();
+        let value = input.parse::<Lit>()?;
+        if !matches!(value, Lit::Int(_) | Lit::Float(_)) {
+            return Err(syn::Error::new_spanned(value, "expected a number"));
+        }
+        let unit = if input.is_empty() {
+            None
+        } else {
+            Some(parse_expr(input)?)
+        };
+        Ok(Self {
+            negative,
+            value,
+            unit,
+        })
+    }
+}
+
+impl Parse for AssertDim {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let unit = input.parse::<LitStr>()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { expr, unit })
+    }
+}
+
+fn parse_expr(input: ParseStream) -> syn::Result<Expr> {
+    let mut expr = parse_factor(input)?;
+    loop {
+        if input.parse::<Option<Token![*]>>()?.is_some() {
+            expr = Expr::Mul(Box::new(expr), Box::new(parse_factor(input)?));
+        } else if input.parse::<Option<Token![/]>>()?.is_some() {
+            expr = Expr::Div(Box::new(expr), Box::new(parse_factor(input)?));
+        } else if input.is_empty() {
+            return Ok(expr);
+        } else {
+            return Err(input.error("expected `*`, `/` or the end of the unit"));
+        }
+    }
+}
+
+fn parse_factor(input: ParseStream) -> syn::Result<Expr> {
+    let factor = if input.peek(syn::token::Paren) {
+        let inner;
+        parenthesized!(inner in input);
+        parse_expr(&inner)?
+    } else if input.peek(Token![%]) {
+        input.parse::<Token![%]>()?;
+        Expr::Symbol("%".to_string())
+    } else if input.peek(LitInt) {
+        let one = input.parse::<LitInt>()?;
+        if one.base10_digits() != "1" || !one.suffix().is_empty() {
+            return Err(syn::Error::new_spanned(one, "expected a unit or `1`"));
+        }
+        Expr::One
+    } else {
+        Expr::Symbol(Ident::parse_any(input)?.unraw().to_string())
+    };
+
+    if input.parse::<Option<Token![^]>>()?.is_none() {
+        return Ok(factor);
+    }
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    let exp = input.parse::<LitInt>()?;
+    let digits = format!("{}{}", if negative { "-" } else { "" }, exp.base10_digits());
+    let exp = digits
+        .parse::<i8>()
+        .map_err(|_| syn::Error::new_spanned(exp, "the exponent must fit into an `i8`"))?;
+    Ok(Expr::Pow(Box::new(factor), exp))
+}
+
+impl Expr {
+    fn expand(&self) -> TokenStream2 {
+        let unit = quote! { ::tiny_uom::macro_support::ConstUnit };
+        match self {
+            Expr::Symbol(symbol) => quote! { #unit::symbol(#symbol) },
+            Expr::One => quote! { #unit::ONE },
+            Expr::Mul(lhs, rhs) => {
+                let (lhs, rhs) = (lhs.expand(), rhs.expand());
+                quote! { #lhs.mul(#rhs) }
+            }
+            Expr::Div(lhs, rhs) => {
+                let (lhs, rhs) = (lhs.expand(), rhs.expand());
+                quote! { #lhs.div(#rhs) }
+            }
+            Expr::Pow(base, exp) => {
+                let base = base.expand();
+                quote! { #base.powi(#exp) }
+            }
+        }
+    }
+}
+
+/// Returns the type of `Quantity` with the exponents of the constant `UNIT`.
+fn quantity_type() -> TokenStream2 {
+    let exps = (0..7usize).map(|idx| quote! { { UNIT.exponents[#idx] } });
+    quote! { ::tiny_uom::Quantity<#(#exps),*> }
+}
+
+impl Literal {
+    pub(crate) fn expand(&self) -> TokenStream2 {
+        let unit = self
+            .unit
+            .as_ref()
+            .map_or_else(|| Expr::One.expand(), Expr::expand);
+        let digits = match &self.value {
+            Lit::Int(lit) => lit.base10_digits(),
+            Lit::Float(lit) => lit.base10_digits(),
+            _ => unreachable!("only numbers are parsed"),
+        };
+        let value = LitFloat::new(&format!("{digits}f64"), self.value.span());
+        let sign = self.negative.then(|| quote! { - });
+        let ty = quantity_type();
+
+        quote! {
+            {
+                const UNIT: ::tiny_uom::macro_support::ConstUnit = #unit;
+                const VALUE: f32 = UNIT.convert(#sign #value);
+                <#ty>::new(VALUE)
+            }
+        }
+    }
+}
+
+impl AssertDim {
+    pub(crate) fn expand(&self) -> TokenStream2 {
+        let (expr, unit) = (&self.expr, &self.unit);
+        let ty = quantity_type();
+
+        quote! {
+            {
+                const UNIT: ::tiny_uom::macro_support::ConstUnit =
+                    ::tiny_uom::macro_support::ConstUnit::parse(#unit);
+                let checked: #ty = #expr;
+                checked
+            }
+        }
+    }
+}
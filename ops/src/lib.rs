@@ -0,0 +1,128 @@
+//! Type aliases for the quantities of [`tiny-uom`] derived from other quantities.
+//!
+//! Instead of counting exponents by hand, the type of a derived quantity can
+//! be written in terms of the quantities it is made of:
+//!
+//! ```
+//! #![feature(generic_const_exprs)]
+//! #![allow(incomplete_features)]
+//! use tiny_uom::Quantity;
+//! use tiny_uom_ops::{Inverse, Prod, Ratio, Squared};
+//!
+//! type Length = Quantity<1, 0, 0, 0, 0, 0, 0>;
+//! type Time = Quantity<0, 0, 1, 0, 0, 0, 0>;
+//! type Mass = Quantity<0, 1, 0, 0, 0, 0, 0>;
+//!
+//! type Acceleration = Ratio<Length, Squared<Time>>;
+//! type Force = Prod<Mass, Acceleration>;
+//! type Stiffness = Ratio<Force, Length>;
+//! type Frequency = Inverse<Time>;
+//!
+//! let stiffness: Stiffness = Quantity::<0, 1, -2, 0, 0, 0, 0>::new(120.0);
+//! let frequency: Frequency = Quantity::<0, 0, -1, 0, 0, 0, 0>::new(50.0);
+//! assert_eq!(stiffness.raw(), 120.0);
+//! assert_eq!(frequency.raw(), 50.0);
+//! ```
+//!
+//! The aliases compute the exponents in constant expressions, which needs the
+//! unstable and incomplete `generic_const_exprs` feature of a nightly
+//! compiler. Every crate that uses them must enable the feature itself, like
+//! in the example above. They live in this crate instead of behind a feature
+//! of `tiny-uom`, so enabling them never turns the feature on for the other
+//! users of `tiny-uom` in the same build. An exponent that overflows an `i8`
+//! fails to compile.
+//!
+//! [`tiny-uom`]: tiny_uom
+
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+#![deny(
+    rust_2021_compatibility,
+    warnings,
+    clippy::pedantic,
+    missing_docs,
+    missing_debug_implementations,
+    rustdoc::broken_intra_doc_links,
+    unsafe_code
+)]
+
+use tiny_uom::exponent::{add, mul};
+use tiny_uom::{Dimensioned, Quantity, Si};
+
+/// Implemented by the quantities with the seven SI base units.
+pub trait SiDimensioned: Dimensioned<Exponents = [i8; 7], System = Si> {}
+
+impl<Q: Dimensioned<Exponents = [i8; 7], System = Si>> SiDimensioned for Q {}
+
+/// Returns the exponent of the base unit at `index` of `Q` multiplied by `n`.
+#[must_use]
+pub const fn scaled<Q: SiDimensioned>(index: usize, n: i8) -> i8 {
+    mul(Q::EXPONENTS[index], n)
+}
+
+/// Returns the sum of the exponents of the base unit at `index`
+/// of `A` and `n` times the exponent of `B`.
+#[must_use]
+pub const fn combined<A: SiDimensioned, B: SiDimensioned>(index: usize, n: i8) -> i8 {
+    add(A::EXPONENTS[index], mul(B::EXPONENTS[index], n))
+}
+
+/// The reciprocal of a quantity, like `Inverse<Time>` for a frequency.
+#[allow(type_alias_bounds)]
+pub type Inverse<Q: SiDimensioned> = Quantity<
+    { scaled::<Q>(0, -1) },
+    { scaled::<Q>(1, -1) },
+    { scaled::<Q>(2, -1) },
+    { scaled::<Q>(3, -1) },
+    { scaled::<Q>(4, -1) },
+    { scaled::<Q>(5, -1) },
+    { scaled::<Q>(6, -1) },
+>;
+
+/// The square of a quantity, like `Squared<Length>` for an area.
+#[allow(type_alias_bounds)]
+pub type Squared<Q: SiDimensioned> = Quantity<
+    { scaled::<Q>(0, 2) },
+    { scaled::<Q>(1, 2) },
+    { scaled::<Q>(2, 2) },
+    { scaled::<Q>(3, 2) },
+    { scaled::<Q>(4, 2) },
+    { scaled::<Q>(5, 2) },
+    { scaled::<Q>(6, 2) },
+>;
+
+/// The cube of a quantity, like `Cubed<Length>` for a volume.
+#[allow(type_alias_bounds)]
+pub type Cubed<Q: SiDimensioned> = Quantity<
+    { scaled::<Q>(0, 3) },
+    { scaled::<Q>(1, 3) },
+    { scaled::<Q>(2, 3) },
+    { scaled::<Q>(3, 3) },
+    { scaled::<Q>(4, 3) },
+    { scaled::<Q>(5, 3) },
+    { scaled::<Q>(6, 3) },
+>;
+
+/// The product of two quantities, like `Prod<Force, Length>` for an energy.
+#[allow(type_alias_bounds)]
+pub type Prod<A: SiDimensioned, B: SiDimensioned> = Quantity<
+    { combined::<A, B>(0, 1) },
+    { combined::<A, B>(1, 1) },
+    { combined::<A, B>(2, 1) },
+    { combined::<A, B>(3, 1) },
+    { combined::<A, B>(4, 1) },
+    { combined::<A, B>(5, 1) },
+    { combined::<A, B>(6, 1) },
+>;
+
+/// The ratio of two quantities, like `Ratio<Length, Time>` for a velocity.
+#[allow(type_alias_bounds)]
+pub type Ratio<A: SiDimensioned, B: SiDimensioned> = Quantity<
+    { combined::<A, B>(0, -1) },
+    { combined::<A, B>(1, -1) },
+    { combined::<A, B>(2, -1) },
+    { combined::<A, B>(3, -1) },
+    { combined::<A, B>(4, -1) },
+    { combined::<A, B>(5, -1) },
+    { combined::<A, B>(6, -1) },
+>;
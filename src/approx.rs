@@ -0,0 +1,81 @@
+//! [`approx`](https://docs.rs/approx) support for quantities.
+//!
+//! The epsilon of the comparisons is a quantity of the same unit.
+//!
+//! ```
+//! use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_ne};
+//! use tiny_uom::values::m;
+//!
+//! let length = 0.1 * m + 0.2 * m;
+//! assert_relative_eq!(length, 0.3 * m);
+//! assert_abs_diff_eq!(length, 0.31 * m, epsilon = 0.02 * m);
+//! assert_ulps_ne!(length, 0.31 * m);
+//! ```
+
+use crate::Quantity;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > AbsDiffEq for Quantity<m, kg, s, A, K, mol, cd>
+{
+    type Epsilon = Self;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Self::new(f32::default_epsilon())
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.abs_diff_eq(&other.value, epsilon.value)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > RelativeEq for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn default_max_relative() -> Self::Epsilon {
+        Self::new(f32::default_max_relative())
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.value
+            .relative_eq(&other.value, epsilon.value, max_relative.value)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > UlpsEq for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq(&other.value, epsilon.value, max_ulps)
+    }
+}
@@ -0,0 +1,154 @@
+//! Conversions between quantities and [Apache Arrow] columns.
+//!
+//! Columns are stored as a [`Float32Array`] of the raw values, while the unit
+//! is kept in the metadata of the [`Field`] under [`UNIT_KEY`]. Since Parquet
+//! files store the Arrow schema, the unit also survives a round trip through
+//! columnar storage.
+//!
+//! ```
+//! use tiny_uom::arrow::{field, from_array, to_array};
+//! use tiny_uom::values::{m, s};
+//! use tiny_uom::Quantity;
+//!
+//! let speeds = vec![1.5 * (m / s), 3.0 * (m / s)];
+//! let field = field::<1, 0, -1, 0, 0, 0, 0>("speed", false);
+//! let array = to_array(&speeds);
+//! assert_eq!(field.metadata()["unit"], "m/s");
+//!
+//! let read: Vec<Quantity<1, 0, -1, 0, 0, 0, 0>> = from_array(&field, &array).unwrap();
+//! assert_eq!(read, speeds);
+//! assert!(from_array::<0, 0, 1, 0, 0, 0, 0>(&field, &array).is_err());
+//! ```
+//!
+//! [Apache Arrow]: https://arrow.apache.org
+
+use crate::{
+    error::{DimensionMismatch, ParseError},
+    registry::UnitRegistry,
+    Quantity,
+};
+use arrow_array::{Array, Float32Array};
+use arrow_schema::{DataType, Field};
+use std::{collections::HashMap, fmt};
+
+/// The key of the field metadata entry that holds the unit of a column.
+pub const UNIT_KEY: &str = "unit";
+
+/// An error that occurred while reading quantities from an Arrow column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnError {
+    /// The field has no [`UNIT_KEY`] metadata entry.
+    MissingUnit,
+    /// The unit of the field could not be parsed or has a different dimension.
+    Unit(ParseError),
+    /// The column contains a null value at the given index.
+    Null(usize),
+}
+
+impl fmt::Display for ColumnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnError::MissingUnit => write!(f, "field has no `{UNIT_KEY}` metadata"),
+            ColumnError::Unit(err) => write!(f, "invalid unit of field: {err}"),
+            ColumnError::Null(idx) => write!(f, "column contains null at index {idx}"),
+        }
+    }
+}
+
+impl std::error::Error for ColumnError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ColumnError::Unit(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for ColumnError {
+    fn from(err: ParseError) -> Self {
+        ColumnError::Unit(err)
+    }
+}
+
+/// Create a `Float32` field whose metadata contains the unit of the quantity.
+#[must_use]
+pub fn field<
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    name: impl Into<String>,
+    nullable: bool,
+) -> Field {
+    let unit = Quantity::<m, kg, s, A, K, mol, cd>::UNIT.to_string();
+    Field::new(name, DataType::Float32, nullable)
+        .with_metadata(HashMap::from([(UNIT_KEY.to_string(), unit)]))
+}
+
+/// Collect the raw values of the quantities into an Arrow array.
+#[must_use]
+pub fn to_array<
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    values: &[Quantity<m, kg, s, A, K, mol, cd>],
+) -> Float32Array {
+    values.iter().map(|q| q.value).collect::<Vec<_>>().into()
+}
+
+/// Read the quantities from an Arrow array described by `field`.
+///
+/// The unit in the metadata may use any unit of the built-in [`UnitRegistry`],
+/// so a column in `km/h` is converted into `m/s`.
+///
+/// # Errors
+///
+/// Returns an error if the field has no unit, the unit has a different
+/// dimension than the quantity, or the array contains nulls.
+pub fn from_array<
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    field: &Field,
+    array: &Float32Array,
+) -> Result<Vec<Quantity<m, kg, s, A, K, mol, cd>>, ColumnError> {
+    let expected = Quantity::<m, kg, s, A, K, mol, cd>::UNIT;
+    let unit = field
+        .metadata()
+        .get(UNIT_KEY)
+        .ok_or(ColumnError::MissingUnit)?;
+    let (factor, unit) = UnitRegistry::builtin().parse_unit(unit)?;
+    if unit != expected {
+        let err = DimensionMismatch {
+            expected,
+            found: unit,
+        };
+        return Err(ParseError::from(err).into());
+    }
+
+    if let Some(idx) = (0..array.len()).find(|&idx| array.is_null(idx)) {
+        return Err(ColumnError::Null(idx));
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let values = array
+        .values()
+        .iter()
+        .map(|&value| Quantity::new((f64::from(value) * factor) as f32))
+        .collect();
+    Ok(values)
+}
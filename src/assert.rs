@@ -0,0 +1,32 @@
+//! Compile-time assertions about the dimensions of quantity types.
+
+/// Fail to compile if two quantity types have different dimensions.
+///
+/// The types may be written in any form, like a `Quantity` with exponents or
+/// an alias, and must be the same quantity type. Use it to document the
+/// dimensions of the types in formula-heavy code.
+///
+/// ```
+/// use tiny_uom::{assert_same_dim, Quantity};
+///
+/// type Force = Quantity<1, 1, -2, 0, 0, 0, 0>;
+/// type Energy = Quantity<2, 1, -2, 0, 0, 0, 0>;
+///
+/// assert_same_dim!(Energy, Quantity<2, 1, -2, 0, 0, 0, 0>);
+/// assert_same_dim!(Quantity<1, 1, -2, 0, 0, 0, 0>, Force);
+/// ```
+///
+/// ```compile_fail
+/// use tiny_uom::{assert_same_dim, Quantity};
+///
+/// type Force = Quantity<1, 1, -2, 0, 0, 0, 0>;
+/// type Energy = Quantity<2, 1, -2, 0, 0, 0, 0>;
+///
+/// assert_same_dim!(Force, Energy);
+/// ```
+#[macro_export]
+macro_rules! assert_same_dim {
+    ($lhs:ty, $rhs:ty $(,)?) => {
+        const _: fn($lhs) -> $rhs = |quantity| quantity;
+    };
+}
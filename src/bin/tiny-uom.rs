@@ -0,0 +1,56 @@
+//! Convert a value between unit expressions.
+//!
+//! ```text
+//! $ tiny-uom 65 mph km/h
+//! 104.60736 km/h
+//! $ tiny-uom 1 kW*h
+//! 3600000 m^2*kg/s^2
+//! ```
+#![deny(rust_2021_compatibility, warnings, clippy::pedantic, unsafe_code)]
+
+use std::process::ExitCode;
+use tiny_uom::registry::UnitRegistry;
+
+const USAGE: &str = "usage: tiny-uom <value> <from> [<to>]";
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let (value, from, to) = match args.as_slice() {
+        [value, from] => (value, from, None),
+        [value, from, to] => (value, from, Some(to)),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let value = match value.parse::<f64>() {
+        Ok(value) => value,
+        Err(err) => {
+            eprintln!("error: invalid value `{value}`: {err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let registry = UnitRegistry::builtin();
+    let result = match to {
+        Some(to) => registry
+            .convert(value, from, to)
+            .map(|value| format!("{value} {to}")),
+        None => registry
+            .parse_unit(from)
+            .map(|(factor, unit)| format!("{} {unit}", value * factor))
+            .map_err(Into::into),
+    };
+
+    match result {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Dimensionless groups of a set of variables, following the Buckingham π theorem.
+//!
+//! [`pi_groups`] returns a basis of the products of powers of the variables
+//! that are dimensionless. Every dimensionless product of the variables is a
+//! product of powers of these groups, so a physical law relating `n`
+//! variables of rank `r` can be written in `n - r` groups.
+//!
+//! ```
+//! use tiny_uom::buckingham::pi_groups;
+//! use tiny_uom::Quantity;
+//!
+//! type Density = Quantity<-3, 1, 0, 0, 0, 0, 0>;
+//! type Velocity = Quantity<1, 0, -1, 0, 0, 0, 0>;
+//! type Length = Quantity<1, 0, 0, 0, 0, 0, 0>;
+//! type Viscosity = Quantity<-1, 1, -1, 0, 0, 0, 0>;
+//!
+//! // the Reynolds number `ρ v D / μ`
+//! let groups = pi_groups(&[Density::UNIT, Velocity::UNIT, Length::UNIT, Viscosity::UNIT]);
+//! assert_eq!(groups, [[1, 1, 1, -1]]);
+//! ```
+
+use crate::Unit;
+
+/// Returns the greatest common divisor of `a` and `b`, which is never negative.
+fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+/// A fraction in lowest terms with a positive denominator.
+#[derive(Clone, Copy, PartialEq)]
+struct Fraction {
+    num: i64,
+    den: i64,
+}
+
+impl Fraction {
+    const ZERO: Fraction = Fraction { num: 0, den: 1 };
+
+    fn new(num: i64, den: i64) -> Self {
+        let div = gcd(num, den) * den.signum();
+        Self {
+            num: num / div,
+            den: den / div,
+        }
+    }
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.num * rhs.den - rhs.num * self.den, self.den * rhs.den)
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.num * rhs.num, self.den * rhs.den)
+    }
+
+    fn div(self, rhs: Self) -> Self {
+        Self::new(self.num * rhs.den, self.den * rhs.num)
+    }
+}
+
+/// Returns a basis of the dimensionless groups that can be formed from
+/// variables with the given units.
+///
+/// Each group holds the exponent of every variable, in the order of `units`.
+/// The exponents are the smallest integers, and the first non-zero exponent
+/// of a group is positive. Variables that are themselves dimensionless form
+/// a group on their own.
+///
+/// ```
+/// use tiny_uom::buckingham::pi_groups;
+/// use tiny_uom::Unit;
+///
+/// let length = Unit { m: 1, ..Unit::DIMENSIONLESS };
+/// let time = Unit { s: 1, ..Unit::DIMENSIONLESS };
+/// let acceleration = Unit { m: 1, s: -2, ..Unit::DIMENSIONLESS };
+///
+/// // the period of a pendulum only depends on `t^2 g / l`
+/// assert_eq!(pi_groups(&[time, acceleration, length]), [[2, 1, -1]]);
+/// assert!(pi_groups(&[length, time]).is_empty());
+/// ```
+#[must_use]
+pub fn pi_groups(units: &[Unit]) -> Vec<Vec<i32>> {
+    // one row per base unit, one column per variable
+    let mut rows: Vec<Vec<Fraction>> = (0..7)
+        .map(|base| {
+            units
+                .iter()
+                .map(|unit| Fraction::new(i64::from(unit.exponents()[base]), 1))
+                .collect()
+        })
+        .collect();
+
+    // reduce the matrix into its reduced row echelon form
+    let mut pivots = Vec::new();
+    for col in 0..units.len() {
+        let row = pivots.len();
+        let Some(pivot) = (row..rows.len()).find(|&r| rows[r][col] != Fraction::ZERO) else {
+            continue;
+        };
+        rows.swap(row, pivot);
+
+        let lead = rows[row][col];
+        for value in &mut rows[row] {
+            *value = value.div(lead);
+        }
+        let reduced = rows[row].clone();
+        for (other, values) in rows.iter_mut().enumerate() {
+            let factor = values[col];
+            if other != row && factor != Fraction::ZERO {
+                for (value, &sub) in values.iter_mut().zip(&reduced) {
+                    *value = value.sub(factor.mul(sub));
+                }
+            }
+        }
+        pivots.push(col);
+    }
+
+    // every free variable gives one vector of the nullspace
+    (0..units.len())
+        .filter(|col| !pivots.contains(col))
+        .map(|free| {
+            let mut group = vec![Fraction::ZERO; units.len()];
+            group[free] = Fraction::new(1, 1);
+            for (row, &pivot) in pivots.iter().enumerate() {
+                group[pivot] = Fraction::ZERO.sub(rows[row][free]);
+            }
+            integral(&group)
+        })
+        .collect()
+}
+
+/// Scale a vector of fractions to the smallest integers with a positive first entry.
+fn integral(group: &[Fraction]) -> Vec<i32> {
+    let lcm = group
+        .iter()
+        .fold(1, |lcm, value| lcm / gcd(lcm, value.den) * value.den);
+    let ints: Vec<i64> = group
+        .iter()
+        .map(|value| value.num * (lcm / value.den))
+        .collect();
+    let div = ints.iter().fold(0, |div, &value| gcd(div, value));
+    let sign = ints
+        .iter()
+        .find(|&&value| value != 0)
+        .map_or(1, |value| value.signum());
+    ints.iter()
+        .map(|&value| {
+            i32::try_from(value / div * sign).expect("the exponents of a group fit into an `i32`")
+        })
+        .collect()
+}
@@ -0,0 +1,91 @@
+//! Conversions of `f32` slices and vectors into quantities, using [`bytemuck`].
+//!
+//! Quantities are `#[repr(transparent)]` wrappers of their raw `f32`, so
+//! buffers of raw values, like DMA buffers or existing float arrays, can be
+//! used as quantities without copying them.
+//!
+//! ```
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! let mut raw = [1.0, 2.0, 3.0];
+//! let lengths = Quantity::<1, 0, 0, 0, 0, 0, 0>::wrap_slice_mut(&mut raw);
+//! lengths[1] += 0.5 * m;
+//! assert_eq!(Quantity::peel_slice(lengths), [1.0, 2.5, 3.0]);
+//! ```
+//!
+//! With the `std` feature, vectors can be converted in the same way,
+//! keeping their allocation:
+//!
+//! ```
+//! use tiny_uom::values::s;
+//! use tiny_uom::Quantity;
+//!
+//! let times = Quantity::<0, 0, 1, 0, 0, 0, 0>::wrap_vec(vec![0.5, 1.5]);
+//! assert_eq!(times, [0.5 * s, 1.5 * s]);
+//! assert_eq!(Quantity::peel_vec(times), [0.5, 1.5]);
+//! ```
+//!
+//! [`bytemuck`]: https://docs.rs/bytemuck
+
+use crate::Quantity;
+use ::bytemuck::TransparentWrapper;
+#[cfg(feature = "std")]
+use ::bytemuck::TransparentWrapperAlloc;
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// View a slice of raw values in SI units as a slice of quantities.
+    #[must_use]
+    #[inline]
+    pub fn wrap_slice(values: &[f32]) -> &[Self] {
+        TransparentWrapper::wrap_slice(values)
+    }
+
+    /// View a mutable slice of raw values in SI units as a slice of quantities.
+    #[must_use]
+    #[inline]
+    pub fn wrap_slice_mut(values: &mut [f32]) -> &mut [Self] {
+        TransparentWrapper::wrap_slice_mut(values)
+    }
+
+    /// View a slice of quantities as a slice of their raw values in SI units.
+    #[must_use]
+    #[inline]
+    pub fn peel_slice(quantities: &[Self]) -> &[f32] {
+        TransparentWrapper::peel_slice(quantities)
+    }
+
+    /// View a mutable slice of quantities as a slice of their raw values in SI units.
+    #[must_use]
+    #[inline]
+    pub fn peel_slice_mut(quantities: &mut [Self]) -> &mut [f32] {
+        TransparentWrapper::peel_slice_mut(quantities)
+    }
+
+    /// Convert a vector of raw values in SI units into a vector of quantities,
+    /// without reallocating it.
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn wrap_vec(values: Vec<f32>) -> Vec<Self> {
+        TransparentWrapperAlloc::wrap_vec(values)
+    }
+
+    /// Convert a vector of quantities into a vector of their raw values
+    /// in SI units, without reallocating it.
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn peel_vec(quantities: Vec<Self>) -> Vec<f32> {
+        TransparentWrapperAlloc::peel_vec(quantities)
+    }
+}
@@ -0,0 +1,117 @@
+//! Numerical integration and differentiation of evenly spaced samples.
+//!
+//! The integral of samples of `Q` over a step of `X` has the dimension of
+//! `Q * X`, so integrating power over time yields energy, and the derivative
+//! has the dimension of `Q / X`. Like for
+//! [`Quantity::mul_quantity`](crate::Quantity::mul_quantity), the type of
+//! the result has to be known and must have the summed or subtracted exponents.
+//!
+//! ```
+//! use tiny_uom::calculus::{simpson, trapezoid};
+//! use tiny_uom::values::s;
+//! use tiny_uom::Quantity;
+//!
+//! type Power = Quantity<2, 1, -3, 0, 0, 0, 0>;
+//! type Energy = Quantity<2, 1, -2, 0, 0, 0, 0>;
+//!
+//! let power = [0.0, 50.0, 100.0, 150.0, 200.0].map(Power::new);
+//! let energy: Energy = trapezoid(&power, 1.0 * s);
+//! assert_eq!(energy, Energy::new(400.0));
+//! assert_eq!(simpson::<_, _, Energy>(&power, 1.0 * s), Energy::new(400.0));
+//! ```
+//!
+//! ```
+//! use tiny_uom::calculus::{differentiate, gradient};
+//! use tiny_uom::values::{m, s};
+//! use tiny_uom::Quantity;
+//!
+//! type Velocity = Quantity<1, 0, -1, 0, 0, 0, 0>;
+//!
+//! let positions = [0.0 * m, 1.0 * m, 4.0 * m, 9.0 * m];
+//! let velocity: Vec<Velocity> = differentiate(&positions, 1.0 * s).collect();
+//! assert_eq!(velocity, [1.0, 3.0, 5.0].map(Velocity::new));
+//!
+//! let velocity: Vec<Velocity> = gradient(&positions, 1.0 * s).collect();
+//! assert_eq!(velocity, [1.0, 2.0, 4.0, 5.0].map(Velocity::new));
+//! ```
+
+use crate::{Dimensioned, ExponentCheck, Si};
+
+/// Integrate samples spaced `dx` apart using the trapezoidal rule.
+///
+/// The integral of less than two samples is zero.
+#[must_use]
+pub fn trapezoid<Q, X, O>(samples: &[Q], dx: X) -> O
+where
+    Q: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    X: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+{
+    let () = ExponentCheck::<Q, X, O>::PRODUCT;
+    let sum: f32 = samples
+        .windows(2)
+        .map(|pair| pair[0].raw() + pair[1].raw())
+        .sum();
+    O::from_raw(sum * 0.5 * dx.raw())
+}
+
+/// Integrate samples spaced `dx` apart using Simpson's rule.
+///
+/// Simpson's rule needs an odd number of samples. For an even number of
+/// samples, the last interval is integrated using the trapezoidal rule.
+/// The integral of less than two samples is zero.
+#[must_use]
+pub fn simpson<Q, X, O>(samples: &[Q], dx: X) -> O
+where
+    Q: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    X: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+{
+    let () = ExponentCheck::<Q, X, O>::PRODUCT;
+    let (odd, tail) = match samples.len() {
+        len if len % 2 == 1 => (samples, &samples[..0]),
+        0 => (samples, samples),
+        len => (&samples[..len - 1], &samples[len - 2..]),
+    };
+    let sum: f32 = odd
+        .windows(3)
+        .step_by(2)
+        .map(|triple| triple[0].raw() + 4.0 * triple[1].raw() + triple[2].raw())
+        .sum();
+    let integral = sum * dx.raw() / 3.0;
+    O::from_raw(integral + trapezoid::<Q, X, O>(tail, dx).raw())
+}
+
+/// Returns the forward differences of samples spaced `dx` apart divided by `dx`,
+/// one less than there are samples.
+pub fn differentiate<'a, Q, X, O>(samples: &'a [Q], dx: X) -> impl Iterator<Item = O> + 'a
+where
+    Q: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    X: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si> + 'a,
+    O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+{
+    let () = ExponentCheck::<Q, X, O>::QUOTIENT;
+    samples
+        .windows(2)
+        .map(move |pair| O::from_raw((pair[1].raw() - pair[0].raw()) / dx.raw()))
+}
+
+/// Returns the derivative at every sample spaced `dx` apart, using central
+/// differences for the inner samples and one-sided differences at the ends.
+///
+/// No derivatives are returned for less than two samples.
+pub fn gradient<'a, Q, X, O>(samples: &'a [Q], dx: X) -> impl Iterator<Item = O> + 'a
+where
+    Q: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    X: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si> + 'a,
+    O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+{
+    let () = ExponentCheck::<Q, X, O>::QUOTIENT;
+    let len = if samples.len() < 2 { 0 } else { samples.len() };
+    (0..len).map(move |idx| {
+        let before = samples[idx.saturating_sub(1)].raw();
+        let after = samples[(idx + 1).min(len - 1)].raw();
+        let steps = if idx == 0 || idx == len - 1 { 1.0 } else { 2.0 };
+        O::from_raw((after - before) / (steps * dx.raw()))
+    })
+}
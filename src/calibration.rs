@@ -0,0 +1,91 @@
+//! Scaling raw sensor readings into quantities.
+//!
+//! A [`Calibration`] turns the counts of an ADC or the raw register value
+//! of a sensor into a quantity, so drivers can expose their readings in
+//! volts, kelvin or pascal instead of magic-scaled integers.
+//!
+//! ```
+//! use tiny_uom::calibration::Calibration;
+//! use tiny_uom::values::K;
+//! use tiny_uom::Quantity;
+//!
+//! let pa = Quantity::<-1, 1, -2, 0, 0, 0, 0>::new(1.0);
+//! let pressure = Calibration::new(101_325.0 * pa, 2.0 * pa);
+//! assert_eq!(pressure.apply(100_u16), 101_525.0 * pa);
+//! assert_eq!(pressure.counts(101_525.0 * pa), 100.0);
+//!
+//! // A thermistor reading 512 counts at 273.15 K and 3584 counts at 373.15 K.
+//! let temperature = Calibration::from_points((512, 273.15 * K), (3584, 373.15 * K)).unwrap();
+//! assert_eq!(temperature.apply(2048), 323.15 * K);
+//!
+//! // Two readings with the same counts do not determine a gain.
+//! assert_eq!(Calibration::from_points((512, 273.15 * K), (512, 373.15 * K)), None);
+//! ```
+
+use crate::Quantity;
+
+/// A linear mapping from raw counts to quantities of type `Q`.
+///
+/// A reading of `counts` is converted into `offset + gain * counts`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Calibration<Q> {
+    /// The quantity at zero counts.
+    pub offset: Q,
+    /// The change of the quantity per count.
+    pub gain: Q,
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Calibration<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    /// Create a calibration from the quantity at zero counts and the change per count.
+    #[must_use]
+    pub const fn new(
+        offset: Quantity<m, kg, s, A, K, mol, cd>,
+        gain: Quantity<m, kg, s, A, K, mol, cd>,
+    ) -> Self {
+        Self { offset, gain }
+    }
+
+    /// Create a calibration from two readings of known quantities, or `None`
+    /// if both readings have the same counts.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_points<C: Into<f64>>(
+        (low_counts, low): (C, Quantity<m, kg, s, A, K, mol, cd>),
+        (high_counts, high): (C, Quantity<m, kg, s, A, K, mol, cd>),
+    ) -> Option<Self> {
+        let (low_counts, high_counts) = (low_counts.into(), high_counts.into());
+        let span = high_counts - low_counts;
+        if span == 0.0 {
+            return None;
+        }
+        let gain = (f64::from(high.value) - f64::from(low.value)) / span;
+        let offset = f64::from(low.value) - gain * low_counts;
+        Some(Self::new(
+            Quantity::new(offset as f32),
+            Quantity::new(gain as f32),
+        ))
+    }
+
+    /// Convert a raw reading into a quantity.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn apply<C: Into<f64>>(&self, counts: C) -> Quantity<m, kg, s, A, K, mol, cd> {
+        let value = f64::from(self.offset.value) + f64::from(self.gain.value) * counts.into();
+        Quantity::new(value as f32)
+    }
+
+    /// Returns the raw reading that corresponds to a quantity, for example to set a threshold.
+    #[must_use]
+    pub fn counts(&self, quantity: Quantity<m, kg, s, A, K, mol, cd>) -> f32 {
+        (quantity.value - self.offset.value) / self.gain.value
+    }
+}
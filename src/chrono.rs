@@ -0,0 +1,51 @@
+//! Conversions between time quantities and [`chrono`] time deltas.
+//!
+//! The difference of two `DateTime`s is a [`TimeDelta`], which can be
+//! used directly for rates and velocities.
+//!
+//! ```
+//! use chrono::{DateTime, TimeDelta};
+//! use tiny_uom::values::{m, s};
+//! use tiny_uom::Quantity;
+//!
+//! let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+//! let end = DateTime::from_timestamp(1_700_000_004, 0).unwrap();
+//!
+//! let speed = (100.0 * m) / Quantity::from(end - start);
+//! assert_eq!(speed, 25.0 * (m / s));
+//!
+//! assert_eq!((-1.5 * s).to_time_delta(), Ok(TimeDelta::milliseconds(-1500)));
+//! assert!((f32::NAN * s).to_time_delta().is_err());
+//! ```
+//!
+//! [`chrono`]: https://docs.rs/chrono
+
+use crate::duration::duration_error;
+use crate::error::ConversionError;
+use crate::Quantity;
+use chrono::TimeDelta;
+use std::time::Duration;
+
+impl From<TimeDelta> for Quantity<0, 0, 1, 0, 0, 0, 0> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn from(delta: TimeDelta) -> Self {
+        let secs = delta.num_seconds() as f64 + f64::from(delta.subsec_nanos()) * 1e-9;
+        Quantity::new(secs as f32)
+    }
+}
+
+impl Quantity<0, 0, 1, 0, 0, 0, 0> {
+    /// Convert this time into a [`TimeDelta`], which may be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::NonFinite`] if the time is not finite, and
+    /// [`ConversionError::OutOfRange`] if it is too large for a `TimeDelta`.
+    pub fn to_time_delta(self) -> Result<TimeDelta, ConversionError> {
+        let delta = Duration::try_from_secs_f32(self.value.abs())
+            .ok()
+            .and_then(|duration| TimeDelta::from_std(duration).ok())
+            .ok_or_else(|| duration_error(self.value))?;
+        Ok(if self.value < 0.0 { -delta } else { delta })
+    }
+}
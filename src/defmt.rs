@@ -0,0 +1,69 @@
+//! [`defmt`] formatting of units and quantities.
+//!
+//! Quantities are formatted like their [`Display`](core::fmt::Display)
+//! output with the alternate flag, for example `9.81 N` or `3 m/s`,
+//! without using `core::fmt`.
+//!
+//! [`defmt`]: https://docs.rs/defmt
+
+use crate::{Quantity, Unit};
+use defmt::{write, Format, Formatter};
+
+fn write_product(f: Formatter<'_>, factors: impl Iterator<Item = (&'static str, i8)>) {
+    for (idx, (symbol, exp)) in factors.enumerate() {
+        if idx > 0 {
+            write!(f, "*");
+        }
+        write!(f, "{=str}", symbol);
+        if exp != 1 {
+            write!(f, "^{=i8}", exp);
+        }
+    }
+}
+
+impl Format for Unit {
+    /// Formats the unit using the symbols of the SI base units, like `m*kg/s^2`.
+    fn format(&self, f: Formatter<'_>) {
+        let (numerator, denominator) = self.fraction();
+        if numerator.clone().next().is_some() {
+            write_product(f, numerator);
+        } else {
+            write!(f, "1");
+        }
+
+        match denominator.clone().count() {
+            0 => {}
+            1 => {
+                write!(f, "/");
+                write_product(f, denominator);
+            }
+            _ => {
+                write!(f, "/(");
+                write_product(f, denominator);
+                write!(f, ")");
+            }
+        }
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Format for Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Formats the value followed by the symbol of the named unit if there is one,
+    /// or else the [`Unit`].
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{=f32}", self.value);
+        match Self::UNIT.info() {
+            _ if Self::UNIT.is_dimensionless() => {}
+            Some(info) => write!(f, " {=str}", info.symbol),
+            None => write!(f, " {}", Self::UNIT),
+        }
+    }
+}
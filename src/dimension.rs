@@ -0,0 +1,295 @@
+//! A trait implemented by every quantity type of this crate.
+
+use crate::{info::UNITS, macro_support, Quantity};
+use core::marker::PhantomData;
+
+pub(crate) mod sealed {
+    /// Prevents implementations of [`Dimensioned`](super::Dimensioned) outside of this crate.
+    pub trait Sealed {}
+}
+
+/// Implemented by every quantity type of this crate, to write code that is
+/// generic over any quantity.
+///
+/// Besides [`Quantity`], this includes the quantities of the `mechanics`,
+/// `electromechanics`, `wide`, `rational` and `unit_param` modules if their
+/// features are enabled, and the quantities defined by [`unit_system!`](crate::unit_system).
+/// The trait is sealed, it can not be implemented outside of this crate.
+///
+/// ```
+/// use tiny_uom::values::{m, s};
+/// use tiny_uom::Dimensioned;
+///
+/// /// Smooth a new sample into the previous value of any quantity.
+/// fn smooth<Q: Dimensioned<Scalar = f32>>(previous: Q, sample: Q, alpha: f32) -> Q {
+///     Q::from_raw(previous.raw() + alpha * (sample.raw() - previous.raw()))
+/// }
+///
+/// assert_eq!(smooth(10.0 * m, 20.0 * m, 0.5), 15.0 * m);
+/// assert_eq!(smooth(1.0 * s, 3.0 * s, 0.25), 1.5 * s);
+///
+/// fn exponents<Q: Dimensioned>(_: Q) -> Q::Exponents {
+///     Q::EXPONENTS
+/// }
+///
+/// assert_eq!(exponents(m / s), [1, 0, -1, 0, 0, 0, 0]);
+/// ```
+pub trait Dimensioned: Copy + sealed::Sealed {
+    /// The type of the raw value, like `f32`.
+    type Scalar: Copy;
+
+    /// The type of the exponents of the base units, like `[i8; 7]`.
+    type Exponents: Copy;
+
+    /// The unit system whose base units the exponents refer to, like [`Si`].
+    ///
+    /// Code that interprets the exponents as those of the SI base units
+    /// bounds this to `Si`, so the quantities of a
+    /// [`unit_system!`](crate::unit_system) with as many base dimensions are
+    /// not mistaken for SI quantities.
+    type System;
+
+    /// The exponents of the base units, in the order of the const parameters.
+    const EXPONENTS: Self::Exponents;
+
+    /// Create a quantity from its raw value in SI units.
+    fn from_raw(value: Self::Scalar) -> Self;
+
+    /// Returns the raw value of this quantity in SI units.
+    fn raw(self) -> Self::Scalar;
+}
+
+/// The [`System`](Dimensioned::System) of the quantities of this crate,
+/// whose exponents are those of the SI base units.
+///
+/// The quantities of a [`unit_system!`](crate::unit_system) are never SI
+/// quantities, even with seven base dimensions:
+///
+/// ```compile_fail
+/// tiny_uom::unit_system! {
+///     pub mod seven { a, b, c, d, e, g, h }
+/// }
+///
+/// use tiny_uom::values::m;
+/// use tiny_uom::Quantity;
+///
+/// let area: Quantity<2, 0, 0, 0, 0, 0, 0> = (2.0 * m).mul_quantity(3.0 * seven::values::a);
+/// ```
+///
+/// ```compile_fail
+/// tiny_uom::unit_system! {
+///     pub mod seven { a, b, c, d, e, g, h }
+/// }
+///
+/// let any = tiny_uom::dynamic::AnyQuantity::from(3.0 * seven::values::a);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Si {}
+
+/// Implemented by a quantity for the quantity types with the same dimension.
+///
+/// This is the bound of the operators that need equal dimensions, like the
+/// addition. [`Quantity`] implements it for every other `Quantity`, and
+/// mixing up two dimensions fails to compile with a message naming both of
+/// them by their [`UnitInfo`](crate::UnitInfo), or by their unit if it has
+/// no name:
+///
+/// ```compile_fail,E0080
+/// use tiny_uom::values::{m, s};
+///
+/// // error: evaluation panicked: expected Length (m), found Time (s)
+/// let sum = 2.0 * m + 3.0 * s;
+/// ```
+///
+/// The other quantity types, which [`quantity_ops!`](crate::quantity_ops)
+/// implements it for, fail with a message naming both quantity types instead
+/// of a mismatch of their const parameters.
+#[diagnostic::on_unimplemented(
+    message = "cannot combine `{Rhs}` with `{Self}` because their dimensions differ",
+    label = "expected a quantity with the dimension of `{Rhs}`, found `{Self}`",
+    note = "the const parameters of a quantity are the exponents of its base units, in the order of the const parameters of its type"
+)]
+pub trait SameDimension<Rhs> {
+    /// Convert this quantity into `Rhs`.
+    fn into_same(self) -> Rhs;
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > sealed::Sealed for Quantity<m, kg, s, A, K, mol, cd>
+{
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Dimensioned for Quantity<m, kg, s, A, K, mol, cd>
+{
+    type Scalar = f32;
+    type Exponents = [i8; 7];
+    type System = Si;
+
+    const EXPONENTS: [i8; 7] = [m, kg, s, A, K, mol, cd];
+
+    #[inline]
+    fn from_raw(value: f32) -> Self {
+        Quantity::new(value)
+    }
+
+    #[inline]
+    fn raw(self) -> f32 {
+        self.value
+    }
+}
+
+/// The check that a quantity converts into a quantity of the same dimension.
+///
+/// Like [`ExponentCheck`](crate::ExponentCheck), this is an associated
+/// constant, which fails with a message naming the expected and the found
+/// dimension.
+pub(crate) struct DimensionCheck<Expected, Found>(PhantomData<(Expected, Found)>);
+
+impl<Expected, Found> DimensionCheck<Expected, Found>
+where
+    Expected: Dimensioned<Exponents = [i8; 7], System = Si>,
+    Found: Dimensioned<Exponents = [i8; 7], System = Si>,
+{
+    const MESSAGE: Message = Message::new()
+        .push("expected ")
+        .push_dimension(Expected::EXPONENTS)
+        .push(", found ")
+        .push_dimension(Found::EXPONENTS);
+
+    pub(crate) const SAME: () = assert!(
+        macro_support::same_dimension(&Expected::EXPONENTS, &Found::EXPONENTS),
+        "{}",
+        Self::MESSAGE.as_str()
+    );
+}
+
+/// A message that is written in a constant, like the `Display` of a [`Unit`](crate::Unit).
+struct Message {
+    buf: [u8; 128],
+    len: usize,
+}
+
+impl Message {
+    const SYMBOLS: [&'static str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+    const fn new() -> Self {
+        Self {
+            buf: [0; 128],
+            len: 0,
+        }
+    }
+
+    const fn push(mut self, text: &str) -> Self {
+        let bytes = text.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() && self.len < self.buf.len() {
+            self.buf[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    /// Push the name of the quantity and the symbol of its unit, like
+    /// `Length (m)`, or only the unit if it has no name, like `m/s`.
+    const fn push_dimension(self, exps: [i8; 7]) -> Self {
+        let mut i = 0;
+        while i < UNITS.len() {
+            let info = &UNITS[i];
+            if macro_support::same_dimension(&info.unit.exponents(), &exps) {
+                let start = self.len;
+                let mut this = self.push(info.quantity);
+                this.buf[start] = this.buf[start].to_ascii_uppercase();
+                return this.push(" (").push(info.symbol).push(")");
+            }
+            i += 1;
+        }
+        self.push_unit(exps)
+    }
+
+    /// Push the unit like its `Display`, like `m*kg/s^2`.
+    const fn push_unit(self, exps: [i8; 7]) -> Self {
+        let (mut numerator, mut denominator) = (0, 0);
+        let mut i = 0;
+        while i < 7 {
+            if exps[i] > 0 {
+                numerator += 1;
+            } else if exps[i] < 0 {
+                denominator += 1;
+            }
+            i += 1;
+        }
+
+        let mut this = if numerator == 0 {
+            self.push("1")
+        } else {
+            self.push_product(exps, 1)
+        };
+        match denominator {
+            0 => this,
+            1 => this.push("/").push_product(exps, -1),
+            _ => {
+                this = this.push("/(").push_product(exps, -1);
+                this.push(")")
+            }
+        }
+    }
+
+    /// Push the base units whose exponents have the sign of `sign`, like `m*kg`.
+    const fn push_product(mut self, exps: [i8; 7], sign: i8) -> Self {
+        let mut first = true;
+        let mut i = 0;
+        while i < 7 {
+            if exps[i].signum() == sign {
+                if !first {
+                    self = self.push("*");
+                }
+                first = false;
+                self = self.push(Self::SYMBOLS[i]);
+                let exp = exps[i].unsigned_abs();
+                if exp != 1 {
+                    self = self.push("^").push_digits(exp);
+                }
+            }
+            i += 1;
+        }
+        self
+    }
+
+    const fn push_digits(self, n: u8) -> Self {
+        let digits = [b'0' + n / 100, b'0' + n / 10 % 10, b'0' + n % 10];
+        let skip = if n >= 100 {
+            0
+        } else if n >= 10 {
+            1
+        } else {
+            2
+        };
+        match core::str::from_utf8(digits.split_at(skip).1) {
+            Ok(digits) => self.push(digits),
+            Err(_) => self,
+        }
+    }
+
+    const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.buf.split_at(self.len).0) {
+            Ok(message) => message,
+            Err(_) => "the quantities have different dimensions",
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Conversions between quantities and the SI quantities of [`dimensioned`].
+//!
+//! Every quantity can be converted into the [`SI`] quantity with `f32` values
+//! and the same dimension, and back. Converting between quantities of
+//! different dimensions fails to compile.
+//!
+//! Note that `dimensioned` orders the candela before the mole,
+//! while the const parameters of [`Quantity`] have them the other way around.
+//!
+//! ```
+//! use dimensioned::si::{Candela, MeterPerSecond};
+//! use tiny_uom::values::{cd, m, s};
+//! use tiny_uom::Quantity;
+//!
+//! let speed: MeterPerSecond<f32> = (10.0 * (m / s)).into();
+//! assert_eq!(speed, MeterPerSecond::new(10.0));
+//!
+//! let speed: Quantity<1, 0, -1, 0, 0, 0, 0> = speed.into();
+//! assert_eq!(speed, 10.0 * (m / s));
+//!
+//! let intensity: Candela<f32> = (2.0 * cd).into();
+//! assert_eq!(intensity, Candela::new(2.0));
+//! ```
+//!
+//! ```compile_fail
+//! use dimensioned::si::Second;
+//! use tiny_uom::values::m;
+//!
+//! let time: Second<f32> = (10.0 * m).into();
+//! ```
+//!
+//! [`dimensioned`]: https://docs.rs/dimensioned
+
+use crate::{macro_support, Quantity};
+use dimensioned::si::SI;
+use dimensioned::typenum::{ATerm, Integer, TArr};
+
+/// Implemented for the type-level arrays of exponents used by [`SI`].
+trait Exponents {
+    /// The exponents in the order of the const parameters of [`Quantity`].
+    const EXPONENTS: [i8; 7];
+}
+
+impl<M, KG, S, A, K, CD, MOL> Exponents
+    for TArr<M, TArr<KG, TArr<S, TArr<A, TArr<K, TArr<CD, TArr<MOL, ATerm>>>>>>>
+where
+    M: Integer,
+    KG: Integer,
+    S: Integer,
+    A: Integer,
+    K: Integer,
+    CD: Integer,
+    MOL: Integer,
+{
+    const EXPONENTS: [i8; 7] = [M::I8, KG::I8, S::I8, A::I8, K::I8, MOL::I8, CD::I8];
+}
+
+impl<
+        U: Exponents,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > From<SI<f32, U>> for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn from(quantity: SI<f32, U>) -> Self {
+        const {
+            assert!(
+                macro_support::same_dimension(&U::EXPONENTS, &[m, kg, s, A, K, mol, cd]),
+                "the `dimensioned` quantity has a different dimension"
+            );
+        }
+        Quantity::new(quantity.value_unsafe)
+    }
+}
+
+impl<
+        U: Exponents,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > From<Quantity<m, kg, s, A, K, mol, cd>> for SI<f32, U>
+{
+    fn from(quantity: Quantity<m, kg, s, A, K, mol, cd>) -> Self {
+        const {
+            assert!(
+                macro_support::same_dimension(&U::EXPONENTS, &[m, kg, s, A, K, mol, cd]),
+                "the `dimensioned` quantity has a different dimension"
+            );
+        }
+        SI::new(quantity.value)
+    }
+}
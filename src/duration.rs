@@ -0,0 +1,58 @@
+//! Conversions between time quantities and [`Duration`].
+//!
+//! ```
+//! use std::time::Duration;
+//! use tiny_uom::error::ConversionError;
+//! use tiny_uom::values::s;
+//! use tiny_uom::Quantity;
+//!
+//! let timeout = Quantity::from(Duration::from_millis(1500));
+//! assert_eq!(timeout, 1.5 * s);
+//! assert_eq!(timeout.to_duration(), Ok(Duration::from_millis(1500)));
+//! assert!(matches!((-1.0 * s).to_duration(), Err(ConversionError::OutOfRange(_))));
+//! assert!(matches!((f32::NAN * s).to_duration(), Err(ConversionError::NonFinite(_))));
+//! ```
+//!
+//! The conversions into the durations of `chrono`, `time` and `hifitime`
+//! report their errors in the same way, as a [`ConversionError`].
+
+use crate::error::{ConversionError, NonFinite, OutOfRange};
+use crate::Quantity;
+use core::time::Duration;
+
+/// The error for a time of `seconds` that does not fit into a duration type.
+pub(crate) fn duration_error(seconds: f32) -> ConversionError {
+    let unit = Quantity::<0, 0, 1, 0, 0, 0, 0>::UNIT;
+    let value = f64::from(seconds);
+    if seconds.is_finite() {
+        OutOfRange { value, unit }.into()
+    } else {
+        NonFinite { value, unit }.into()
+    }
+}
+
+impl From<Duration> for Quantity<0, 0, 1, 0, 0, 0, 0> {
+    fn from(duration: Duration) -> Self {
+        Quantity::new(duration.as_secs_f32())
+    }
+}
+
+impl Quantity<0, 0, 1, 0, 0, 0, 0> {
+    /// Convert this time into a [`Duration`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::NonFinite`] if the time is not finite, and
+    /// [`ConversionError::OutOfRange`] if it is negative or too large for a `Duration`.
+    pub fn to_duration(self) -> Result<Duration, ConversionError> {
+        Duration::try_from_secs_f32(self.value).map_err(|_| duration_error(self.value))
+    }
+}
+
+impl TryFrom<Quantity<0, 0, 1, 0, 0, 0, 0>> for Duration {
+    type Error = ConversionError;
+
+    fn try_from(time: Quantity<0, 0, 1, 0, 0, 0, 0>) -> Result<Self, Self::Error> {
+        time.to_duration()
+    }
+}
@@ -0,0 +1,481 @@
+//! Quantities whose dimension is only known at runtime.
+//!
+//! A [`DynQuantity`] stores its exponents next to its value, for the cases
+//! the const generics of [`Quantity`] can not handle, like
+//! heterogeneous collections, user input or plugin systems. Addition and
+//! subtraction check the dimensions at runtime, while multiplication and
+//! division combine them, so formulas entered at runtime can be evaluated
+//! with correct units.
+//!
+//! ```
+//! use tiny_uom::dynamic::DynQuantity;
+//! use tiny_uom::Unit;
+//!
+//! let metre = Unit { m: 1, ..Unit::DIMENSIONLESS };
+//! let second = Unit { s: 1, ..Unit::DIMENSIONLESS };
+//!
+//! let distance = DynQuantity::new(100.0, metre);
+//! let time = DynQuantity::new(8.0, second);
+//!
+//! let speed = distance / time;
+//! assert_eq!(speed.to_string(), "12.5 m/s");
+//! assert_eq!(speed.checked_add(speed), Ok(DynQuantity::new(25.0, speed.dim)));
+//! assert!(speed.checked_add(distance).is_err());
+//! ```
+//!
+//! Quantities convert into a [`DynQuantity`] with [`From`], and back with
+//! [`TryFrom`], which fails if the dimensions differ.
+
+use crate::error::{DimensionMismatch, DynamicError};
+use crate::{Dimensioned, Quantity, Si, Unit, UnitInfo};
+use core::fmt;
+use core::ops::{Div, Mul, Neg};
+
+/// A value in SI units together with its dimension.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DynQuantity {
+    /// The raw value in SI units.
+    pub value: f64,
+    /// The dimension of this quantity.
+    pub dim: Unit,
+}
+
+impl DynQuantity {
+    /// Create a quantity from its raw value in SI units and its dimension.
+    #[must_use]
+    #[inline]
+    pub const fn new(value: f64, dim: Unit) -> Self {
+        Self { value, dim }
+    }
+
+    /// Create a dimensionless quantity.
+    #[must_use]
+    #[inline]
+    pub const fn dimensionless(value: f64) -> Self {
+        Self::new(value, Unit::DIMENSIONLESS)
+    }
+
+    /// Returns an error if `rhs` has a different dimension than `self`.
+    fn check(self, rhs: Self) -> Result<(), DimensionMismatch> {
+        if self.dim == rhs.dim {
+            Ok(())
+        } else {
+            Err(DimensionMismatch {
+                expected: self.dim,
+                found: rhs.dim,
+            })
+        }
+    }
+
+    /// Add two quantities of the same dimension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions differ.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, DynamicError> {
+        self.check(rhs)?;
+        Ok(Self::new(self.value + rhs.value, self.dim))
+    }
+
+    /// Subtract two quantities of the same dimension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions differ.
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, DynamicError> {
+        self.check(rhs)?;
+        Ok(Self::new(self.value - rhs.value, self.dim))
+    }
+
+    /// Multiply two quantities, adding their exponents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an exponent overflows an `i8`.
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, DynamicError> {
+        let dim = self
+            .dim
+            .checked_mul(rhs.dim)
+            .ok_or(DynamicError::ExponentOverflow)?;
+        Ok(Self::new(self.value * rhs.value, dim))
+    }
+
+    /// Divide two quantities, subtracting their exponents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an exponent overflows an `i8`.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, DynamicError> {
+        let dim = rhs
+            .dim
+            .checked_powi(-1)
+            .and_then(|inverse| self.dim.checked_mul(inverse))
+            .ok_or(DynamicError::ExponentOverflow)?;
+        Ok(Self::new(self.value / rhs.value, dim))
+    }
+
+    /// Raise this quantity to an integer power, multiplying its exponents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an exponent overflows an `i8`.
+    pub fn checked_powi(self, n: i8) -> Result<Self, DynamicError> {
+        let dim = self
+            .dim
+            .checked_powi(n)
+            .ok_or(DynamicError::ExponentOverflow)?;
+        Ok(Self::new(powi(self.value, n), dim))
+    }
+
+    /// Raise this quantity to an integer power, multiplying its exponents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows an `i8`.
+    #[must_use]
+    pub fn powi(self, n: i8) -> Self {
+        self.checked_powi(n)
+            .expect("the exponent overflows an `i8`")
+    }
+
+    /// Returns the square root of this quantity, halving its exponents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an exponent is odd.
+    ///
+    /// ```
+    /// use tiny_uom::dynamic::DynQuantity;
+    /// use tiny_uom::Unit;
+    ///
+    /// let area = DynQuantity::new(16.0, Unit { m: 2, ..Unit::DIMENSIONLESS });
+    /// assert_eq!(area.sqrt().unwrap().to_string(), "4 m");
+    /// assert!(area.sqrt().unwrap().sqrt().is_err());
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn sqrt(self) -> Result<Self, DynamicError> {
+        let mut exps = self.dim.exponents();
+        for exp in &mut exps {
+            if *exp % 2 != 0 {
+                return Err(DynamicError::OddExponent(self.dim));
+            }
+            *exp /= 2;
+        }
+        let value = crate::math::imp::sqrt64(self.value);
+        Ok(Self::new(value, Unit::from_exponents(exps)))
+    }
+
+    /// Returns `true` if this quantity has the dimension of `Q`.
+    #[must_use]
+    pub fn is<Q: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>>(self) -> bool {
+        self.dim == Unit::from_exponents(Q::EXPONENTS)
+    }
+
+    /// Convert this quantity into the quantity type `Q`.
+    ///
+    /// Unlike [`TryFrom`], this can be called in code that is generic over
+    /// the quantity type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `Q` has a different dimension.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn downcast<Q: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>>(
+        self,
+    ) -> Result<Q, DimensionMismatch> {
+        if self.is::<Q>() {
+            Ok(Q::from_raw(self.value as f32))
+        } else {
+            Err(DimensionMismatch {
+                expected: Unit::from_exponents(Q::EXPONENTS),
+                found: self.dim,
+            })
+        }
+    }
+
+    /// Returns the named unit with the dimension of this quantity, if there is one.
+    #[must_use]
+    pub fn named_unit(self) -> Option<&'static UnitInfo> {
+        self.dim.info()
+    }
+
+    /// Express the dimension of this quantity with a named unit, if that
+    /// needs fewer base units.
+    ///
+    /// The named unit that leaves the fewest base units is used, so a
+    /// quantity in `m^2*kg/s` is expressed in `J*s`. Dimensions that have a
+    /// named unit are expressed in that unit only.
+    ///
+    /// ```
+    /// use tiny_uom::dynamic::DynQuantity;
+    /// use tiny_uom::Unit;
+    ///
+    /// let kg = DynQuantity::new(1.0, Unit { kg: 1, ..Unit::DIMENSIONLESS });
+    /// let m = DynQuantity::new(1.0, Unit { m: 1, ..Unit::DIMENSIONLESS });
+    /// let s = DynQuantity::new(1.0, Unit { s: 1, ..Unit::DIMENSIONLESS });
+    ///
+    /// let action = 6.6 * kg * m * m / s;
+    /// assert_eq!(action.to_string(), "6.6 m^2*kg/s");
+    /// assert_eq!(action.simplify().to_string(), "6.6 J*s");
+    /// assert_eq!((kg * m / s / s).simplify().to_string(), "1 N");
+    /// assert_eq!((m / s).simplify().to_string(), "1 m/s");
+    /// ```
+    #[must_use]
+    pub fn simplify(self) -> Simplified {
+        fn base_units(unit: Unit) -> u32 {
+            unit.exponents()
+                .iter()
+                .map(|exp| u32::from(exp.unsigned_abs()))
+                .sum()
+        }
+
+        let mut best = Simplified {
+            value: self.value,
+            named: None,
+            rest: self.dim,
+        };
+        let mut best_cost = base_units(self.dim);
+        for info in UnitInfo::all() {
+            let Some(rest) = info
+                .unit
+                .checked_powi(-1)
+                .and_then(|inverse| self.dim.checked_mul(inverse))
+            else {
+                continue;
+            };
+            let cost = 1 + base_units(rest);
+            if cost < best_cost {
+                best = Simplified {
+                    value: self.value,
+                    named: Some(info),
+                    rest,
+                };
+                best_cost = cost;
+            }
+        }
+        best
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>> From<Q> for DynQuantity {
+    /// Convert a statically typed quantity into a [`DynQuantity`].
+    fn from(quantity: Q) -> Self {
+        Self::new(
+            f64::from(quantity.raw()),
+            Unit::from_exponents(Q::EXPONENTS),
+        )
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > TryFrom<DynQuantity> for Quantity<m, kg, s, A, K, mol, cd>
+{
+    type Error = DimensionMismatch;
+
+    /// Convert a [`DynQuantity`] into a quantity with the same dimension.
+    ///
+    /// ```
+    /// use tiny_uom::dynamic::DynQuantity;
+    /// use tiny_uom::values::m;
+    /// use tiny_uom::Quantity;
+    ///
+    /// let input = DynQuantity::from(2.5 * m);
+    /// assert_eq!(Quantity::try_from(input), Ok(2.5 * m));
+    /// assert!(Quantity::<0, 0, 1, 0, 0, 0, 0>::try_from(input).is_err());
+    /// ```
+    fn try_from(quantity: DynQuantity) -> Result<Self, Self::Error> {
+        quantity.downcast()
+    }
+}
+
+/// Raise `x` to an integer power by repeated squaring.
+fn powi(mut x: f64, n: i8) -> f64 {
+    let mut exp = n.unsigned_abs();
+    let mut out = 1.0;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            out *= x;
+        }
+        x *= x;
+        exp >>= 1;
+    }
+    if n < 0 {
+        1.0 / out
+    } else {
+        out
+    }
+}
+
+/// A [`DynQuantity`] expressed with a named unit, returned by [`DynQuantity::simplify`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Simplified {
+    /// The raw value in SI units.
+    pub value: f64,
+    /// The named unit, if one is used.
+    pub named: Option<&'static UnitInfo>,
+    /// The base units that remain after dividing by the named unit.
+    pub rest: Unit,
+}
+
+impl fmt::Display for Simplified {
+    /// Formats the value followed by the named unit and the remaining
+    /// base units, like `6.6 J*s`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_factors(
+            f: &mut fmt::Formatter<'_>,
+            factors: impl Iterator<Item = (&'static str, i8)>,
+        ) -> fmt::Result {
+            for (idx, (symbol, exp)) in factors.enumerate() {
+                if idx > 0 {
+                    f.write_str("*")?;
+                }
+                f.write_str(symbol)?;
+                if exp != 1 {
+                    write!(f, "^{exp}")?;
+                }
+            }
+            Ok(())
+        }
+
+        fmt::Display::fmt(&self.value, f)?;
+        let Some(named) = self.named else {
+            if self.rest.is_dimensionless() {
+                return Ok(());
+            }
+            return write!(f, " {}", self.rest);
+        };
+
+        write!(f, " {}", named.symbol)?;
+        let (numerator, denominator) = self.rest.fraction();
+        for (symbol, exp) in numerator {
+            f.write_str("*")?;
+            f.write_str(symbol)?;
+            if exp != 1 {
+                write!(f, "^{exp}")?;
+            }
+        }
+        match denominator.clone().count() {
+            0 => Ok(()),
+            1 => {
+                f.write_str("/")?;
+                write_factors(f, denominator)
+            }
+            _ => {
+                f.write_str("/(")?;
+                write_factors(f, denominator)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl Mul for DynQuantity {
+    type Output = Self;
+
+    /// Multiply two quantities, adding their exponents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows an `i8`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("the exponent overflows an `i8`")
+    }
+}
+
+impl Div for DynQuantity {
+    type Output = Self;
+
+    /// Divide two quantities, subtracting their exponents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows an `i8`.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs)
+            .expect("the exponent overflows an `i8`")
+    }
+}
+
+impl Mul<f64> for DynQuantity {
+    type Output = Self;
+
+    /// Multiply the value of this quantity with a number.
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.value * rhs, self.dim)
+    }
+}
+
+impl Mul<DynQuantity> for f64 {
+    type Output = DynQuantity;
+
+    /// Multiply the value of this quantity with a number.
+    #[inline]
+    fn mul(self, rhs: DynQuantity) -> Self::Output {
+        DynQuantity::new(self * rhs.value, rhs.dim)
+    }
+}
+
+impl Div<f64> for DynQuantity {
+    type Output = Self;
+
+    /// Divides the value of this quantity with a number.
+    #[inline]
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.value / rhs, self.dim)
+    }
+}
+
+impl Neg for DynQuantity {
+    type Output = Self;
+
+    /// Negate the value of this quantity.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value, self.dim)
+    }
+}
+
+impl fmt::Display for DynQuantity {
+    /// Formats the value followed by the [`Unit`], like `9.81 m*kg/s^2`.
+    ///
+    /// The alternate flag (`{:#}`) uses the symbol of the named unit
+    /// from [`UnitInfo`] if there is one, like `9.81 N`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        match self.dim.info() {
+            _ if self.dim.is_dimensionless() => Ok(()),
+            Some(info) if f.alternate() => write!(f, " {}", info.symbol),
+            _ => write!(f, " {}", self.dim),
+        }
+    }
+}
+
+/// A statically typed quantity with its type erased.
+///
+/// Any quantity type can be converted into a [`DynQuantity`], which keeps
+/// the value and the dimension, so quantities of different types can be
+/// stored together. [`downcast`](DynQuantity::downcast) converts it back
+/// into a quantity type with the same dimension.
+///
+/// ```
+/// use tiny_uom::dynamic::AnyQuantity;
+/// use tiny_uom::values::m;
+/// use tiny_uom::Quantity;
+///
+/// type Force = Quantity<1, 1, -2, 0, 0, 0, 0>;
+///
+/// let readings = [AnyQuantity::from(Force::new(9.81)), AnyQuantity::from(2.0 * m)];
+/// assert_eq!(readings[0].downcast::<Force>(), Ok(Force::new(9.81)));
+/// assert!(readings[1].downcast::<Force>().is_err());
+/// assert_eq!(readings[1].to_string(), "2 m");
+/// ```
+pub type AnyQuantity = DynQuantity;
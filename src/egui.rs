@@ -0,0 +1,142 @@
+//! An [`egui`] widget to edit quantities.
+//!
+//! [`QuantityDragValue`] is a [`DragValue`] that shows the unit of the
+//! quantity as a suffix. The value can be shown in any unit using
+//! [`QuantityDragValue::unit`], and the text field also accepts values with
+//! a unit like `3 mi`, which are converted using the built-in
+//! [`UnitRegistry`](crate::registry::UnitRegistry).
+//!
+//! ```no_run
+//! use tiny_uom::egui::QuantityDragValue;
+//! use tiny_uom::values::m;
+//!
+//! # fn show(ui: &mut egui::Ui) {
+//! let mut altitude = 1500.0 * m;
+//! ui.add(QuantityDragValue::new(&mut altitude));
+//! ui.add(QuantityDragValue::new(&mut altitude).unit(1000.0 * m, "km"));
+//! # }
+//! ```
+//!
+//! [`egui`]: https://docs.rs/egui
+
+use crate::Quantity;
+use egui::{DragValue, Response, Ui, Widget};
+use std::ops::RangeInclusive;
+
+/// A [`DragValue`] for a quantity, showing its unit as a suffix.
+#[derive(Debug)]
+pub struct QuantityDragValue<'a, Q> {
+    quantity: &'a mut Q,
+    factor: f64,
+    suffix: String,
+    speed: Option<f64>,
+    range: Option<RangeInclusive<f64>>,
+}
+
+impl<
+        'a,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > QuantityDragValue<'a, Quantity<m, kg, s, A, K, mol, cd>>
+{
+    /// Create a widget editing the quantity in SI units.
+    ///
+    /// The suffix uses the symbol of the named unit if there is one, like `N`.
+    #[must_use]
+    pub fn new(quantity: &'a mut Quantity<m, kg, s, A, K, mol, cd>) -> Self {
+        let unit = Quantity::<m, kg, s, A, K, mol, cd>::UNIT;
+        let suffix = match unit.info() {
+            _ if unit.is_dimensionless() => String::new(),
+            Some(info) => format!(" {}", info.symbol),
+            None => format!(" {unit}"),
+        };
+
+        Self {
+            quantity,
+            factor: 1.0,
+            suffix,
+            speed: None,
+            range: None,
+        }
+    }
+
+    /// Show and edit the value as multiples of `unit`, using `symbol` as suffix.
+    #[must_use]
+    pub fn unit(mut self, unit: Quantity<m, kg, s, A, K, mol, cd>, symbol: &str) -> Self {
+        self.factor = unit.value.into();
+        self.suffix = format!(" {symbol}");
+        self
+    }
+
+    /// Set how much the value changes per pixel dragged, in the displayed unit.
+    ///
+    /// By default this is a hundredth of the order of magnitude of the value.
+    #[must_use]
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+
+    /// Clamp the value to the range while dragging.
+    #[must_use]
+    pub fn range(mut self, range: RangeInclusive<Quantity<m, kg, s, A, K, mol, cd>>) -> Self {
+        let (start, end) = range.into_inner();
+        self.range = Some(f64::from(start.value)..=f64::from(end.value));
+        self
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Widget for QuantityDragValue<'_, Quantity<m, kg, s, A, K, mol, cd>>
+{
+    #[allow(clippy::cast_possible_truncation)]
+    fn ui(self, ui: &mut Ui) -> Response {
+        let QuantityDragValue {
+            quantity,
+            factor,
+            suffix,
+            speed,
+            range,
+        } = self;
+
+        let shown = f64::from(quantity.value) / factor;
+        let speed = speed.unwrap_or_else(|| {
+            if shown == 0.0 || !shown.is_finite() {
+                0.01
+            } else {
+                10f64.powf(shown.abs().log10().floor() - 2.0)
+            }
+        });
+
+        let mut drag = DragValue::from_get_set(|value| {
+            if let Some(value) = value {
+                quantity.value = (value * factor) as f32;
+            }
+            f64::from(quantity.value) / factor
+        })
+        .suffix(suffix)
+        .speed(speed)
+        .custom_parser(move |text| {
+            text.trim().parse::<f64>().ok().or_else(|| {
+                let parsed = text.parse::<Quantity<m, kg, s, A, K, mol, cd>>().ok()?;
+                Some(f64::from(parsed.value) / factor)
+            })
+        });
+        if let Some(range) = range {
+            drag = drag.range(range.start() / factor..=range.end() / factor);
+        }
+        ui.add(drag)
+    }
+}
@@ -0,0 +1,35 @@
+//! Quantities using only the metre, kilogram, second, ampere and kelvin.
+//!
+//! Like [`mechanics`](crate::mechanics), but also covering electric and
+//! thermal quantities, for firmware that never uses the mole or the candela.
+//!
+//! ```
+//! use tiny_uom::electromechanics::{values::{A, K}, Quantity};
+//!
+//! let current = 2.0 * A - 0.5 * A;
+//! assert_eq!(current.to_string(), "1.5 A");
+//!
+//! let temperature: tiny_uom::Quantity<0, 0, 0, 0, 1, 0, 0> = (300.0 * K).into();
+//! assert_eq!(temperature, 300.0 * tiny_uom::values::K);
+//! assert_eq!(Quantity::<0, 0, 0, 0, 1>::UNIT, tiny_uom::Quantity::<0, 0, 0, 0, 1, 0, 0>::UNIT);
+//! ```
+
+#![allow(non_upper_case_globals)]
+
+crate::reduced::reduced_quantity!([m, kg, s, A, K], crate::Quantity<m, kg, s, A, K, 0, 0>);
+
+/// Constants for the multiplicative identities of each unit
+pub mod values {
+    use super::Quantity;
+
+    /// Time in seconds
+    pub const s: Quantity<0, 0, 1, 0, 0> = Quantity { value: 1.0 };
+    /// Length in metre
+    pub const m: Quantity<1, 0, 0, 0, 0> = Quantity { value: 1.0 };
+    /// Mass in kilogram
+    pub const kg: Quantity<0, 1, 0, 0, 0> = Quantity { value: 1.0 };
+    /// Electric current in ampere
+    pub const A: Quantity<0, 0, 0, 1, 0> = Quantity { value: 1.0 };
+    /// Temperature in kelvin
+    pub const K: Quantity<0, 0, 0, 0, 1> = Quantity { value: 1.0 };
+}
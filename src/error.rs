@@ -0,0 +1,276 @@
+//! Error types shared by the fallible operations of this crate.
+//!
+//! All errors implement [`core::error::Error`] and carry the [`Unit`]s
+//! involved, so they can be propagated using `?` and rendered for users.
+
+use crate::Unit;
+use core::fmt;
+
+/// A value had a different dimension than the one that was required.
+///
+/// The message names the quantities of units with a special name:
+///
+/// ```
+/// use tiny_uom::error::DimensionMismatch;
+/// use tiny_uom::Unit;
+///
+/// let metre = Unit { m: 1, ..Unit::DIMENSIONLESS };
+/// let second = Unit { s: 1, ..Unit::DIMENSIONLESS };
+/// let err = DimensionMismatch { expected: metre, found: second };
+/// assert_eq!(err.to_string(), "expected length (`m`), found time (`s`)");
+///
+/// let err = DimensionMismatch { expected: Unit { s: -1, ..metre }, found: second };
+/// assert_eq!(err.to_string(), "expected unit `m/s`, found time (`s`)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DimensionMismatch {
+    /// The unit that was required.
+    pub expected: Unit,
+    /// The unit that was found instead.
+    pub found: Unit,
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {}, found {}",
+            Described(self.expected),
+            Described(self.found)
+        )
+    }
+}
+
+/// Formats a unit with the name of its quantity if it has a special name, like ``length (`m`)``.
+struct Described(Unit);
+
+impl fmt::Display for Described {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.info() {
+            Some(info) => write!(f, "{} (`{}`)", info.quantity, self.0),
+            None => write!(f, "unit `{}`", self.0),
+        }
+    }
+}
+
+impl core::error::Error for DimensionMismatch {}
+
+/// An operation on [`DynQuantity`](crate::dynamic::DynQuantity)s failed
+/// because of the dimensions of its operands.
+///
+/// A mismatch keeps the exponents of both units, so applications can
+/// inspect them or show the message to their users:
+///
+/// ```
+/// use tiny_uom::dynamic::DynQuantity;
+/// use tiny_uom::error::DynamicError;
+/// use tiny_uom::Unit;
+///
+/// let speed = DynQuantity::new(3.0, Unit { m: 1, s: -1, ..Unit::DIMENSIONLESS });
+/// let mass = DynQuantity::new(2.0, Unit { kg: 1, ..Unit::DIMENSIONLESS });
+///
+/// let err = speed.checked_add(mass).unwrap_err();
+/// assert_eq!(err.to_string(), "expected unit `m/s`, found mass (`kg`)");
+/// let DynamicError::DimensionMismatch(mismatch) = err else { unreachable!() };
+/// assert_eq!(mismatch.found.exponents(), [0, 1, 0, 0, 0, 0, 0]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DynamicError {
+    /// The operands of an addition or subtraction have different dimensions.
+    DimensionMismatch(DimensionMismatch),
+    /// An exponent of the result does not fit into an `i8`.
+    ExponentOverflow,
+    /// The square root of a unit with an odd exponent was taken.
+    OddExponent(Unit),
+}
+
+impl fmt::Display for DynamicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicError::DimensionMismatch(err) => err.fmt(f),
+            DynamicError::ExponentOverflow => f.write_str("unit exponent overflowed"),
+            DynamicError::OddExponent(unit) => {
+                write!(f, "cannot take the square root of `{unit}`")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DynamicError {}
+
+impl From<DimensionMismatch> for DynamicError {
+    fn from(err: DimensionMismatch) -> Self {
+        DynamicError::DimensionMismatch(err)
+    }
+}
+
+/// An operation produced or received a value that is NaN or infinite.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NonFinite {
+    /// The offending value.
+    pub value: f64,
+    /// The unit of the offending value.
+    pub unit: Unit,
+}
+
+impl fmt::Display for NonFinite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value `{}` in `{}` is not finite", self.value, self.unit)
+    }
+}
+
+impl core::error::Error for NonFinite {}
+
+/// A value was too large, or had the wrong sign, for the type it was converted into.
+///
+/// ```
+/// use tiny_uom::error::OutOfRange;
+/// use tiny_uom::Unit;
+///
+/// let err = OutOfRange { value: -1.5, unit: Unit { s: 1, ..Unit::DIMENSIONLESS } };
+/// assert_eq!(err.to_string(), "value `-1.5` in `s` is out of range");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OutOfRange {
+    /// The offending value.
+    pub value: f64,
+    /// The unit of the offending value.
+    pub unit: Unit,
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value `{}` in `{}` is out of range",
+            self.value, self.unit
+        )
+    }
+}
+
+impl core::error::Error for OutOfRange {}
+
+/// A buffer was too small to format a value into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall;
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("buffer too small for the formatted value")
+    }
+}
+
+impl core::error::Error for BufferTooSmall {}
+
+/// An error that occurred while parsing a [`Quantity`](crate::Quantity) or a unit expression.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The numeric value could not be parsed.
+    InvalidNumber(core::num::ParseFloatError),
+    /// The unit expression is malformed at the given byte offset.
+    InvalidSyntax(usize),
+    /// The unit expression contains a symbol that is not known.
+    UnknownUnit(String),
+    /// An exponent of the resulting unit does not fit into an `i8`.
+    ExponentOverflow,
+    /// The unit expression has a different dimension than the quantity.
+    DimensionMismatch(DimensionMismatch),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidNumber(_) => f.write_str("invalid number"),
+            ParseError::InvalidSyntax(pos) => write!(f, "invalid unit expression at offset {pos}"),
+            ParseError::UnknownUnit(symbol) => write!(f, "unknown unit `{symbol}`"),
+            ParseError::ExponentOverflow => f.write_str("unit exponent overflowed"),
+            ParseError::DimensionMismatch(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseError::InvalidNumber(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<DimensionMismatch> for ParseError {
+    fn from(err: DimensionMismatch) -> Self {
+        ParseError::DimensionMismatch(err)
+    }
+}
+
+/// An error that occurred while converting a value between units, or
+/// into a type of another crate such as a `Duration`.
+///
+/// The `Parse` variant is only available with the `std` feature.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ConversionError {
+    /// One of the units could not be parsed.
+    #[cfg(feature = "std")]
+    Parse(ParseError),
+    /// The units have different dimensions.
+    DimensionMismatch(DimensionMismatch),
+    /// The converted value is NaN or infinite.
+    NonFinite(NonFinite),
+    /// The value does not fit into the target type.
+    OutOfRange(OutOfRange),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            ConversionError::Parse(err) => err.fmt(f),
+            ConversionError::DimensionMismatch(err) => err.fmt(f),
+            ConversionError::NonFinite(err) => err.fmt(f),
+            ConversionError::OutOfRange(err) => err.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for ConversionError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            ConversionError::Parse(err) => err.source(),
+            ConversionError::DimensionMismatch(_)
+            | ConversionError::NonFinite(_)
+            | ConversionError::OutOfRange(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ParseError> for ConversionError {
+    fn from(err: ParseError) -> Self {
+        ConversionError::Parse(err)
+    }
+}
+
+impl From<DimensionMismatch> for ConversionError {
+    fn from(err: DimensionMismatch) -> Self {
+        ConversionError::DimensionMismatch(err)
+    }
+}
+
+impl From<NonFinite> for ConversionError {
+    fn from(err: NonFinite) -> Self {
+        ConversionError::NonFinite(err)
+    }
+}
+
+impl From<OutOfRange> for ConversionError {
+    fn from(err: OutOfRange) -> Self {
+        ConversionError::OutOfRange(err)
+    }
+}
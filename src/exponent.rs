@@ -0,0 +1,77 @@
+//! Const functions for the arithmetic on exponents.
+//!
+//! These are the functions the crate uses to compute the exponents of
+//! derived quantities. Overflowing an `i8` panics, which fails to compile
+//! when they are evaluated in a constant.
+//!
+//! ```
+//! use tiny_uom::exponent::{add, mul, sub};
+//!
+//! const AREA: i8 = mul(1, 2);
+//! const VOLUME: i8 = add(AREA, 1);
+//! assert_eq!((AREA, VOLUME, sub(VOLUME, 3)), (2, 3, 0));
+//! ```
+//!
+//! With the unstable `generic_const_exprs` feature of a nightly compiler,
+//! they can be used in the bounds of code that is generic over the exponents:
+//!
+#![cfg_attr(feature = "nightly", doc = "```")]
+#![cfg_attr(not(feature = "nightly"), doc = "```ignore")]
+//! #![feature(generic_const_exprs)]
+//! #![allow(incomplete_features)]
+//! use tiny_uom::exponent::add;
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! fn product<const m1: i8, const m2: i8>(
+//!     a: Quantity<m1, 0, 0, 0, 0, 0, 0>,
+//!     b: Quantity<m2, 0, 0, 0, 0, 0, 0>,
+//! ) -> Quantity<{ add(m1, m2) }, 0, 0, 0, 0, 0, 0>
+//! where
+//!     Quantity<{ add(m1, m2) }, 0, 0, 0, 0, 0, 0>:,
+//! {
+//!     Quantity::new(a.raw() * b.raw())
+//! }
+//!
+//! let area: Quantity<2, 0, 0, 0, 0, 0, 0> = product(2.0 * m, 3.0 * m);
+//! assert_eq!(area.raw(), 6.0);
+//! ```
+
+/// Add two exponents, like the exponents of the factors of a product.
+///
+/// # Panics
+///
+/// Panics if the result overflows an `i8`.
+#[must_use]
+pub const fn add(lhs: i8, rhs: i8) -> i8 {
+    match lhs.checked_add(rhs) {
+        Some(exp) => exp,
+        None => panic!("the exponent overflows an `i8`"),
+    }
+}
+
+/// Subtract two exponents, like the exponents of the dividend and the divisor of a ratio.
+///
+/// # Panics
+///
+/// Panics if the result overflows an `i8`.
+#[must_use]
+pub const fn sub(lhs: i8, rhs: i8) -> i8 {
+    match lhs.checked_sub(rhs) {
+        Some(exp) => exp,
+        None => panic!("the exponent overflows an `i8`"),
+    }
+}
+
+/// Multiply an exponent, like the exponents of a quantity raised to the power of `rhs`.
+///
+/// # Panics
+///
+/// Panics if the result overflows an `i8`.
+#[must_use]
+pub const fn mul(lhs: i8, rhs: i8) -> i8 {
+    match lhs.checked_mul(rhs) {
+        Some(exp) => exp,
+        None => panic!("the exponent overflows an `i8`"),
+    }
+}
@@ -0,0 +1,91 @@
+//! Arithmetic that fails instead of producing values that are NaN or infinite,
+//! for code that must not silently propagate them.
+
+use crate::error::NonFinite;
+use crate::Quantity;
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Returns `self` if its value is finite.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonFinite`] if the value is NaN or infinite.
+    #[inline]
+    pub fn finite(self) -> Result<Self, NonFinite> {
+        if self.value.is_finite() {
+            Ok(self)
+        } else {
+            Err(NonFinite {
+                value: f64::from(self.value),
+                unit: Self::UNIT,
+            })
+        }
+    }
+
+    /// Add two quantities, failing instead of returning a value that is not finite.
+    ///
+    /// ```
+    /// use tiny_uom::values::m;
+    ///
+    /// assert_eq!((1.0 * m).try_add(2.0 * m), Ok(3.0 * m));
+    /// assert!((f32::MAX * m).try_add(f32::MAX * m).is_err());
+    /// assert!((f32::NAN * m).try_add(2.0 * m).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonFinite`] if the sum is NaN or infinite.
+    #[inline]
+    pub fn try_add(self, rhs: Self) -> Result<Self, NonFinite> {
+        (self + rhs).finite()
+    }
+
+    /// Subtract two quantities, failing instead of returning a value that is not finite.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonFinite`] if the difference is NaN or infinite.
+    #[inline]
+    pub fn try_sub(self, rhs: Self) -> Result<Self, NonFinite> {
+        (self - rhs).finite()
+    }
+
+    /// Multiply this quantity with a number, failing instead of returning a
+    /// value that is not finite.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonFinite`] if the product is NaN or infinite.
+    #[inline]
+    pub fn try_mul(self, rhs: f32) -> Result<Self, NonFinite> {
+        (self * rhs).finite()
+    }
+
+    /// Divide this quantity by a number, failing on a division by zero
+    /// instead of returning a value that is not finite.
+    ///
+    /// ```
+    /// use tiny_uom::values::s;
+    ///
+    /// assert_eq!((3.0 * s).try_div(2.0), Ok(1.5 * s));
+    /// assert!((3.0 * s).try_div(0.0).is_err());
+    /// assert!((0.0 * s).try_div(0.0).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonFinite`] if the quotient is NaN or infinite.
+    #[inline]
+    pub fn try_div(self, rhs: f32) -> Result<Self, NonFinite> {
+        (self / rhs).finite()
+    }
+}
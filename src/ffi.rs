@@ -0,0 +1,148 @@
+//! A C interface for lengths, times, masses and temperatures.
+//!
+//! Every quantity is passed as a `#[repr(C)]` struct with a single `float`
+//! field that holds the value in SI units, so the C compiler keeps them
+//! apart just like the Rust compiler does. The structs have the same size
+//! and alignment as a `float`.
+//!
+//! The header can be generated using [`cbindgen`](https://github.com/mozilla/cbindgen)
+//! with the `cbindgen.toml` in the root of the repository. The functions are
+//! generated by a macro, so `cbindgen` has to expand the crate, which needs a
+//! nightly compiler:
+//!
+//! ```text
+//! $ rustup run nightly cbindgen --config cbindgen.toml --output tiny_uom.h
+//! ```
+//!
+//! ```
+//! use tiny_uom::ffi::*;
+//! use tiny_uom::values::m;
+//!
+//! let length = tu_length_add(tu_length_from_km(1.5), tu_length_from_m(20.0));
+//! assert_eq!(tu_length_to_m(length), 1520.0);
+//! assert_eq!(tiny_uom::Quantity::from(length), 1520.0 * m);
+//!
+//! let temperature = tu_temperature_from_celsius(25.0);
+//! assert_eq!(tu_temperature_to_k(temperature), 298.15);
+//! ```
+// `#[no_mangle]` is reported by `unsafe_code`, nothing in this module is unsafe otherwise.
+#![allow(unsafe_code)]
+
+use crate::Quantity;
+
+/// Define a `#[repr(C)]` struct for a quantity, its `From` conversions
+/// and the C functions to convert it from and to the given units.
+macro_rules! ffi_quantity {
+    (
+        $(#[$doc:meta])*
+        $name:ident($quantity:ty, $add:ident, $sub:ident) {
+            $($unit:literal => $factor:literal: $from:ident, $to:ident;)+
+        }
+    ) => {
+        $(#[$doc])*
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        pub struct $name {
+            /// The value in SI units.
+            pub value: f32,
+        }
+
+        impl From<$quantity> for $name {
+            fn from(quantity: $quantity) -> Self {
+                Self {
+                    value: quantity.value,
+                }
+            }
+        }
+
+        impl From<$name> for $quantity {
+            fn from(quantity: $name) -> Self {
+                Quantity::new(quantity.value)
+            }
+        }
+
+        #[doc = concat!(" Add two values of `", stringify!($name), "`.")]
+        #[no_mangle]
+        #[must_use]
+        pub extern "C" fn $add(lhs: $name, rhs: $name) -> $name {
+            (<$quantity>::from(lhs) + <$quantity>::from(rhs)).into()
+        }
+
+        #[doc = concat!(" Subtract two values of `", stringify!($name), "`.")]
+        #[no_mangle]
+        #[must_use]
+        pub extern "C" fn $sub(lhs: $name, rhs: $name) -> $name {
+            (<$quantity>::from(lhs) - <$quantity>::from(rhs)).into()
+        }
+
+        $(
+            #[doc = concat!(" Create a `", stringify!($name), "` from a value in `", $unit, "`.")]
+            #[no_mangle]
+            #[must_use]
+            pub extern "C" fn $from(value: f32) -> $name {
+                $name {
+                    value: value * $factor,
+                }
+            }
+
+            #[doc = concat!(" Returns the value of a `", stringify!($name), "` in `", $unit, "`.")]
+            #[no_mangle]
+            #[must_use]
+            pub extern "C" fn $to(quantity: $name) -> f32 {
+                quantity.value / $factor
+            }
+        )+
+    };
+}
+
+ffi_quantity! {
+    /// A length, in metres.
+    TuLength(Quantity<1, 0, 0, 0, 0, 0, 0>, tu_length_add, tu_length_sub) {
+        "m" => 1.0: tu_length_from_m, tu_length_to_m;
+        "km" => 1000.0: tu_length_from_km, tu_length_to_km;
+        "mm" => 0.001: tu_length_from_mm, tu_length_to_mm;
+    }
+}
+
+ffi_quantity! {
+    /// A time, in seconds.
+    TuTime(Quantity<0, 0, 1, 0, 0, 0, 0>, tu_time_add, tu_time_sub) {
+        "s" => 1.0: tu_time_from_s, tu_time_to_s;
+        "ms" => 0.001: tu_time_from_ms, tu_time_to_ms;
+        "h" => 3600.0: tu_time_from_h, tu_time_to_h;
+    }
+}
+
+ffi_quantity! {
+    /// A mass, in kilograms.
+    TuMass(Quantity<0, 1, 0, 0, 0, 0, 0>, tu_mass_add, tu_mass_sub) {
+        "kg" => 1.0: tu_mass_from_kg, tu_mass_to_kg;
+        "g" => 0.001: tu_mass_from_g, tu_mass_to_g;
+    }
+}
+
+ffi_quantity! {
+    /// A thermodynamic temperature, in kelvin.
+    TuTemperature(Quantity<0, 0, 0, 0, 1, 0, 0>, tu_temperature_add, tu_temperature_sub) {
+        "K" => 1.0: tu_temperature_from_k, tu_temperature_to_k;
+    }
+}
+
+/// The temperature in kelvin at zero degrees Celsius.
+const ZERO_CELSIUS: f32 = 273.15;
+
+/// Create a `TuTemperature` from a value in degrees Celsius.
+#[no_mangle]
+#[must_use]
+pub extern "C" fn tu_temperature_from_celsius(value: f32) -> TuTemperature {
+    TuTemperature {
+        value: value + ZERO_CELSIUS,
+    }
+}
+
+/// Returns the value of a `TuTemperature` in degrees Celsius.
+#[no_mangle]
+#[must_use]
+pub extern "C" fn tu_temperature_to_celsius(quantity: TuTemperature) -> f32 {
+    quantity.value - ZERO_CELSIUS
+}
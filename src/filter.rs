@@ -0,0 +1,122 @@
+//! Filters that smooth streams of quantities, like noisy sensor readings.
+//!
+//! ```
+//! use tiny_uom::filter::{LowPass, MovingAverage};
+//! use tiny_uom::values::{s, K};
+//!
+//! let mut average = MovingAverage::<_, 3>::new();
+//! assert_eq!(average.push(290.0 * K), 290.0 * K);
+//! assert_eq!(average.push(293.0 * K), 291.5 * K);
+//! average.push(296.0 * K);
+//! assert_eq!(average.push(299.0 * K), 296.0 * K);
+//!
+//! let mut low_pass = LowPass::new(0.9 * s);
+//! assert_eq!(low_pass.update(290.0 * K, 0.1 * s), 290.0 * K);
+//! assert_eq!(low_pass.update(300.0 * K, 0.1 * s), 291.0 * K);
+//! ```
+
+use crate::{Dimensioned, Quantity};
+use core::marker::PhantomData;
+
+/// The simple moving average of the last `N` samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MovingAverage<Q, const N: usize> {
+    window: [f32; N],
+    next: usize,
+    len: usize,
+    quantity: PhantomData<Q>,
+}
+
+impl<Q: Dimensioned<Scalar = f32>, const N: usize> MovingAverage<Q, N> {
+    /// Create a moving average without any samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window is empty.
+    #[must_use]
+    pub const fn new() -> Self {
+        assert!(N > 0, "the window must not be empty");
+        Self {
+            window: [0.0; N],
+            next: 0,
+            len: 0,
+            quantity: PhantomData,
+        }
+    }
+
+    /// Add a sample, replacing the oldest one if the window is full,
+    /// and return the new average.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn push(&mut self, sample: Q) -> Q {
+        self.window[self.next] = sample.raw();
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+        let sum: f32 = self.window[..self.len].iter().sum();
+        Q::from_raw(sum / self.len as f32)
+    }
+
+    /// Returns the average of the samples in the window, or `None` if there are none.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average(&self) -> Option<Q> {
+        let sum: f32 = self.window[..self.len].iter().sum();
+        (self.len > 0).then(|| Q::from_raw(sum / self.len as f32))
+    }
+
+    /// Remove all samples.
+    pub fn reset(&mut self) {
+        self.next = 0;
+        self.len = 0;
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>, const N: usize> Default for MovingAverage<Q, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A first-order low-pass filter, smoothing samples exponentially with a time constant.
+///
+/// The first sample initializes the output. Every following sample taken
+/// `dt` after the previous one moves the output by `dt / (tau + dt)` of the
+/// way towards the sample.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LowPass<Q> {
+    tau: Quantity<0, 0, 1, 0, 0, 0, 0>,
+    output: Option<Q>,
+}
+
+impl<Q: Dimensioned<Scalar = f32>> LowPass<Q> {
+    /// Create a filter with the time constant `tau`.
+    #[must_use]
+    #[inline]
+    pub const fn new(tau: Quantity<0, 0, 1, 0, 0, 0, 0>) -> Self {
+        Self { tau, output: None }
+    }
+
+    /// Filter a sample taken `dt` after the previous one and return the new output.
+    pub fn update(&mut self, sample: Q, dt: Quantity<0, 0, 1, 0, 0, 0, 0>) -> Q {
+        let output = match self.output {
+            Some(output) => {
+                let alpha = dt.value / (self.tau.value + dt.value);
+                Q::from_raw(output.raw() + alpha * (sample.raw() - output.raw()))
+            }
+            None => sample,
+        };
+        self.output = Some(output);
+        output
+    }
+
+    /// Returns the current output, or `None` before the first sample.
+    #[must_use]
+    #[inline]
+    pub fn output(&self) -> Option<Q> {
+        self.output
+    }
+
+    /// Forget the output, so the next sample initializes it again.
+    pub fn reset(&mut self) {
+        self.output = None;
+    }
+}
@@ -0,0 +1,54 @@
+//! Formatting quantities into byte buffers without allocating.
+
+use crate::{error::BufferTooSmall, Quantity};
+use core::fmt::{self, Write};
+
+/// A [`Write`] that fills a byte buffer from the start.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        let dst = self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+        dst.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Format this quantity like its [`Display`](fmt::Display) output into `buf`,
+    /// returning the written part of the buffer.
+    ///
+    /// ```
+    /// use tiny_uom::values::{m, s};
+    ///
+    /// let mut buf = [0; 16];
+    /// assert_eq!((2.5 * (m / s)).format_into(&mut buf), Ok("2.5 m/s"));
+    /// assert!((2.5 * (m / s)).format_into(&mut buf[..4]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output does not fit into `buf`.
+    pub fn format_into(self, buf: &mut [u8]) -> Result<&str, BufferTooSmall> {
+        let mut writer = SliceWriter { buf, len: 0 };
+        write!(writer, "{self}").map_err(|_| BufferTooSmall)?;
+
+        let SliceWriter { buf, len } = writer;
+        // Only whole `str`s were written, so this can not fail.
+        core::str::from_utf8(&buf[..len]).map_err(|_| BufferTooSmall)
+    }
+}
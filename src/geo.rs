@@ -0,0 +1,56 @@
+//! Distances and bearings between [`geo`] points as quantities.
+//!
+//! The points are longitude and latitude in degrees. Distances are
+//! lengths in metres and bearings are angles in radians, measured
+//! clockwise from north.
+//!
+//! ```
+//! use geo::{point, Geodesic, Haversine};
+//! use std::f32::consts::FRAC_PI_2;
+//! use tiny_uom::geo::{bearing, distance};
+//! use tiny_uom::values::m;
+//!
+//! let equator = point!(x: 0.0, y: 0.0);
+//! let east = point!(x: 1.0, y: 0.0);
+//!
+//! let length = distance(&Geodesic, equator, east);
+//! assert!((length - 111_319.49 * m).raw().abs() < 0.1);
+//! assert!((distance(&Haversine, equator, east) - length).raw().abs() < 200.0);
+//! assert!((bearing(&Haversine, equator, east).raw() - FRAC_PI_2).abs() < 1e-6);
+//! ```
+//!
+//! [`geo`]: https://docs.rs/geo
+
+use crate::Quantity;
+use geo::{Bearing, Distance, GeodesicMeasure, HaversineMeasure, Point, Rhumb};
+
+/// A metric space on longitude and latitude in degrees that measures distances in metres.
+///
+/// This is implemented for [`Haversine`](geo::Haversine), [`Geodesic`](geo::Geodesic) and [`Rhumb`].
+pub trait GeographicMetric: Distance<f64, Point<f64>, Point<f64>> + Bearing<f64> {}
+
+impl GeographicMetric for HaversineMeasure {}
+impl GeographicMetric for GeodesicMeasure {}
+impl GeographicMetric for Rhumb {}
+
+/// Returns the distance between two points.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn distance<M: GeographicMetric>(
+    metric: &M,
+    origin: Point<f64>,
+    destination: Point<f64>,
+) -> Quantity<1, 0, 0, 0, 0, 0, 0> {
+    Quantity::new(metric.distance(origin, destination) as f32)
+}
+
+/// Returns the bearing from `origin` to `destination`, between zero and two pi.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn bearing<M: GeographicMetric>(
+    metric: &M,
+    origin: Point<f64>,
+    destination: Point<f64>,
+) -> Quantity<0, 0, 0, 0, 0, 0, 0> {
+    Quantity::new(metric.bearing(origin, destination).to_radians() as f32)
+}
@@ -0,0 +1,276 @@
+//! Interoperability with [`glam`] vectors.
+//!
+//! [`QVec3`] keeps the unit of a [`Vec3`] in its type, while the values are
+//! stored in a plain `Vec3` in SI units. Multiplying a `Vec3` with a quantity
+//! attaches the unit, and [`QVec3::to_glam_in`] removes it again at the
+//! boundary to glam-based code. Vectors of quantities that do not need glam
+//! are [`linalg::QVec3`](crate::linalg::QVec3).
+//!
+//! ```
+//! use glam::Vec3;
+//! use tiny_uom::values::m;
+//!
+//! let position = Vec3::new(1.0, 2.0, 3.0) * (1000.0 * m);
+//! let offset = Vec3::X * m;
+//!
+//! let moved = position + offset;
+//! assert_eq!(moved.x(), 1001.0 * m);
+//! assert_eq!(moved.to_glam_in(1000.0 * m), Vec3::new(1.001, 2.0, 3.0));
+//! ```
+//!
+//! [`glam`]: https://docs.rs/glam
+
+use crate::Quantity;
+use glam::Vec3;
+use std::{
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+/// A [`Vec3`] whose components are quantities of type `Q`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QVec3<Q> {
+    /// The raw values of the components in SI units.
+    pub value: Vec3,
+    unit: PhantomData<Q>,
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > QVec3<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    /// Create a vector from its components.
+    #[must_use]
+    pub fn new(
+        x: Quantity<m, kg, s, A, K, mol, cd>,
+        y: Quantity<m, kg, s, A, K, mol, cd>,
+        z: Quantity<m, kg, s, A, K, mol, cd>,
+    ) -> Self {
+        Self::from_glam(Vec3::new(x.value, y.value, z.value))
+    }
+
+    /// Create a vector from raw values in SI units.
+    #[must_use]
+    pub const fn from_glam(value: Vec3) -> Self {
+        Self {
+            value,
+            unit: PhantomData,
+        }
+    }
+
+    /// Returns the raw values in SI units.
+    #[must_use]
+    pub const fn to_glam(self) -> Vec3 {
+        self.value
+    }
+
+    /// Returns the values as multiples of `unit`, like `to_glam_in(1000.0 * m)`
+    /// for a vector in kilometres.
+    #[must_use]
+    pub fn to_glam_in(self, unit: Quantity<m, kg, s, A, K, mol, cd>) -> Vec3 {
+        self.value / unit.value
+    }
+
+    /// Returns the x component.
+    #[must_use]
+    pub const fn x(self) -> Quantity<m, kg, s, A, K, mol, cd> {
+        Quantity::new(self.value.x)
+    }
+
+    /// Returns the y component.
+    #[must_use]
+    pub const fn y(self) -> Quantity<m, kg, s, A, K, mol, cd> {
+        Quantity::new(self.value.y)
+    }
+
+    /// Returns the z component.
+    #[must_use]
+    pub const fn z(self) -> Quantity<m, kg, s, A, K, mol, cd> {
+        Quantity::new(self.value.z)
+    }
+
+    /// Returns the length of the vector.
+    #[must_use]
+    pub fn length(self) -> Quantity<m, kg, s, A, K, mol, cd> {
+        Quantity::new(self.value.length())
+    }
+
+    /// Returns the distance between two points.
+    #[must_use]
+    pub fn distance(self, rhs: Self) -> Quantity<m, kg, s, A, K, mol, cd> {
+        Quantity::new(self.value.distance(rhs.value))
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Mul<Quantity<m, kg, s, A, K, mol, cd>> for Vec3
+{
+    type Output = QVec3<Quantity<m, kg, s, A, K, mol, cd>>;
+
+    /// Attach a unit to a vector.
+    #[inline]
+    fn mul(self, rhs: Quantity<m, kg, s, A, K, mol, cd>) -> Self::Output {
+        QVec3::from_glam(self * rhs.value)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Mul<Vec3> for Quantity<m, kg, s, A, K, mol, cd>
+{
+    type Output = QVec3<Quantity<m, kg, s, A, K, mol, cd>>;
+
+    /// Attach a unit to a vector.
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        QVec3::from_glam(self.value * rhs)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Add for QVec3<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    type Output = Self;
+
+    /// Add two vectors of the same unit.
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_glam(self.value + rhs.value)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > AddAssign for QVec3<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    /// Add two vectors of the same unit.
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Sub for QVec3<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    type Output = Self;
+
+    /// Subtract two vectors of the same unit.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_glam(self.value - rhs.value)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > SubAssign for QVec3<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    /// Subtract two vectors of the same unit.
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Neg for QVec3<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    type Output = Self;
+
+    /// Negate every component of the vector.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_glam(-self.value)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Mul<f32> for QVec3<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    type Output = Self;
+
+    /// Multiply every component of the vector with a number.
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::from_glam(self.value * rhs)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Div<f32> for QVec3<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    type Output = Self;
+
+    /// Divide every component of the vector by a number.
+    #[inline]
+    fn div(self, rhs: f32) -> Self::Output {
+        Self::from_glam(self.value / rhs)
+    }
+}
@@ -0,0 +1,112 @@
+//! Conversions between time quantities and [`hifitime`] durations.
+//!
+//! Quantities store their value in an `f32`, which can not represent
+//! the time since an epoch with nanosecond precision. Compute the
+//! difference of two `Epoch`s using `hifitime` first, and convert only
+//! the resulting [`Duration`] into a quantity.
+//!
+//! ```
+//! use hifitime::{Duration, Epoch};
+//! use tiny_uom::values::{m, s};
+//! use tiny_uom::Quantity;
+//!
+//! let start = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+//! let end = start + Duration::from_seconds(90.5);
+//!
+//! let elapsed = Quantity::from(end - start);
+//! assert_eq!(elapsed, 90.5 * s);
+//! assert_eq!((181.0 * m) / elapsed, 2.0 * (m / s));
+//!
+//! assert_eq!((-2.0 * s).to_hifitime_duration(), Ok(Duration::from_seconds(-2.0)));
+//! assert!((f32::NAN * s).to_hifitime_duration().is_err());
+//! ```
+//!
+//! The `f32` of a [`Quantity`] resolves only about 0.1 ms at one day.
+//! A [`DynQuantity`] stores its value in an `f64`, which keeps durations
+//! of up to about 48 days exact to the nanosecond, and checks their unit
+//! when converting them back:
+//!
+//! ```
+//! use hifitime::{Duration, Epoch};
+//! use tiny_uom::dynamic::DynQuantity;
+//! use tiny_uom::values::m;
+//!
+//! let start = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+//! let end = start + Duration::from_days(30.0) + Duration::from_nanoseconds(123_456_789.0);
+//!
+//! let elapsed = DynQuantity::from(end - start);
+//! assert_eq!(elapsed.value, 30.0 * 86_400.0 + 0.123_456_789);
+//! assert_eq!(elapsed.to_hifitime_duration(), Ok(end - start));
+//!
+//! let tick = DynQuantity::from(Duration::from_days(1.0) + Duration::from_nanoseconds(1.0));
+//! assert_eq!(tick.to_hifitime_duration(), Ok(Duration::from_days(1.0) + Duration::from_nanoseconds(1.0)));
+//! assert!(DynQuantity::from(2.0 * m).to_hifitime_duration().is_err());
+//! ```
+//!
+//! [`hifitime`]: https://docs.rs/hifitime
+
+use crate::duration::duration_error;
+use crate::dynamic::DynQuantity;
+use crate::error::{ConversionError, DimensionMismatch, NonFinite};
+use crate::Quantity;
+use hifitime::Duration;
+
+/// The unit of time.
+const SECOND: crate::Unit = Quantity::<0, 0, 1, 0, 0, 0, 0>::UNIT;
+
+impl From<Duration> for Quantity<0, 0, 1, 0, 0, 0, 0> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(duration: Duration) -> Self {
+        Quantity::new(duration.to_seconds() as f32)
+    }
+}
+
+impl Quantity<0, 0, 1, 0, 0, 0, 0> {
+    /// Convert this time into a [`hifitime::Duration`], which may be negative.
+    ///
+    /// Times beyond the range of a `Duration` saturate to its minimum or maximum.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::NonFinite`] if the time is not finite.
+    pub fn to_hifitime_duration(self) -> Result<Duration, ConversionError> {
+        if !self.value.is_finite() {
+            return Err(duration_error(self.value));
+        }
+        Ok(Duration::from_seconds(self.value.into()))
+    }
+}
+
+impl From<Duration> for DynQuantity {
+    fn from(duration: Duration) -> Self {
+        DynQuantity::new(duration.to_seconds(), SECOND)
+    }
+}
+
+impl DynQuantity {
+    /// Convert this time into a [`hifitime::Duration`], which may be negative.
+    ///
+    /// Times beyond the range of a `Duration` saturate to its minimum or maximum.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::DimensionMismatch`] if this is not a time,
+    /// and [`ConversionError::NonFinite`] if its value is not finite.
+    pub fn to_hifitime_duration(self) -> Result<Duration, ConversionError> {
+        if self.dim != SECOND {
+            return Err(DimensionMismatch {
+                expected: SECOND,
+                found: self.dim,
+            }
+            .into());
+        }
+        if !self.value.is_finite() {
+            return Err(NonFinite {
+                value: self.value,
+                unit: self.dim,
+            }
+            .into());
+        }
+        Ok(Duration::from_seconds(self.value))
+    }
+}
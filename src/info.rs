@@ -0,0 +1,115 @@
+//! Metadata about the named units of the SI.
+
+use crate::Unit;
+
+/// The symbol, name and quantity of a named unit of the [`SI`].
+///
+/// ```
+/// use tiny_uom::{Unit, UnitInfo};
+///
+/// let newton = UnitInfo::from_symbol("N").unwrap();
+/// assert_eq!(newton.name, "newton");
+/// assert_eq!(newton.quantity, "force");
+/// assert_eq!(UnitInfo::from_unit(newton.unit), Some(newton));
+/// ```
+///
+/// [`SI`]: https://jcgm.bipm.org/vim/en/1.16.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnitInfo {
+    /// The symbol of the unit, like `N`.
+    pub symbol: &'static str,
+    /// The name of the unit, like `newton`.
+    pub name: &'static str,
+    /// The name of the quantity that is measured in this unit, like `force`.
+    pub quantity: &'static str,
+    /// The exponents of the SI base units that make up this unit.
+    pub unit: Unit,
+}
+
+const fn info(
+    symbol: &'static str,
+    name: &'static str,
+    quantity: &'static str,
+    exps: [i8; 7],
+) -> UnitInfo {
+    UnitInfo {
+        symbol,
+        name,
+        quantity,
+        unit: Unit::from_exponents(exps),
+    }
+}
+
+#[rustfmt::skip]
+pub(crate) static UNITS: &[UnitInfo] = &[
+    // base units
+    info("m", "metre", "length", [1, 0, 0, 0, 0, 0, 0]),
+    info("kg", "kilogram", "mass", [0, 1, 0, 0, 0, 0, 0]),
+    info("s", "second", "time", [0, 0, 1, 0, 0, 0, 0]),
+    info("A", "ampere", "electric current", [0, 0, 0, 1, 0, 0, 0]),
+    info("K", "kelvin", "thermodynamic temperature", [0, 0, 0, 0, 1, 0, 0]),
+    info("mol", "mole", "amount of substance", [0, 0, 0, 0, 0, 1, 0]),
+    info("cd", "candela", "luminous intensity", [0, 0, 0, 0, 0, 0, 1]),
+    // derived units with special names
+    info("Hz", "hertz", "frequency", [0, 0, -1, 0, 0, 0, 0]),
+    info("N", "newton", "force", [1, 1, -2, 0, 0, 0, 0]),
+    info("Pa", "pascal", "pressure", [-1, 1, -2, 0, 0, 0, 0]),
+    info("J", "joule", "energy", [2, 1, -2, 0, 0, 0, 0]),
+    info("W", "watt", "power", [2, 1, -3, 0, 0, 0, 0]),
+    info("C", "coulomb", "electric charge", [0, 0, 1, 1, 0, 0, 0]),
+    info("V", "volt", "electric potential difference", [2, 1, -3, -1, 0, 0, 0]),
+    info("F", "farad", "capacitance", [-2, -1, 4, 2, 0, 0, 0]),
+    info("Ω", "ohm", "electric resistance", [2, 1, -3, -2, 0, 0, 0]),
+    info("S", "siemens", "electric conductance", [-2, -1, 3, 2, 0, 0, 0]),
+    info("Wb", "weber", "magnetic flux", [2, 1, -2, -1, 0, 0, 0]),
+    info("T", "tesla", "magnetic flux density", [0, 1, -2, -1, 0, 0, 0]),
+    info("H", "henry", "inductance", [2, 1, -2, -2, 0, 0, 0]),
+    info("lm", "lumen", "luminous flux", [0, 0, 0, 0, 0, 0, 1]),
+    info("lx", "lux", "illuminance", [-2, 0, 0, 0, 0, 0, 1]),
+    info("Bq", "becquerel", "activity", [0, 0, -1, 0, 0, 0, 0]),
+    info("Gy", "gray", "absorbed dose", [2, 0, -2, 0, 0, 0, 0]),
+    info("Sv", "sievert", "dose equivalent", [2, 0, -2, 0, 0, 0, 0]),
+    info("kat", "katal", "catalytic activity", [0, 0, -1, 0, 0, 1, 0]),
+];
+
+impl UnitInfo {
+    /// Returns the base units and all derived units with a special name.
+    #[must_use]
+    pub fn all() -> &'static [UnitInfo] {
+        UNITS
+    }
+
+    /// Look up a unit by its symbol.
+    #[must_use]
+    pub fn from_symbol(symbol: &str) -> Option<&'static UnitInfo> {
+        UNITS.iter().find(|info| info.symbol == symbol)
+    }
+
+    /// Look up a unit by its name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<&'static UnitInfo> {
+        UNITS.iter().find(|info| info.name == name)
+    }
+
+    /// Look up the named unit with the given exponents.
+    ///
+    /// Some units share their exponents, like hertz and becquerel.
+    /// In that case the more common one is returned.
+    #[must_use]
+    pub fn from_unit(unit: Unit) -> Option<&'static UnitInfo> {
+        UNITS.iter().find(|info| info.unit == unit)
+    }
+
+    /// Returns all named units with the given exponents.
+    pub fn all_from_unit(unit: Unit) -> impl Iterator<Item = &'static UnitInfo> {
+        UNITS.iter().filter(move |info| info.unit == unit)
+    }
+}
+
+impl Unit {
+    /// Returns the metadata of the named unit with these exponents, if there is one.
+    #[must_use]
+    pub fn info(self) -> Option<&'static UnitInfo> {
+        UnitInfo::from_unit(self)
+    }
+}
@@ -0,0 +1,196 @@
+//! Interpolation between quantities.
+//!
+//! ```
+//! use tiny_uom::interpolate::{inverse_lerp, lerp, remap};
+//! use tiny_uom::values::{m, s};
+//! use tiny_uom::Quantity;
+//!
+//! let half = Quantity::new(0.5);
+//! assert_eq!(lerp(2.0 * m, 4.0 * m, half), 3.0 * m);
+//! assert_eq!(inverse_lerp(2.0 * m, 4.0 * m, 3.0 * m), half);
+//!
+//! // map the position of a slider onto the duration of an animation
+//! let duration = remap(3.0 * m, (2.0 * m, 4.0 * m), (10.0 * s, 20.0 * s));
+//! assert_eq!(duration, 15.0 * s);
+//! ```
+//!
+//! A [`LookupTable`] interpolates between measured points, like the
+//! resistance of a sensor at a set of temperatures.
+
+use crate::{Dimensioned, Quantity};
+
+/// Returns the quantity at the fraction `t` of the way from `a` to `b`.
+///
+/// Values of `t` outside of `0..=1` extrapolate beyond the endpoints.
+#[must_use]
+#[inline]
+pub fn lerp<Q: Dimensioned<Scalar = f32>>(a: Q, b: Q, t: Quantity<0, 0, 0, 0, 0, 0, 0>) -> Q {
+    Q::from_raw(a.raw() + (b.raw() - a.raw()) * t.value)
+}
+
+/// Returns the quantity halfway between `a` and `b`.
+///
+/// ```
+/// use tiny_uom::interpolate::midpoint;
+/// use tiny_uom::values::m;
+///
+/// assert_eq!(midpoint(2.0 * m, 5.0 * m), 3.5 * m);
+/// ```
+#[must_use]
+#[inline]
+pub fn midpoint<Q: Dimensioned<Scalar = f32>>(a: Q, b: Q) -> Q {
+    Q::from_raw(a.raw().midpoint(b.raw()))
+}
+
+/// Returns the fraction of the way from `a` to `b` at which `value` lies,
+/// the inverse of [`lerp`].
+///
+/// The fraction is not finite if `a` and `b` are equal.
+#[must_use]
+#[inline]
+pub fn inverse_lerp<Q: Dimensioned<Scalar = f32>>(
+    a: Q,
+    b: Q,
+    value: Q,
+) -> Quantity<0, 0, 0, 0, 0, 0, 0> {
+    Quantity::new((value.raw() - a.raw()) / (b.raw() - a.raw()))
+}
+
+/// Map a value from the range `from` onto the range `to`, which may have
+/// a different dimension.
+#[must_use]
+#[inline]
+pub fn remap<Q: Dimensioned<Scalar = f32>, R: Dimensioned<Scalar = f32>>(
+    value: Q,
+    from: (Q, Q),
+    to: (R, R),
+) -> R {
+    lerp(to.0, to.1, inverse_lerp(from.0, from.1, value))
+}
+
+/// A table of points `(x, y)` that interpolates `y` for any `x`.
+///
+/// The quantities of `x` must be strictly increasing. Outside of the
+/// table, the first or the last `y` is returned, and NaN is returned for
+/// an `x` of NaN.
+///
+/// ```
+/// use tiny_uom::interpolate::LookupTable;
+/// use tiny_uom::Quantity;
+///
+/// type Temperature = Quantity<0, 0, 0, 0, 1, 0, 0>;
+/// type Resistance = Quantity<2, 1, -3, -2, 0, 0, 0>;
+///
+/// let pt100 = LookupTable::new([
+///     (Temperature::new(273.15), Resistance::new(100.0)),
+///     (Temperature::new(323.15), Resistance::new(119.4)),
+///     (Temperature::new(373.15), Resistance::new(138.5)),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(pt100.linear(Temperature::new(298.15)), Resistance::new(109.7));
+/// assert_eq!(pt100.linear(Temperature::new(200.0)), Resistance::new(100.0));
+/// assert!((pt100.cubic(Temperature::new(298.15)).raw() - 109.72).abs() < 0.01);
+/// assert!(pt100.linear(Temperature::new(f32::NAN)).raw().is_nan());
+/// assert!(pt100.cubic(Temperature::new(f32::NAN)).raw().is_nan());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LookupTable<X, Y, const N: usize> {
+    points: [(X, Y); N],
+}
+
+impl<X: Dimensioned<Scalar = f32>, Y: Dimensioned<Scalar = f32>, const N: usize>
+    LookupTable<X, Y, N>
+{
+    /// Create a table from its points, or `None` if it has less than two
+    /// points or the quantities of `x` are not strictly increasing.
+    #[must_use]
+    pub fn new(points: [(X, Y); N]) -> Option<Self> {
+        let increasing = points
+            .windows(2)
+            .all(|pair| pair[0].0.raw() < pair[1].0.raw());
+        (N >= 2 && increasing).then_some(Self { points })
+    }
+
+    /// Returns the points of this table.
+    #[must_use]
+    #[inline]
+    pub const fn points(&self) -> &[(X, Y); N] {
+        &self.points
+    }
+
+    /// Returns the index of the segment containing `x`, or the value
+    /// at the end of the table if `x` is outside of it, or NaN if `x` is NaN.
+    fn segment(&self, x: f32) -> Result<usize, Y> {
+        if x.is_nan() {
+            return Err(Y::from_raw(f32::NAN));
+        }
+        let (first, last) = (self.points[0], self.points[N - 1]);
+        if x <= first.0.raw() {
+            return Err(first.1);
+        }
+        if x >= last.0.raw() {
+            return Err(last.1);
+        }
+        Ok(self.points.partition_point(|point| point.0.raw() <= x) - 1)
+    }
+
+    /// Returns the slope of the segment starting at the point `idx`.
+    fn secant(&self, idx: usize) -> f32 {
+        let ((x0, y0), (x1, y1)) = (self.points[idx], self.points[idx + 1]);
+        (y1.raw() - y0.raw()) / (x1.raw() - x0.raw())
+    }
+
+    /// Returns the slope of the monotone cubic interpolation at the point `idx`,
+    /// following Fritsch and Carlson.
+    fn tangent(&self, idx: usize) -> f32 {
+        if idx == 0 {
+            return self.secant(0);
+        }
+        if idx == N - 1 {
+            return self.secant(N - 2);
+        }
+        let (before, after) = (self.secant(idx - 1), self.secant(idx));
+        if before * after <= 0.0 {
+            return 0.0;
+        }
+        let h0 = self.points[idx].0.raw() - self.points[idx - 1].0.raw();
+        let h1 = self.points[idx + 1].0.raw() - self.points[idx].0.raw();
+        let (w0, w1) = (2.0 * h1 + h0, h1 + 2.0 * h0);
+        (w0 + w1) / (w0 / before + w1 / after)
+    }
+
+    /// Returns `y` at `x`, interpolating linearly between the points.
+    #[must_use]
+    pub fn linear(&self, x: X) -> Y {
+        match self.segment(x.raw()) {
+            Ok(idx) => {
+                let ((x0, y0), (x1, y1)) = (self.points[idx], self.points[idx + 1]);
+                lerp(y0, y1, inverse_lerp(x0, x1, x))
+            }
+            Err(end) => end,
+        }
+    }
+
+    /// Returns `y` at `x`, interpolating with a monotone cubic spline.
+    ///
+    /// The spline passes through all points and does not overshoot them,
+    /// so a monotone table is interpolated monotonically.
+    #[must_use]
+    pub fn cubic(&self, x: X) -> Y {
+        match self.segment(x.raw()) {
+            Ok(idx) => {
+                let ((x0, y0), (x1, y1)) = (self.points[idx], self.points[idx + 1]);
+                let h = x1.raw() - x0.raw();
+                let t = (x.raw() - x0.raw()) / h;
+                let (t2, t3) = (t * t, t * t * t);
+                let y = (2.0 * t3 - 3.0 * t2 + 1.0) * y0.raw()
+                    + (t3 - 2.0 * t2 + t) * h * self.tangent(idx)
+                    + (-2.0 * t3 + 3.0 * t2) * y1.raw()
+                    + (t3 - t2) * h * self.tangent(idx + 1);
+                Y::from_raw(y)
+            }
+            Err(end) => end,
+        }
+    }
+}
@@ -0,0 +1,278 @@
+//! Kinds of quantities that share their exponents, like frequency and activity.
+//!
+//! The hertz, the becquerel and the radian per second all have the exponents
+//! of `1/s`, and the joule and the newton metre both have the exponents of
+//! an energy. A [`Kinded`] quantity carries a [`Kind`] marker in its type, so
+//! quantities of different kinds can not be mixed up, and changing the kind
+//! needs an explicit [`cast`](Kinded::cast).
+//!
+//! ```
+//! use tiny_uom::kind::{Activity, Frequency, Kinded};
+//! use tiny_uom::Quantity;
+//!
+//! let per_second = Quantity::<0, 0, -1, 0, 0, 0, 0>::new(1.0);
+//!
+//! let clock = Kinded::<_, Frequency>::new(50.0 * per_second);
+//! let sample = Kinded::<_, Activity>::new(3.7e10 * per_second);
+//! assert_eq!((clock + clock).to_string(), "100 Hz");
+//! assert_eq!(sample.to_string(), "37000000000 Bq");
+//!
+//! let activity: Kinded<_, Activity> = clock.cast();
+//! assert_eq!(activity.quantity, 50.0 * per_second);
+//! ```
+//!
+//! A kind names the quantity it applies to, so a quantity of other
+//! exponents can not be given that kind:
+//!
+//! ```compile_fail
+//! use tiny_uom::kind::{Frequency, Kinded};
+//! use tiny_uom::values::m;
+//!
+//! let clock = Kinded::<_, Frequency>::new(5.0 * m);
+//! ```
+//!
+//! ```compile_fail
+//! use tiny_uom::kind::{Energy, Frequency, Kinded};
+//! use tiny_uom::Quantity;
+//!
+//! let work = Kinded::<_, Energy>::new(Quantity::<2, 1, -2, 0, 0, 0, 0>::new(1.0));
+//! let clock: Kinded<_, Frequency> = work.cast();
+//! ```
+//!
+//! ```compile_fail
+//! use tiny_uom::kind::{Activity, Frequency, Kinded};
+//! use tiny_uom::Quantity;
+//!
+//! let per_second = Quantity::<0, 0, -1, 0, 0, 0, 0>::new(1.0);
+//! let sum = Kinded::<_, Frequency>::new(per_second) + Kinded::<_, Activity>::new(per_second);
+//! ```
+//!
+//! Applications can define their own kinds:
+//!
+//! ```
+//! use tiny_uom::kind::{Kind, Kinded};
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! struct Altitude;
+//!
+//! impl Kind for Altitude {
+//!     type Quantity = Quantity<1, 0, 0, 0, 0, 0, 0>;
+//!     const SYMBOL: Option<&'static str> = Some("m AMSL");
+//! }
+//!
+//! let (everest, k2) = (
+//!     Kinded::<_, Altitude>::new(8848.0 * m),
+//!     Kinded::<_, Altitude>::new(8611.0 * m),
+//! );
+//! assert_eq!(everest.to_string(), "8848 m AMSL");
+//! assert!(k2 < everest);
+//! assert_eq!(everest, Kinded::new(8848.0 * m));
+//! ```
+
+use crate::Quantity;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A marker for the kind of a [`Kinded`] quantity.
+pub trait Kind: Copy {
+    /// The quantity that quantities of this kind are, like `1/s` for a frequency.
+    type Quantity: Copy;
+
+    /// The symbol used to format quantities of this kind, like `Hz`.
+    ///
+    /// Quantities of a kind without a symbol are formatted like the quantity.
+    const SYMBOL: Option<&'static str> = None;
+}
+
+macro_rules! kinds {
+    ($($(#[$meta:meta])* $kind:ident: $quantity:ty => $symbol:literal,)+) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $kind;
+
+            impl Kind for $kind {
+                type Quantity = $quantity;
+                const SYMBOL: Option<&'static str> = Some($symbol);
+            }
+        )+
+    };
+}
+
+kinds! {
+    /// The number of cycles per second, in hertz.
+    Frequency: Quantity<0, 0, -1, 0, 0, 0, 0> => "Hz",
+    /// The number of decays per second, in becquerel.
+    Activity: Quantity<0, 0, -1, 0, 0, 0, 0> => "Bq",
+    /// The change of an angle per second, in radian per second.
+    AngularVelocity: Quantity<0, 0, -1, 0, 0, 0, 0> => "rad/s",
+    /// An amount of energy, in joule.
+    Energy: Quantity<2, 1, -2, 0, 0, 0, 0> => "J",
+    /// A moment of force, in newton metre.
+    Torque: Quantity<2, 1, -2, 0, 0, 0, 0> => "N*m",
+    /// An absorbed dose of ionizing radiation, in gray.
+    AbsorbedDose: Quantity<2, 0, -2, 0, 0, 0, 0> => "Gy",
+    /// An equivalent dose of ionizing radiation, in sievert.
+    DoseEquivalent: Quantity<2, 0, -2, 0, 0, 0, 0> => "Sv",
+}
+
+/// A quantity of type `Q` of the kind `K`, where `Q` is the quantity of the kind.
+///
+/// Comparing and debug-formatting only need `Q` to support them, so the kind
+/// can be any marker type.
+#[derive(Clone, Copy)]
+pub struct Kinded<Q, K> {
+    /// The quantity regardless of its kind.
+    pub quantity: Q,
+    kind: PhantomData<K>,
+}
+
+impl<Q, K: Kind<Quantity = Q>> Kinded<Q, K> {
+    /// Mark a quantity as being of the kind `K`.
+    #[must_use]
+    #[inline]
+    pub const fn new(quantity: Q) -> Self {
+        Self {
+            quantity,
+            kind: PhantomData,
+        }
+    }
+
+    /// Change the kind of this quantity into `T`, which must be a kind of the same quantity.
+    #[must_use]
+    #[inline]
+    pub fn cast<T: Kind<Quantity = Q>>(self) -> Kinded<Q, T> {
+        Kinded::new(self.quantity)
+    }
+}
+
+impl<Q: PartialEq, K> PartialEq for Kinded<Q, K> {
+    /// Compare two quantities of the same kind.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.quantity == other.quantity
+    }
+}
+
+impl<Q: PartialOrd, K> PartialOrd for Kinded<Q, K> {
+    /// Compare two quantities of the same kind.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.quantity.partial_cmp(&other.quantity)
+    }
+}
+
+impl<Q: fmt::Debug, K> fmt::Debug for Kinded<Q, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Kinded")
+            .field("quantity", &self.quantity)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+impl<Q: Default, K: Kind<Quantity = Q>> Default for Kinded<Q, K> {
+    /// A quantity of the kind `K` with a value of zero.
+    fn default() -> Self {
+        Self::new(Q::default())
+    }
+}
+
+impl<Q: Add<Output = Q>, K: Kind<Quantity = Q>> Add for Kinded<Q, K> {
+    type Output = Self;
+
+    /// Add two quantities of the same kind.
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.quantity + rhs.quantity)
+    }
+}
+
+impl<Q: AddAssign, K: Kind<Quantity = Q>> AddAssign for Kinded<Q, K> {
+    /// Add two quantities of the same kind.
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.quantity += rhs.quantity;
+    }
+}
+
+impl<Q: Sub<Output = Q>, K: Kind<Quantity = Q>> Sub for Kinded<Q, K> {
+    type Output = Self;
+
+    /// Subtract two quantities of the same kind.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.quantity - rhs.quantity)
+    }
+}
+
+impl<Q: SubAssign, K: Kind<Quantity = Q>> SubAssign for Kinded<Q, K> {
+    /// Subtract two quantities of the same kind.
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.quantity -= rhs.quantity;
+    }
+}
+
+impl<Q: Mul<f32, Output = Q>, K: Kind<Quantity = Q>> Mul<f32> for Kinded<Q, K> {
+    type Output = Self;
+
+    /// Multiply the quantity with a number.
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::new(self.quantity * rhs)
+    }
+}
+
+impl<Q: MulAssign<f32>, K: Kind<Quantity = Q>> MulAssign<f32> for Kinded<Q, K> {
+    /// Multiply the quantity with a number.
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        self.quantity *= rhs;
+    }
+}
+
+impl<Q: Div<f32, Output = Q>, K: Kind<Quantity = Q>> Div<f32> for Kinded<Q, K> {
+    type Output = Self;
+
+    /// Divide the quantity by a number.
+    #[inline]
+    fn div(self, rhs: f32) -> Self::Output {
+        Self::new(self.quantity / rhs)
+    }
+}
+
+impl<Q: DivAssign<f32>, K: Kind<Quantity = Q>> DivAssign<f32> for Kinded<Q, K> {
+    /// Divide the quantity by a number.
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        self.quantity /= rhs;
+    }
+}
+
+impl<Q: Neg<Output = Q>, K: Kind<Quantity = Q>> Neg for Kinded<Q, K> {
+    type Output = Self;
+
+    /// Negate the quantity.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.quantity)
+    }
+}
+
+impl<Q: crate::Dimensioned<Scalar = f32> + fmt::Display, K: Kind> fmt::Display for Kinded<Q, K> {
+    /// Formats the value followed by the symbol of the kind, like `50 Hz`,
+    /// or like the quantity if the kind has no symbol.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match K::SYMBOL {
+            Some(symbol) => {
+                fmt::Display::fmt(&self.quantity.raw(), f)?;
+                write!(f, " {symbol}")
+            }
+            None => fmt::Display::fmt(&self.quantity, f),
+        }
+    }
+}
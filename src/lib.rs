@@ -16,6 +16,9 @@
 //!
 //! let velocity = distance / time;
 //! assert_eq!(velocity, 5.0 * (m / s));
+//!
+//! let steps = 10;
+//! assert_eq!(steps * time, 20.0 * s);
 //! # }
 //! ```
 //!
@@ -34,12 +37,264 @@
     unsafe_code
 )]
 #![allow(non_upper_case_globals)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(adt_const_params))]
+
+#[cfg(any(feature = "quickcheck", feature = "schemars"))]
+extern crate alloc;
 
-use std::clone::Clone;
+use core::clone::Clone;
+use core::fmt;
 
+pub use dimension::{Dimensioned, SameDimension, Si};
+#[cfg(feature = "std")]
+pub use error::ParseError as ParseQuantityError;
+pub use info::UnitInfo;
 pub use si::values;
 
+/// Derive unit-annotated constructors and getters for a struct of quantities.
+///
+/// `from_units` creates the struct from raw values, where each field is
+/// given in the unit of its `#[quantity(unit = "...")]` attribute, or in SI
+/// units without one. Fields with a unit also get a getter returning the raw
+/// value in that unit, named like `speed_as_km_per_h`, or after the
+/// `getter = "..."` attribute. Units may use every unit of the built-in
+/// [`UnitRegistry`](registry::UnitRegistry), and an unknown unit or a unit of
+/// the wrong dimension fails to compile.
+///
+/// ```
+/// use tiny_uom::{Quantity, QuantityStruct};
+///
+/// #[derive(QuantityStruct)]
+/// struct Limits {
+///     #[quantity(unit = "km/h")]
+///     speed: Quantity<1, 0, -1, 0, 0, 0, 0>,
+///     #[quantity(unit = "°", getter = "pitch_in_degrees")]
+///     pitch: Quantity<0, 0, 0, 0, 0, 0, 0>,
+///     length: Quantity<1, 0, 0, 0, 0, 0, 0>,
+/// }
+///
+/// let limits = Limits::from_units(36.0, 90.0, 4.5);
+/// assert_eq!(limits.speed.raw(), 10.0);
+/// assert_eq!(limits.speed_as_km_per_h(), 36.0);
+/// assert_eq!(limits.pitch_in_degrees(), 90.0);
+/// assert_eq!(limits.length.raw(), 4.5);
+/// ```
+///
+/// `#[quantity(serde)]` on the struct implements `Serialize` and `Deserialize`
+/// using `tiny_uom::serde::with_unit` for every field, which requires the
+/// `serde` feature and a dependency on `serde`.
+///
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// use tiny_uom::{Quantity, QuantityStruct};
+///
+/// #[derive(QuantityStruct)]
+/// #[quantity(serde)]
+/// struct Reading {
+///     #[quantity(unit = "km/h")]
+///     speed: Quantity<1, 0, -1, 0, 0, 0, 0>,
+///     length: Quantity<1, 0, 0, 0, 0, 0, 0>,
+/// }
+///
+/// let reading = Reading::from_units(36.0, 4.5);
+/// let json = serde_json::to_string(&reading).unwrap();
+/// assert_eq!(json, r#"{"speed":"10 m/s","length":"4.5 m"}"#);
+/// ```
+///
+/// ```compile_fail
+/// use tiny_uom::{Quantity, QuantityStruct};
+///
+/// #[derive(QuantityStruct)]
+/// struct Limits {
+///     #[quantity(unit = "km/hr")]
+///     speed: Quantity<1, 0, -1, 0, 0, 0, 0>,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use tiny_uom::{Quantity, QuantityStruct};
+///
+/// #[derive(QuantityStruct)]
+/// struct Limits {
+///     #[quantity(unit = "km")]
+///     speed: Quantity<1, 0, -1, 0, 0, 0, 0>,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use tiny_uom_macros::QuantityStruct;
+
+/// Create a quantity from a number literal followed by a unit, like `qty!(9.81 m/s^2)`.
+///
+/// The type and the value in SI units are computed at compile time, so it
+/// can also be used in constants. Units may use every unit of the built-in
+/// [`UnitRegistry`](registry::UnitRegistry) with SI prefixes, combined using
+/// `*`, `/`, integer powers with `^` and parentheses. Units that are not
+/// identifiers, like `°`, need one of their aliases, like `deg`.
+///
+/// ```
+/// use tiny_uom::values::{m, s};
+/// use tiny_uom::{qty, Quantity};
+///
+/// const G: Quantity<1, 0, -2, 0, 0, 0, 0> = qty!(9.81 m/s^2);
+/// assert_eq!(G.raw(), 9.81);
+///
+/// assert_eq!(qty!(36 km/h), 10.0 * (m / s));
+/// assert_eq!(qty!(-2.5 mm), -0.0025 * m);
+/// assert_eq!(qty!(1.5 kN*m), Quantity::<2, 1, -2, 0, 0, 0, 0>::new(1500.0));
+/// assert_eq!(qty!(12 in).raw(), 0.3048);
+/// assert_eq!(qty!(50 %).raw(), 0.5);
+/// ```
+///
+/// An unknown unit fails to compile, and so does a literal of the wrong dimension:
+///
+/// ```compile_fail
+/// use tiny_uom::{qty, Quantity};
+///
+/// let length: Quantity<1, 0, 0, 0, 0, 0, 0> = qty!(3 s);
+/// ```
+///
+/// ```compile_fail
+/// let length = tiny_uom::qty!(3 parsec);
+/// ```
+#[cfg(feature = "derive")]
+pub use tiny_uom_macros::qty;
+
+/// Fail to compile if an expression does not have the dimension of a unit,
+/// like `assert_dim!(force, "kg*m/s^2")`.
+///
+/// The unit is parsed at compile time like by [`UnitRegistry::parse_unit`]
+/// of the built-in registry, so it may also use units that are not
+/// identifiers, like `°`. The macro evaluates to the value of the
+/// expression. Use it to document the dimensions of the intermediate
+/// results in formula-heavy code.
+///
+/// ```
+/// use tiny_uom::values::{m, s};
+/// use tiny_uom::{assert_dim, qty};
+///
+/// let velocity = assert_dim!((10.0 * m) / (2.0 * s), "km/h");
+/// assert_eq!(velocity.raw(), 5.0);
+///
+/// let force = qty!(19.62 N);
+/// assert_dim!(force, "kg*m/s^2");
+/// assert_dim!(qty!(90 deg), "°");
+/// ```
+///
+/// [`UnitRegistry::parse_unit`]: registry::UnitRegistry::parse_unit
+///
+/// ```compile_fail
+/// use tiny_uom::values::m;
+/// use tiny_uom::assert_dim;
+///
+/// assert_dim!(3.0 * m, "s");
+/// ```
+#[cfg(feature = "derive")]
+pub use tiny_uom_macros::assert_dim;
+
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+mod assert;
+#[cfg(feature = "std")]
+pub mod buckingham;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+pub mod calculus;
+pub mod calibration;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "defmt")]
+mod defmt;
+mod dimension;
+#[cfg(feature = "dimensioned")]
+mod dimensioned;
+mod duration;
+pub mod dynamic;
+#[cfg(feature = "egui")]
+pub mod egui;
+#[cfg(feature = "electromechanics")]
+pub mod electromechanics;
+pub mod error;
+pub mod exponent;
+mod fallible;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod filter;
+mod format;
+#[cfg(feature = "geo")]
+pub mod geo;
+#[cfg(feature = "glam")]
+pub mod glam;
+#[cfg(feature = "hifitime")]
+mod hifitime;
+mod info;
+pub mod interpolate;
+pub mod kind;
+pub mod linalg;
+#[doc(hidden)]
+pub mod macro_support;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod math;
+#[cfg(feature = "mechanics")]
+pub mod mechanics;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+#[cfg(feature = "num-traits")]
+mod num;
+#[cfg(feature = "std")]
+mod parse;
+#[cfg(feature = "plotters")]
+pub mod plotters;
+pub mod point;
+pub mod prefix;
+pub mod prelude;
+pub mod quantities;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+pub mod rand;
+pub mod range;
+#[cfg(feature = "rational")]
+pub mod rational;
+#[cfg(any(feature = "mechanics", feature = "electromechanics"))]
+mod reduced;
+#[cfg(feature = "std")]
+pub mod registry;
+#[cfg(feature = "rhai")]
+pub mod rhai;
+pub mod scale;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "encase")]
+mod shader;
 mod si;
+pub mod sort;
+#[cfg(feature = "sqlx")]
+mod sql;
+pub mod stats;
+mod system;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "ucum")]
+pub mod ucum;
+#[cfg(feature = "nightly")]
+pub mod unit_param;
+#[cfg(feature = "uom")]
+mod uom;
+#[cfg(feature = "std")]
+pub mod vec;
+#[cfg(kani)]
+mod verification;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "wide")]
+pub mod wide;
 
 /// The `Unit` struct can represent every possible unit
 /// that is defined in the [`SI`] system.
@@ -65,138 +320,948 @@ mod si;
 /// ```
 ///
 /// [`SI`]: https://jcgm.bipm.org/vim/en/1.16.html
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "nightly", derive(core::marker::ConstParamTy))]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(default)
+)]
+pub struct Unit {
+    /// Exponent of the metre
+    pub m: i8,
+    /// Exponent of the kilogram
+    pub kg: i8,
+    /// Exponent of the second
+    pub s: i8,
+    /// Exponent of the ampere
+    pub A: i8,
+    /// Exponent of the kelvin
+    pub K: i8,
+    /// Exponent of the mole
+    pub mol: i8,
+    /// Exponent of the candela
+    pub cd: i8,
+}
 
-/// Implement all methods and traits for a quantity type.
-macro_rules! quantity_impl {
-    ($backing_ty:ty, $quantity:ident, $unit_exp_ty:ty, $($unit:ident),+) => {
-        /// A `Quantity` represents a raw value and it's unit
-        /// that is represented as a const generic parameter.
-        #[derive(Clone, Copy, Debug, PartialEq)]
-        #[repr(transparent)]
-        pub struct $quantity<$(const $unit: $unit_exp_ty,)*> {
-            /// The raw value of this `Quantity`
-            pub value: $backing_ty,
+impl Unit {
+    /// The unit of a dimensionless quantity, where every exponent is zero.
+    pub const DIMENSIONLESS: Unit = Unit {
+        m: 0,
+        kg: 0,
+        s: 0,
+        A: 0,
+        K: 0,
+        mol: 0,
+        cd: 0,
+    };
+
+    /// Returns the exponents of this unit in the order of the
+    /// const parameters of [`Quantity`].
+    #[must_use]
+    pub const fn exponents(self) -> [i8; 7] {
+        [self.m, self.kg, self.s, self.A, self.K, self.mol, self.cd]
+    }
+
+    /// Create a unit from exponents in the order of the
+    /// const parameters of [`Quantity`].
+    #[must_use]
+    pub const fn from_exponents(exps: [i8; 7]) -> Self {
+        Unit {
+            m: exps[0],
+            kg: exps[1],
+            s: exps[2],
+            A: exps[3],
+            K: exps[4],
+            mol: exps[5],
+            cd: exps[6],
         }
-        impl<$(const $unit: $unit_exp_ty,)*> $quantity<$($unit,)*> {
-            /// Create a new `Quantity` with the given value.
-            #[must_use]
-            pub const fn new(value: $backing_ty) -> Self {
-                Self { value }
+    }
+
+    /// Returns `true` if every exponent of this unit is zero.
+    #[must_use]
+    pub const fn is_dimensionless(self) -> bool {
+        self.m == 0
+            && self.kg == 0
+            && self.s == 0
+            && self.A == 0
+            && self.K == 0
+            && self.mol == 0
+            && self.cd == 0
+    }
+
+    /// Multiply two units by adding their exponents,
+    /// returning `None` if an exponent overflows.
+    #[must_use]
+    pub fn checked_mul(self, rhs: Unit) -> Option<Unit> {
+        let (lhs, rhs) = (self.exponents(), rhs.exponents());
+        let mut out = [0; 7];
+        for i in 0..7 {
+            out[i] = lhs[i].checked_add(rhs[i])?;
+        }
+        Some(Unit::from_exponents(out))
+    }
+
+    /// Raise this unit to an integer power by multiplying its exponents,
+    /// returning `None` if an exponent overflows.
+    #[must_use]
+    pub fn checked_powi(self, n: i8) -> Option<Unit> {
+        let mut out = self.exponents();
+        for exp in &mut out {
+            *exp = exp.checked_mul(n)?;
+        }
+        Some(Unit::from_exponents(out))
+    }
+}
+
+/// The symbols of the SI base units in the order of the const parameters of [`Quantity`].
+const SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+impl Unit {
+    /// Split the base units into the numerator and the denominator used for formatting.
+    ///
+    /// The exponents of the denominator are negated.
+    pub(crate) fn fraction(
+        self,
+    ) -> (
+        impl Iterator<Item = (&'static str, i8)> + Clone,
+        impl Iterator<Item = (&'static str, i8)> + Clone,
+    ) {
+        // `i8::MIN` can not be negated, so it stays in the numerator like `s^-128`.
+        let exps = self.exponents();
+        let numerator = SYMBOLS
+            .into_iter()
+            .zip(exps)
+            .filter(|&(_, exp)| exp > 0 || exp == i8::MIN);
+        let denominator = SYMBOLS
+            .into_iter()
+            .zip(exps)
+            .filter(|&(_, exp)| exp < 0 && exp != i8::MIN)
+            .map(|(symbol, exp)| (symbol, -exp));
+        (numerator, denominator)
+    }
+}
+
+impl fmt::Display for Unit {
+    /// Formats the unit using the symbols of the SI base units, like `m*kg/s^2`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_product(
+            f: &mut fmt::Formatter<'_>,
+            factors: impl Iterator<Item = (&'static str, i8)>,
+        ) -> fmt::Result {
+            for (idx, (symbol, exp)) in factors.enumerate() {
+                if idx > 0 {
+                    f.write_str("*")?;
+                }
+                f.write_str(symbol)?;
+                if exp != 1 {
+                    write!(f, "^{exp}")?;
+                }
+            }
+            Ok(())
+        }
+
+        let (numerator, denominator) = self.fraction();
+        if numerator.clone().next().is_some() {
+            write_product(f, numerator)?;
+        } else {
+            f.write_str("1")?;
+        }
+
+        match denominator.clone().count() {
+            0 => Ok(()),
+            1 => {
+                f.write_str("/")?;
+                write_product(f, denominator)
+            }
+            _ => {
+                f.write_str("/(")?;
+                write_product(f, denominator)?;
+                f.write_str(")")
             }
         }
+    }
+}
 
-        impl<$(const $unit: $unit_exp_ty,)*> ::std::fmt::Display for $quantity<$($unit,)*> {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                write!(f, "{} * {:?}", self.value, &[$($unit,)*])
+/// Implement the constructor and the arithmetic operators for a quantity type
+/// over a custom scalar type.
+///
+/// This is the operator set of [`Quantity`], for a struct with one const
+/// parameter per base unit and a public `value` field. The arguments are the
+/// type of the value, the name of the struct, the type of the exponents and
+/// the names of the const parameters, in this order. It implements `new`,
+/// the addition, subtraction and remainder of equal units, the negation, and
+/// the multiplication and division by the scalar type. It also implements
+/// [`SameDimension`] for the struct, which the operators of equal units
+/// require of their right-hand side.
+///
+/// The scalar type must be `Copy` and implement the operators with itself
+/// as the right-hand side, and the assigning versions of them:
+///
+/// ```
+/// #![allow(non_upper_case_globals)]
+/// use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+///
+/// /// A fixed-point number with 16 fractional bits.
+/// #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+/// pub struct Fixed(i32);
+///
+/// impl Fixed {
+///     pub const fn from_int(value: i32) -> Self {
+///         Self(value << 16)
+///     }
+/// }
+///
+/// impl Mul for Fixed {
+///     type Output = Self;
+///
+///     fn mul(self, rhs: Self) -> Self {
+///         Self(((i64::from(self.0) * i64::from(rhs.0)) >> 16) as i32)
+///     }
+/// }
+///
+/// impl Div for Fixed {
+///     type Output = Self;
+///
+///     fn div(self, rhs: Self) -> Self {
+///         Self(((i64::from(self.0) << 16) / i64::from(rhs.0)) as i32)
+///     }
+/// }
+/// # impl Add for Fixed {
+/// #     type Output = Self;
+/// #     fn add(self, rhs: Self) -> Self { Self(self.0 + rhs.0) }
+/// # }
+/// # impl Sub for Fixed {
+/// #     type Output = Self;
+/// #     fn sub(self, rhs: Self) -> Self { Self(self.0 - rhs.0) }
+/// # }
+/// # impl Rem for Fixed {
+/// #     type Output = Self;
+/// #     fn rem(self, rhs: Self) -> Self { Self(self.0 % rhs.0) }
+/// # }
+/// # impl Neg for Fixed {
+/// #     type Output = Self;
+/// #     fn neg(self) -> Self { Self(-self.0) }
+/// # }
+/// # impl core::ops::AddAssign for Fixed {
+/// #     fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+/// # }
+/// # impl core::ops::SubAssign for Fixed {
+/// #     fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+/// # }
+/// # impl core::ops::MulAssign for Fixed {
+/// #     fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+/// # }
+/// # impl core::ops::DivAssign for Fixed {
+/// #     fn div_assign(&mut self, rhs: Self) { *self = *self / rhs; }
+/// # }
+///
+/// /// A quantity with a length and a time dimension in fixed-point.
+/// #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+/// pub struct Quantity<const m: i8, const s: i8> {
+///     pub value: Fixed,
+/// }
+///
+/// tiny_uom::quantity_ops!(Fixed, Quantity, i8, m, s);
+///
+/// let length = Quantity::<1, 0>::new(Fixed::from_int(3)) + Quantity::new(Fixed::from_int(5));
+/// assert_eq!(length / Fixed::from_int(2), Quantity::new(Fixed::from_int(4)));
+/// assert_eq!(-(Fixed::from_int(2) * length), Quantity::new(Fixed::from_int(-16)));
+/// ```
+///
+/// Which derives and traits the struct gets, like `Display` or conversions
+/// into [`Quantity`], is up to the struct. For custom base dimensions with
+/// the `f32` of this crate, [`unit_system!`] defines all of it.
+#[macro_export]
+macro_rules! quantity_ops {
+    // Everything but `SameDimension`, for the `Quantity` of this crate, which implements it itself.
+    (@ops $backing_ty:ty, $quantity:ident, $unit_exp_ty:ty, $($unit:ident),+) => {
+        impl<$(const $unit: $unit_exp_ty,)*> $quantity<$($unit,)*> {
+            /// Create a new `Quantity` with the given value.
+            #[must_use]
+            #[inline]
+            pub const fn new(__value: $backing_ty) -> Self {
+                Self { value: __value }
             }
         }
 
         // ============================
         // Add implementations
         // ============================
-        impl<$(const $unit: $unit_exp_ty,)*> ::std::ops::Add<$quantity<$($unit,)*>> for $quantity<$($unit,)*> {
+        impl<$(const $unit: $unit_exp_ty,)* __R: $crate::SameDimension<Self>> ::core::ops::Add<__R> for $quantity<$($unit,)*> {
             type Output = Self;
 
             /// Add the value of two equal units.
-            fn add(self, rhs: Self) -> Self::Output {
+            #[inline]
+            fn add(self, __rhs: __R) -> Self::Output {
                 Self {
-                    value: self.value + rhs.value,
+                    value: self.value + __rhs.into_same().value,
                 }
             }
         }
 
-        impl<$(const $unit: $unit_exp_ty,)*> ::std::ops::AddAssign<$quantity<$($unit,)*>> for $quantity<$($unit,)*> {
+        impl<$(const $unit: $unit_exp_ty,)* __R: $crate::SameDimension<Self>> ::core::ops::AddAssign<__R> for $quantity<$($unit,)*> {
             /// Add the value of two equal units.
-            fn add_assign(&mut self, rhs: Self) {
-                self.value += rhs.value;
+            #[inline]
+            fn add_assign(&mut self, __rhs: __R) {
+                self.value += __rhs.into_same().value;
             }
         }
 
         // ============================
         // Sub implementations
         // ============================
-        impl<$(const $unit: $unit_exp_ty,)*> ::std::ops::Sub<$quantity<$($unit,)*>> for $quantity<$($unit,)*> {
+        impl<$(const $unit: $unit_exp_ty,)* __R: $crate::SameDimension<Self>> ::core::ops::Sub<__R> for $quantity<$($unit,)*> {
             type Output = Self;
 
             /// Subtract the value of two equal units.
-            fn sub(self, rhs: Self) -> Self::Output {
+            #[inline]
+            fn sub(self, __rhs: __R) -> Self::Output {
                 Self {
-                    value: self.value - rhs.value,
+                    value: self.value - __rhs.into_same().value,
                 }
             }
         }
 
-        impl<$(const $unit: $unit_exp_ty,)*> ::std::ops::SubAssign<$quantity<$($unit,)*>> for $quantity<$($unit,)*> {
+        impl<$(const $unit: $unit_exp_ty,)* __R: $crate::SameDimension<Self>> ::core::ops::SubAssign<__R> for $quantity<$($unit,)*> {
             /// Subtract the value of two equal units.
-            fn sub_assign(&mut self, rhs: Self) {
-                self.value -= rhs.value;
+            #[inline]
+            fn sub_assign(&mut self, __rhs: __R) {
+                self.value -= __rhs.into_same().value;
             }
         }
 
         // ============================
         // Mul implementations
         // ============================
-        impl<$(const $unit: $unit_exp_ty,)*> ::std::ops::Mul<$backing_ty> for $quantity<$($unit,)*> {
+        impl<$(const $unit: $unit_exp_ty,)*> ::core::ops::Mul<$backing_ty> for $quantity<$($unit,)*> {
             type Output = Self;
 
             /// Multiply the value of this unit with a number.
-            fn mul(self, rhs: $backing_ty) -> Self::Output {
+            #[inline]
+            fn mul(self, __rhs: $backing_ty) -> Self::Output {
                 Self {
-                    value: self.value * rhs,
+                    value: self.value * __rhs,
                 }
             }
         }
 
-        impl<$(const $unit: $unit_exp_ty,)*> ::std::ops::Mul<$quantity<$($unit,)*>> for $backing_ty {
+        impl<$(const $unit: $unit_exp_ty,)*> ::core::ops::Mul<$quantity<$($unit,)*>> for $backing_ty {
             type Output = $quantity<$($unit,)*>;
 
             /// Multiply the value of this unit with a number.
-            fn mul(self, rhs: $quantity<$($unit,)*>) -> Self::Output {
+            #[inline]
+            fn mul(self, __rhs: $quantity<$($unit,)*>) -> Self::Output {
                 $quantity {
-                    value: self * rhs.value,
+                    value: self * __rhs.value,
                 }
             }
         }
 
-        impl<$(const $unit: $unit_exp_ty,)*> ::std::ops::MulAssign<$backing_ty> for $quantity<$($unit,)*> {
+        impl<$(const $unit: $unit_exp_ty,)*> ::core::ops::MulAssign<$backing_ty> for $quantity<$($unit,)*> {
             /// Multiply the value of this unit with a number.
-            fn mul_assign(&mut self, rhs: $backing_ty) {
-                self.value *= rhs;
+            #[inline]
+            fn mul_assign(&mut self, __rhs: $backing_ty) {
+                self.value *= __rhs;
             }
         }
 
         // ============================
         // Div implementations
         // ============================
-        impl<$(const $unit: $unit_exp_ty,)*> ::std::ops::Div<$backing_ty> for $quantity<$($unit,)*> {
+        impl<$(const $unit: $unit_exp_ty,)*> ::core::ops::Div<$backing_ty> for $quantity<$($unit,)*> {
             type Output = Self;
 
             /// Divides the value of this unit with a number.
-            fn div(self, rhs: $backing_ty) -> Self::Output {
+            #[inline]
+            fn div(self, __rhs: $backing_ty) -> Self::Output {
                 Self {
-                    value: self.value / rhs,
+                    value: self.value / __rhs,
                 }
             }
         }
 
-        impl<$(const $unit: $unit_exp_ty,)*> ::std::ops::DivAssign<$backing_ty> for $quantity<$($unit,)*> {
+        impl<$(const $unit: $unit_exp_ty,)*> ::core::ops::DivAssign<$backing_ty> for $quantity<$($unit,)*> {
             /// Divides the value of this unit with a number.
-            fn div_assign(&mut self, rhs: $backing_ty) {
-                self.value /= rhs;
+            #[inline]
+            fn div_assign(&mut self, __rhs: $backing_ty) {
+                self.value /= __rhs;
+            }
+        }
+
+        // ============================
+        // Rem and Neg implementations
+        // ============================
+        impl<$(const $unit: $unit_exp_ty,)* __R: $crate::SameDimension<Self>> ::core::ops::Rem<__R> for $quantity<$($unit,)*> {
+            type Output = Self;
+
+            /// The remainder of dividing the value by another value of the same unit.
+            #[inline]
+            fn rem(self, __rhs: __R) -> Self::Output {
+                Self {
+                    value: self.value % __rhs.into_same().value,
+                }
+            }
+        }
+
+        impl<$(const $unit: $unit_exp_ty,)*> ::core::ops::Neg for $quantity<$($unit,)*> {
+            type Output = Self;
+
+            /// Negate the value of this unit.
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self { value: -self.value }
             }
         }
     };
+    ($backing_ty:ty, $quantity:ident, $unit_exp_ty:ty, $($unit:ident),+) => {
+        impl<$(const $unit: $unit_exp_ty,)*> $crate::SameDimension<$quantity<$($unit,)*>> for $quantity<$($unit,)*> {
+            #[inline]
+            fn into_same(self) -> Self {
+                self
+            }
+        }
+
+        $crate::quantity_ops!(@ops $backing_ty, $quantity, $unit_exp_ty, $($unit),+);
+    };
+}
+
+/// Implement the arithmetic of a quantity type over a primitive float as `const fn`s.
+///
+/// Used by the quantity types of this crate and by `unit_system!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __quantity_const_ops {
+    ($backing_ty:ty, $quantity:ident, $unit_exp_ty:ty, $($unit:ident),+) => {
+        impl<$(const $unit: $unit_exp_ty,)*> $quantity<$($unit,)*> {
+            /// Add the value of two equal units, in a constant.
+            ///
+            /// ```
+            /// use tiny_uom::values::{m, s};
+            /// use tiny_uom::Quantity;
+            ///
+            /// type Velocity = Quantity<1, 0, -1, 0, 0, 0, 0>;
+            ///
+            /// const ROAD_LIMIT: Velocity = Quantity::new(25.0);
+            /// const TOLERANCE: Velocity = Quantity::new(2.0);
+            /// const SPEED_LIMIT: Velocity = ROAD_LIMIT.const_add(TOLERANCE.const_mul(1.5));
+            /// assert_eq!(SPEED_LIMIT, 28.0 * (m / s));
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn const_add(self, __rhs: Self) -> Self {
+                Self::new(self.value + __rhs.value)
+            }
+
+            /// Subtract the value of two equal units, in a constant.
+            #[must_use]
+            #[inline]
+            pub const fn const_sub(self, __rhs: Self) -> Self {
+                Self::new(self.value - __rhs.value)
+            }
+
+            /// Multiply the value of this unit with a number, in a constant.
+            #[must_use]
+            #[inline]
+            pub const fn const_mul(self, __rhs: $backing_ty) -> Self {
+                Self::new(self.value * __rhs)
+            }
+
+            /// Divides the value of this unit with a number, in a constant.
+            #[must_use]
+            #[inline]
+            pub const fn const_div(self, __rhs: $backing_ty) -> Self {
+                Self::new(self.value / __rhs)
+            }
+
+            /// Negate the value of this unit, in a constant.
+            #[must_use]
+            #[inline]
+            pub const fn const_neg(self) -> Self {
+                Self::new(-self.value)
+            }
+        }
+    };
+}
+
+/// Implement the multiplication and division of a quantity type by an integer type.
+macro_rules! quantity_int_ops {
+    ($int:ty, $backing_ty:ty, $quantity:ident, $unit_exp_ty:ty, $($unit:ident),+) => {
+        impl<$(const $unit: $unit_exp_ty,)*> ::core::ops::Mul<$int> for $quantity<$($unit,)*> {
+            type Output = Self;
+
+            /// Multiply the value of this unit with an integer, like a number of steps.
+            #[inline]
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+            fn mul(self, rhs: $int) -> Self::Output {
+                Self::new(self.value * rhs as $backing_ty)
+            }
+        }
+
+        impl<$(const $unit: $unit_exp_ty,)*> ::core::ops::Mul<$quantity<$($unit,)*>> for $int {
+            type Output = $quantity<$($unit,)*>;
+
+            /// Multiply an integer, like a number of steps, with the value of this unit.
+            #[inline]
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+            fn mul(self, rhs: $quantity<$($unit,)*>) -> Self::Output {
+                $quantity::new(self as $backing_ty * rhs.value)
+            }
+        }
+
+        impl<$(const $unit: $unit_exp_ty,)*> ::core::ops::Div<$int> for $quantity<$($unit,)*> {
+            type Output = Self;
+
+            /// Divides the value of this unit by an integer.
+            #[inline]
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+            fn div(self, rhs: $int) -> Self::Output {
+                Self::new(self.value / rhs as $backing_ty)
+            }
+        }
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use quantity_int_ops;
+
+/// Implement all methods and traits for a quantity type.
+macro_rules! quantity_impl {
+    // Everything but `SameDimension`, for the `Quantity` of this crate, which implements it itself.
+    (@ops $backing_ty:ty, $quantity:ident, $unit_exp_ty:ty, $($unit:ident),+) => {
+        crate::quantity_impl!(@impl $backing_ty, $quantity, $unit_exp_ty, $($unit),+);
+        crate::quantity_ops!(@ops $backing_ty, $quantity, $unit_exp_ty, $($unit),+);
+    };
+    (@impl $backing_ty:ty, $quantity:ident, $unit_exp_ty:ty, $($unit:ident),+) => {
+        /// A `Quantity` represents a raw value and it's unit
+        /// that is represented as a const generic parameter.
+        ///
+        /// The default `Quantity` has a value of zero. Quantities of the same
+        /// unit are ordered by their values like `f32`, so a NaN value is not
+        /// ordered with any other.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(
+            feature = "rkyv",
+            derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize),
+            rkyv(derive(Debug, PartialEq))
+        )]
+        #[cfg_attr(
+            feature = "borsh",
+            derive(::borsh::BorshSerialize, ::borsh::BorshDeserialize)
+        )]
+        #[cfg_attr(
+            feature = "zerocopy",
+            derive(::zerocopy::FromZeroes, ::zerocopy::FromBytes, ::zerocopy::AsBytes)
+        )]
+        #[cfg_attr(
+            feature = "bytemuck",
+            derive(::bytemuck::Zeroable, ::bytemuck::Pod, ::bytemuck::TransparentWrapper)
+        )]
+        #[cfg_attr(
+            feature = "bevy",
+            derive(::bevy_reflect::Reflect),
+            reflect(Debug, PartialEq)
+        )]
+        #[repr(transparent)]
+        pub struct $quantity<$(const $unit: $unit_exp_ty,)*> {
+            /// The raw value of this `Quantity`
+            #[cfg(not(feature = "private_value"))]
+            pub value: $backing_ty,
+            #[cfg(feature = "private_value")]
+            value: $backing_ty,
+        }
+        impl<$(const $unit: $unit_exp_ty,)*> $quantity<$($unit,)*> {
+            /// Returns the raw value of this `Quantity`.
+            ///
+            /// Unlike the `value` field, this is also available with the
+            /// `private_value` feature.
+            #[must_use]
+            #[inline]
+            pub const fn raw(self) -> $backing_ty {
+                self.value
+            }
+
+            /// Convert this `Quantity` into its raw value.
+            #[must_use]
+            #[inline]
+            pub const fn into_raw(self) -> $backing_ty {
+                self.value
+            }
+
+            /// Returns the raw value as a byte array in little-endian byte order.
+            #[must_use]
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; 4] {
+                self.value.to_le_bytes()
+            }
+
+            /// Returns the raw value as a byte array in big-endian byte order.
+            ///
+            /// ```
+            /// use tiny_uom::values::m;
+            ///
+            /// let length = 2.0 * m;
+            /// assert_eq!(length.to_be_bytes(), [0x40, 0, 0, 0]);
+            /// assert_eq!(tiny_uom::Quantity::from_be_bytes(length.to_be_bytes()), length);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; 4] {
+                self.value.to_be_bytes()
+            }
+
+            /// Create a `Quantity` from its raw value in little-endian byte order.
+            #[must_use]
+            #[inline]
+            pub const fn from_le_bytes(bytes: [u8; 4]) -> Self {
+                Self::new(<$backing_ty>::from_le_bytes(bytes))
+            }
+
+            /// Create a `Quantity` from its raw value in big-endian byte order.
+            #[must_use]
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; 4]) -> Self {
+                Self::new(<$backing_ty>::from_be_bytes(bytes))
+            }
+        }
+
+        // The derive of `arbitrary` refers to `std`, which is not available without the `std` feature.
+        #[cfg(feature = "arbitrary")]
+        impl<'a, $(const $unit: $unit_exp_ty,)*> ::arbitrary::Arbitrary<'a> for $quantity<$($unit,)*> {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                <$backing_ty as ::arbitrary::Arbitrary>::arbitrary(u).map(Self::new)
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <$backing_ty as ::arbitrary::Arbitrary>::size_hint(depth)
+            }
+        }
+
+        // The resolver generated by `rkyv` holds no data, but `missing_debug_implementations` still applies.
+        // All quantity types of this crate are named `Quantity`, so their resolvers are `QuantityResolver`.
+        #[cfg(feature = "rkyv")]
+        impl<$(const $unit: $unit_exp_ty,)*> ::core::fmt::Debug for QuantityResolver<$($unit,)*> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct("QuantityResolver").finish_non_exhaustive()
+            }
+        }
+
+        crate::quantity_int_ops!(i32, $backing_ty, $quantity, $unit_exp_ty, $($unit),+);
+        crate::quantity_int_ops!(u32, $backing_ty, $quantity, $unit_exp_ty, $($unit),+);
+        crate::quantity_int_ops!(usize, $backing_ty, $quantity, $unit_exp_ty, $($unit),+);
+        crate::__quantity_const_ops!($backing_ty, $quantity, $unit_exp_ty, $($unit),+);
+    };
+    ($backing_ty:ty, $quantity:ident, $unit_exp_ty:ty, $($unit:ident),+) => {
+        crate::quantity_impl!(@impl $backing_ty, $quantity, $unit_exp_ty, $($unit),+);
+        crate::quantity_ops!($backing_ty, $quantity, $unit_exp_ty, $($unit),+);
+    };
+}
+quantity_impl!(@ops f32, Quantity, i8, m, kg, s, A, K, mol, cd);
+#[allow(unused_imports)]
+pub(crate) use quantity_impl;
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+        const m2: i8,
+        const kg2: i8,
+        const s2: i8,
+        const A2: i8,
+        const K2: i8,
+        const mol2: i8,
+        const cd2: i8,
+    > SameDimension<Quantity<m2, kg2, s2, A2, K2, mol2, cd2>>
+    for Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Fails to compile if the dimensions differ, with a message naming both
+    /// of them, like `expected Length (m), found Time (s)`.
+    #[inline]
+    fn into_same(self) -> Quantity<m2, kg2, s2, A2, K2, mol2, cd2> {
+        let () = dimension::DimensionCheck::<Quantity<m2, kg2, s2, A2, K2, mol2, cd2>, Self>::SAME;
+        Quantity::new(self.value)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// The [`Unit`] of this `Quantity`.
+    pub const UNIT: Unit = Unit {
+        m,
+        kg,
+        s,
+        A,
+        K,
+        mol,
+        cd,
+    };
+
+    /// Multiply two quantities, adding their exponents.
+    ///
+    /// The type of the result has to be written out. A type with the wrong
+    /// exponents fails to compile, and so do exponents that overflow an `i8`
+    /// instead of wrapping around.
+    ///
+    /// ```
+    /// use tiny_uom::values::{kg, m, s};
+    /// use tiny_uom::Quantity;
+    ///
+    /// let momentum: Quantity<1, 1, -1, 0, 0, 0, 0> = (2.0 * kg).mul_quantity(3.0 * (m / s));
+    /// assert_eq!(momentum.raw(), 6.0);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use tiny_uom::Quantity;
+    ///
+    /// let big = Quantity::<100, 0, 0, 0, 0, 0, 0>::new(1.0);
+    /// let wrapped: Quantity<-56, 0, 0, 0, 0, 0, 0> = big.mul_quantity(big);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mul_quantity<
+        R: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+        O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    >(
+        self,
+        rhs: R,
+    ) -> O {
+        let () = ExponentCheck::<Self, R, O>::PRODUCT;
+        O::from_raw(self.value * rhs.raw())
+    }
+
+    /// Divide two quantities, subtracting their exponents.
+    ///
+    /// Like for [`mul_quantity`](Self::mul_quantity), the type of the result
+    /// has to be written out, and exponents that overflow fail to compile.
+    #[must_use]
+    #[inline]
+    pub fn div_quantity<
+        R: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+        O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    >(
+        self,
+        rhs: R,
+    ) -> O {
+        let () = ExponentCheck::<Self, R, O>::QUOTIENT;
+        O::from_raw(self.value / rhs.raw())
+    }
+}
+
+/// The checks of the exponents of the results of `mul_quantity` and `div_quantity`.
+///
+/// These are associated constants instead of inline `const` blocks,
+/// which `generic_const_exprs` does not support in generic functions.
+/// `N` is the number of base units, which is less than seven for the
+/// quantities of the `mechanics` and `electromechanics` modules.
+pub(crate) struct ExponentCheck<L, R, O, const N: usize = 7>(core::marker::PhantomData<(L, R, O)>);
+
+impl<L, R, O, const N: usize> ExponentCheck<L, R, O, N>
+where
+    L: Dimensioned<Exponents = [i8; N], System = Si>,
+    R: Dimensioned<Exponents = [i8; N], System = Si>,
+    O: Dimensioned<Exponents = [i8; N], System = Si>,
+{
+    pub(crate) const PRODUCT: () = {
+        assert!(
+            macro_support::sum_in_range(&L::EXPONENTS, &R::EXPONENTS, 1),
+            "the exponents of the result overflow an `i8`"
+        );
+        assert!(
+            macro_support::is_sum(&O::EXPONENTS, &L::EXPONENTS, &R::EXPONENTS, 1),
+            "the exponents of the result must be the sum of the exponents"
+        );
+    };
+
+    pub(crate) const QUOTIENT: () = {
+        assert!(
+            macro_support::sum_in_range(&L::EXPONENTS, &R::EXPONENTS, -1),
+            "the exponents of the result overflow an `i8`"
+        );
+        assert!(
+            macro_support::is_sum(&O::EXPONENTS, &L::EXPONENTS, &R::EXPONENTS, -1),
+            "the exponents of the result must be the difference of the exponents"
+        );
+    };
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > fmt::Display for Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Formats the value followed by the [`Unit`], like `9.81 m*kg/s^2`.
+    ///
+    /// The alternate flag (`{:#}`) uses the symbol of the named unit
+    /// from [`UnitInfo`] if there is one, like `9.81 N`.
+    /// Both outputs can be parsed back using [`FromStr`](core::str::FromStr).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        match Self::UNIT.info() {
+            _ if Self::UNIT.is_dimensionless() => Ok(()),
+            Some(info) if f.alternate() => write!(f, " {}", info.symbol),
+            _ => write!(f, " {}", Self::UNIT),
+        }
+    }
 }
-quantity_impl!(f32, Quantity, i8, m, kg, s, A, K, mol, cd);
 
 // Without #![feature(generic_const_exprs)], this must be done manually for every pair of dimensions
 // you want to perform an operation on.
-impl std::ops::Div<Quantity<0, 0, 1, 0, 0, 0, 0>> for Quantity<1, 0, 0, 0, 0, 0, 0> {
+impl core::ops::Div<Quantity<0, 0, 1, 0, 0, 0, 0>> for Quantity<1, 0, 0, 0, 0, 0, 0> {
     type Output = Quantity<1, 0, -1, 0, 0, 0, 0>;
 
+    #[inline]
     fn div(self, rhs: Quantity<0, 0, 1, 0, 0, 0, 0>) -> Self::Output {
         Quantity {
             value: self.value / rhs.value,
         }
     }
 }
+
+impl core::ops::Mul for Quantity<0, 0, 0, 0, 0, 0, 0> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quantity {
+            value: self.value * rhs.value,
+        }
+    }
+}
+
+impl core::ops::Div for Quantity<0, 0, 0, 0, 0, 0, 0> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        Quantity {
+            value: self.value / rhs.value,
+        }
+    }
+}
+
+impl core::iter::Product for Quantity<0, 0, 0, 0, 0, 0, 0> {
+    /// Multiply dimensionless quantities, like the efficiencies of the stages of a drive train.
+    ///
+    /// ```
+    /// use tiny_uom::Quantity;
+    ///
+    /// let stages = [0.5, 0.8, 0.25].map(Quantity::<0, 0, 0, 0, 0, 0, 0>::new);
+    /// assert_eq!(stages.iter().product::<Quantity<0, 0, 0, 0, 0, 0, 0>>(), 0.1);
+    /// assert_eq!(stages.into_iter().product::<Quantity<0, 0, 0, 0, 0, 0, 0>>(), 0.1);
+    /// ```
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Quantity::new(iter.map(|factor| factor.value).product())
+    }
+}
+
+impl<'a> core::iter::Product<&'a Self> for Quantity<0, 0, 0, 0, 0, 0, 0> {
+    /// Multiply dimensionless quantities.
+    #[inline]
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > core::ops::MulAssign<Quantity<0, 0, 0, 0, 0, 0, 0>> for Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Multiply the value of this unit with a dimensionless quantity.
+    ///
+    /// ```
+    /// use tiny_uom::values::m;
+    /// use tiny_uom::Quantity;
+    ///
+    /// let efficiency = Quantity::new(0.5);
+    /// let mut length = 3.0 * m;
+    /// length *= efficiency;
+    /// assert_eq!(length, 1.5 * m);
+    /// length /= efficiency;
+    /// assert_eq!(length, 3.0 * m);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, rhs: Quantity<0, 0, 0, 0, 0, 0, 0>) {
+        self.value *= rhs.value;
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > core::ops::DivAssign<Quantity<0, 0, 0, 0, 0, 0, 0>> for Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Divides the value of this unit by a dimensionless quantity.
+    #[inline]
+    fn div_assign(&mut self, rhs: Quantity<0, 0, 0, 0, 0, 0, 0>) {
+        self.value /= rhs.value;
+    }
+}
+
+impl PartialEq<f32> for Quantity<0, 0, 0, 0, 0, 0, 0> {
+    /// Compare a dimensionless quantity with a number.
+    ///
+    /// ```
+    /// use tiny_uom::Quantity;
+    ///
+    /// let ratio = Quantity::<0, 0, 0, 0, 0, 0, 0>::new(0.5);
+    /// assert!(ratio == 0.5 && 0.5 == ratio);
+    /// assert!(ratio < 1.0 && 0.0 < ratio);
+    /// ```
+    #[inline]
+    fn eq(&self, other: &f32) -> bool {
+        self.value == *other
+    }
+}
+
+impl PartialEq<Quantity<0, 0, 0, 0, 0, 0, 0>> for f32 {
+    /// Compare a number with a dimensionless quantity.
+    #[inline]
+    fn eq(&self, other: &Quantity<0, 0, 0, 0, 0, 0, 0>) -> bool {
+        *self == other.value
+    }
+}
+
+impl PartialOrd<f32> for Quantity<0, 0, 0, 0, 0, 0, 0> {
+    /// Compare a dimensionless quantity with a number.
+    #[inline]
+    fn partial_cmp(&self, other: &f32) -> Option<core::cmp::Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<Quantity<0, 0, 0, 0, 0, 0, 0>> for f32 {
+    /// Compare a number with a dimensionless quantity.
+    #[inline]
+    fn partial_cmp(&self, other: &Quantity<0, 0, 0, 0, 0, 0, 0>) -> Option<core::cmp::Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
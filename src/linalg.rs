@@ -0,0 +1,313 @@
+//! Linear algebra on slices of quantities.
+//!
+//! The dot product of samples of `A` and `B` has the dimension of `A * B`,
+//! so the dot product of forces and displacements is the work they do. Like
+//! for [`Quantity::mul_quantity`](crate::Quantity::mul_quantity), the type
+//! of the result has to be known and must have the summed exponents.
+//!
+//! ```
+//! use tiny_uom::linalg::dot;
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! type Force = Quantity<1, 1, -2, 0, 0, 0, 0>;
+//! type Energy = Quantity<2, 1, -2, 0, 0, 0, 0>;
+//!
+//! let forces = [3.0, 0.0, 4.0].map(Force::new);
+//! let displacements = [2.0 * m, 5.0 * m, 1.0 * m];
+//! let work: Energy = dot(&forces, &displacements);
+//! assert_eq!(work, Energy::new(10.0));
+//! ```
+//!
+//! [`QVec2`] and [`QVec3`] are small vectors of quantities for kinematics,
+//! whose [`dot`](QVec3::dot) and [`cross`](QVec3::cross) products have the
+//! dimension of the product of their components.
+//!
+//! ```
+//! use tiny_uom::linalg::QVec3;
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! type Area = Quantity<2, 0, 0, 0, 0, 0, 0>;
+//!
+//! let a = QVec3::new(2.0 * m, 0.0 * m, 0.0 * m);
+//! let b = QVec3::new(0.0 * m, 3.0 * m, 0.0 * m);
+//! let normal: QVec3<Area> = a.cross(b);
+//! assert_eq!(normal, QVec3::new(Area::new(0.0), Area::new(0.0), Area::new(6.0)));
+//! assert_eq!(a.dot::<_, Area>(b), Area::new(0.0));
+//! assert_eq!(a + b - a, b);
+//! ```
+
+use crate::{Dimensioned, ExponentCheck, Si};
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// The number of independent sums of the loops of this module.
+const LANES: usize = 8;
+
+/// Returns `a * b + sum`, rounded once if `std` or `libm` is available.
+#[inline]
+fn mul_add(a: f32, b: f32, sum: f32) -> f32 {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    return crate::math::imp::mul_add(a, b, sum);
+    #[cfg(not(any(feature = "std", feature = "libm")))]
+    return a * b + sum;
+}
+
+/// Returns the sum of the products of the raw values of `a` and `b`.
+///
+/// The products are summed in independent lanes, which lets the compiler
+/// vectorize the loop, and the lanes are added at the end.
+fn dot_raw<A: Dimensioned<Scalar = f32>, B: Dimensioned<Scalar = f32>>(a: &[A], b: &[B]) -> f32 {
+    let ((a, a_tail), (b, b_tail)) = (a.as_chunks::<LANES>(), b.as_chunks::<LANES>());
+    let tail = a_tail
+        .iter()
+        .zip(b_tail)
+        .fold(0.0, |sum, (a, b)| mul_add(a.raw(), b.raw(), sum));
+    let mut lanes = [0.0; LANES];
+    for (a, b) in a.iter().zip(b) {
+        for ((lane, a), b) in lanes.iter_mut().zip(a).zip(b) {
+            *lane = mul_add(a.raw(), b.raw(), *lane);
+        }
+    }
+    lanes.iter().sum::<f32>() + tail
+}
+
+/// Returns the dot product of two slices of quantities.
+///
+/// ```
+/// use tiny_uom::linalg::dot;
+/// use tiny_uom::values::m;
+/// use tiny_uom::Quantity;
+///
+/// type Area = Quantity<2, 0, 0, 0, 0, 0, 0>;
+///
+/// let lengths = [1.5 * m; 20];
+/// assert_eq!(dot::<_, _, Area>(&lengths, &lengths), Area::new(45.0));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+#[must_use]
+pub fn dot<A, B, O>(a: &[A], b: &[B]) -> O
+where
+    A: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    B: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+{
+    let () = ExponentCheck::<A, B, O>::PRODUCT;
+    assert_eq!(a.len(), b.len(), "the slices must have the same length");
+    O::from_raw(dot_raw(a, b))
+}
+
+/// Returns the Euclidean norm of a slice of quantities, the square root
+/// of the sum of their squares.
+///
+/// Like [`Quantity::hypot`](crate::Quantity::hypot), the squares are scaled
+/// by the largest magnitude, so they neither overflow for values above
+/// `sqrt(f32::MAX)`, about `1.8e19`, nor underflow for tiny values.
+///
+/// ```
+/// use tiny_uom::linalg::norm;
+/// use tiny_uom::values::m;
+///
+/// assert_eq!(norm(&[3.0 * m, 4.0 * m]), 5.0 * m);
+/// assert_eq!(norm(&[3e30 * m, 4e30 * m]), 5e30 * m);
+/// assert_eq!(norm(&[3e-30 * m, 4e-30 * m]), 5e-30 * m);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn norm<Q: Dimensioned<Scalar = f32>>(quantities: &[Q]) -> Q {
+    // a NaN is kept as the scale, so it propagates into the result
+    let scale = quantities.iter().fold(0.0_f32, |max, q| {
+        let abs = q.raw().abs();
+        if abs > max || abs.is_nan() {
+            abs
+        } else {
+            max
+        }
+    });
+    if scale == 0.0 || !scale.is_finite() {
+        return Q::from_raw(scale);
+    }
+
+    let sum = quantities.iter().fold(0.0, |sum, q| {
+        let x = q.raw() / scale;
+        mul_add(x, x, sum)
+    });
+    Q::from_raw(scale * crate::math::imp::sqrt(sum))
+}
+
+/// A vector of two quantities of type `Q`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct QVec2<Q> {
+    /// The x component.
+    pub x: Q,
+    /// The y component.
+    pub y: Q,
+}
+
+/// A vector of three quantities of type `Q`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct QVec3<Q> {
+    /// The x component.
+    pub x: Q,
+    /// The y component.
+    pub y: Q,
+    /// The z component.
+    pub z: Q,
+}
+
+impl<Q: Dimensioned<Scalar = f32>> QVec2<Q> {
+    /// Create a vector from its components.
+    #[must_use]
+    #[inline]
+    pub const fn new(x: Q, y: Q) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the z component of the cross product of the vectors extended
+    /// to three dimensions, which is positive if `rhs` points to the left of `self`.
+    #[must_use]
+    #[inline]
+    pub fn cross<R, O>(self, rhs: QVec2<R>) -> O
+    where
+        Q: Dimensioned<Exponents = [i8; 7], System = Si>,
+        R: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+        O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    {
+        let () = ExponentCheck::<Q, R, O>::PRODUCT;
+        O::from_raw(self.x.raw() * rhs.y.raw() - self.y.raw() * rhs.x.raw())
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> QVec3<Q> {
+    /// Create a vector from its components.
+    #[must_use]
+    #[inline]
+    pub const fn new(x: Q, y: Q, z: Q) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the cross product of two vectors, which is perpendicular to both.
+    #[must_use]
+    #[inline]
+    pub fn cross<R, O>(self, rhs: QVec3<R>) -> QVec3<O>
+    where
+        Q: Dimensioned<Exponents = [i8; 7], System = Si>,
+        R: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+        O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    {
+        let () = ExponentCheck::<Q, R, O>::PRODUCT;
+        let (a, b) = (self.to_raw(), rhs.to_raw());
+        QVec3::new(
+            O::from_raw(a[1] * b[2] - a[2] * b[1]),
+            O::from_raw(a[2] * b[0] - a[0] * b[2]),
+            O::from_raw(a[0] * b[1] - a[1] * b[0]),
+        )
+    }
+}
+
+/// Implement the methods and operators shared by the vectors of quantities.
+macro_rules! qvec_impl {
+    ($vec:ident, $len:literal, $($field:ident),+) => {
+        impl<Q: Dimensioned<Scalar = f32>> $vec<Q> {
+            /// Returns the raw values of the components in SI units.
+            #[must_use]
+            #[inline]
+            pub fn to_raw(self) -> [f32; $len] {
+                [$(self.$field.raw()),+]
+            }
+
+            /// Returns the dot product of two vectors.
+            #[must_use]
+            #[inline]
+            pub fn dot<R, O>(self, rhs: $vec<R>) -> O
+            where
+                Q: Dimensioned<Exponents = [i8; 7], System = Si>,
+                R: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+                O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+            {
+                let () = ExponentCheck::<Q, R, O>::PRODUCT;
+                O::from_raw(0.0 $(+ self.$field.raw() * rhs.$field.raw())+)
+            }
+
+            /// Returns the length of the vector, without overflowing like [`norm`].
+            #[cfg(any(feature = "std", feature = "libm"))]
+            #[must_use]
+            #[inline]
+            pub fn length(self) -> Q {
+                norm(&[$(self.$field),+])
+            }
+        }
+
+        impl<Q: Dimensioned<Scalar = f32>> Add for $vec<Q> {
+            type Output = Self;
+
+            /// Add two vectors of the same unit.
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: Q::from_raw(self.$field.raw() + rhs.$field.raw())),+ }
+            }
+        }
+
+        impl<Q: Dimensioned<Scalar = f32>> AddAssign for $vec<Q> {
+            /// Add two vectors of the same unit.
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<Q: Dimensioned<Scalar = f32>> Sub for $vec<Q> {
+            type Output = Self;
+
+            /// Subtract two vectors of the same unit.
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: Q::from_raw(self.$field.raw() - rhs.$field.raw())),+ }
+            }
+        }
+
+        impl<Q: Dimensioned<Scalar = f32>> SubAssign for $vec<Q> {
+            /// Subtract two vectors of the same unit.
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<Q: Dimensioned<Scalar = f32>> Neg for $vec<Q> {
+            type Output = Self;
+
+            /// Negate every component of the vector.
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $($field: Q::from_raw(-self.$field.raw())),+ }
+            }
+        }
+
+        impl<Q: Dimensioned<Scalar = f32>> Mul<f32> for $vec<Q> {
+            type Output = Self;
+
+            /// Multiply every component of the vector with a number.
+            #[inline]
+            fn mul(self, rhs: f32) -> Self {
+                Self { $($field: Q::from_raw(self.$field.raw() * rhs)),+ }
+            }
+        }
+
+        impl<Q: Dimensioned<Scalar = f32>> Div<f32> for $vec<Q> {
+            type Output = Self;
+
+            /// Divide every component of the vector by a number.
+            #[inline]
+            fn div(self, rhs: f32) -> Self {
+                Self { $($field: Q::from_raw(self.$field.raw() / rhs)),+ }
+            }
+        }
+    };
+}
+
+qvec_impl!(QVec2, 2, x, y);
+qvec_impl!(QVec3, 3, x, y, z);
@@ -0,0 +1,488 @@
+//! Functions used by the code that `unit_system!` and the `tiny-uom-macros` crate generate.
+
+#[cfg(feature = "derive")]
+use crate::{
+    info::UNITS,
+    parse::{ExponentError, Lexer, MAX_DEPTH},
+    registry::{BUILTIN, PREFIXES},
+    Quantity, Unit,
+};
+use core::fmt;
+
+pub use crate::dimension::sealed::Sealed;
+
+/// Returns `true` if every sum of `lhs` and `factor` times `rhs` fits into an `i8`.
+#[must_use]
+pub const fn sum_in_range(lhs: &[i8], rhs: &[i8], factor: i8) -> bool {
+    let mut i = 0;
+    while i < lhs.len() {
+        let sum = lhs[i] as i16 + factor as i16 * rhs[i] as i16;
+        if sum < i8::MIN as i16 || sum > i8::MAX as i16 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if `out` is the sum of `lhs` and `factor` times `rhs`.
+#[must_use]
+pub const fn is_sum(out: &[i8], lhs: &[i8], rhs: &[i8], factor: i8) -> bool {
+    let mut i = 0;
+    while i < out.len() {
+        if out[i] as i16 != lhs[i] as i16 + factor as i16 * rhs[i] as i16 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if both slices of exponents are equal.
+#[must_use]
+pub const fn same_dimension(lhs: &[i8], rhs: &[i8]) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if the `i16` exponents of `wide` are equal to `lhs`.
+#[must_use]
+pub const fn same_wide_dimension(lhs: &[i8], wide: &[i16]) -> bool {
+    if lhs.len() != wide.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] as i16 != wide[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Writes the symbols of the base units with their exponents, like ` px/frame^2`.
+///
+/// Writes nothing for a dimensionless quantity.
+///
+/// # Errors
+///
+/// Returns an error if writing into `f` fails.
+pub fn write_unit(f: &mut fmt::Formatter<'_>, symbols: &[&str], exponents: &[i32]) -> fmt::Result {
+    fn write_product<'a>(
+        f: &mut fmt::Formatter<'_>,
+        factors: impl Iterator<Item = (&'a str, i32)>,
+    ) -> fmt::Result {
+        for (idx, (symbol, exp)) in factors.enumerate() {
+            if idx > 0 {
+                f.write_str("*")?;
+            }
+            f.write_str(symbol)?;
+            if exp != 1 {
+                write!(f, "^{exp}")?;
+            }
+        }
+        Ok(())
+    }
+
+    let factors = symbols.iter().copied().zip(exponents.iter().copied());
+    let numerator = factors.clone().filter(|&(_, exp)| exp > 0);
+    let denominator = factors
+        .filter(|&(_, exp)| exp < 0)
+        .map(|(symbol, exp)| (symbol, -exp));
+
+    if numerator.clone().next().is_none() && denominator.clone().next().is_none() {
+        return Ok(());
+    }
+    f.write_str(" ")?;
+    if numerator.clone().next().is_some() {
+        write_product(f, numerator)?;
+    } else {
+        f.write_str("1")?;
+    }
+    match denominator.clone().count() {
+        0 => Ok(()),
+        1 => {
+            f.write_str("/")?;
+            write_product(f, denominator)
+        }
+        _ => {
+            f.write_str("/(")?;
+            write_product(f, denominator)?;
+            f.write_str(")")
+        }
+    }
+}
+
+/// Create a quantity from a value in a unit with the given `factor`.
+#[cfg(feature = "derive")]
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn from_unit<
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    value: f32,
+    factor: f64,
+) -> Quantity<m, kg, s, A, K, mol, cd> {
+    Quantity::new((f64::from(value) * factor) as f32)
+}
+
+/// Returns the value of a quantity in a unit with the given `factor`.
+#[cfg(feature = "derive")]
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn to_unit<
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    quantity: Quantity<m, kg, s, A, K, mol, cd>,
+    factor: f64,
+) -> f32 {
+    (f64::from(quantity.raw()) / factor) as f32
+}
+
+/// Returns `true` if both byte strings are equal, in a constant.
+#[cfg(feature = "derive")]
+const fn bytes_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if `symbol` equals `name` or one of the `aliases`.
+#[cfg(feature = "derive")]
+const fn names_match(symbol: &[u8], name: &str, aliases: &[&str]) -> bool {
+    let mut i = 0;
+    while i < aliases.len() {
+        if bytes_eq(symbol, aliases[i].as_bytes()) {
+            return true;
+        }
+        i += 1;
+    }
+    bytes_eq(symbol, name.as_bytes())
+}
+
+/// Look up a symbol or alias of the units of [`UnitRegistry::new`], returning
+/// the factor, the dimension and whether the unit accepts prefixes.
+///
+/// Like in the registry, a later definition of the same name wins.
+///
+/// [`UnitRegistry::new`]: crate::registry::UnitRegistry::new
+#[cfg(feature = "derive")]
+const fn get_builtin(symbol: &[u8]) -> Option<(f64, Unit, bool)> {
+    let mut found = None;
+    let mut i = 0;
+    while i < UNITS.len() {
+        let info = &UNITS[i];
+        if names_match(symbol, info.symbol, &[info.name]) {
+            found = Some((1.0, info.unit, !bytes_eq(info.symbol.as_bytes(), b"kg")));
+        }
+        i += 1;
+    }
+    let mut i = 0;
+    while i < BUILTIN.len() {
+        let (name, factor, dimension, prefixes, aliases) = BUILTIN[i];
+        if names_match(symbol, name, aliases) {
+            found = Some((factor, Unit::from_exponents(dimension), prefixes));
+        }
+        i += 1;
+    }
+    found
+}
+
+/// Look up a symbol that may have an SI prefix among the units of
+/// [`UnitRegistry::new`] in a constant, returning the factor that converts
+/// values into SI units and the dimension.
+///
+/// This is [`UnitRegistry::lookup`] of [`UnitRegistry::builtin`].
+///
+/// [`UnitRegistry::new`]: crate::registry::UnitRegistry::new
+/// [`UnitRegistry::lookup`]: crate::registry::UnitRegistry::lookup
+/// [`UnitRegistry::builtin`]: crate::registry::UnitRegistry::builtin
+#[cfg(feature = "derive")]
+const fn lookup_builtin(symbol: &[u8]) -> Option<(f64, Unit)> {
+    if let Some((factor, unit, _)) = get_builtin(symbol) {
+        return Some((factor, unit));
+    }
+
+    let mut i = 0;
+    while i < PREFIXES.len() {
+        let (prefix, prefix_factor) = PREFIXES[i];
+        let prefix = prefix.as_bytes();
+        if prefix.len() < symbol.len() {
+            let (head, rest) = symbol.split_at(prefix.len());
+            if bytes_eq(head, prefix) {
+                if let Some((factor, unit, true)) = get_builtin(rest) {
+                    return Some((prefix_factor * factor, unit));
+                }
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// A unit with the factor that converts values into SI units, computed in a constant.
+#[cfg(feature = "derive")]
+#[derive(Clone, Copy, Debug)]
+pub struct ConstUnit {
+    /// The factor that converts values into SI units.
+    pub factor: f64,
+    /// The exponents of the SI base units.
+    pub exponents: [i8; 7],
+}
+
+#[cfg(feature = "derive")]
+impl ConstUnit {
+    /// The unit of dimensionless values.
+    pub const ONE: Self = Self {
+        factor: 1.0,
+        exponents: [0; 7],
+    };
+
+    /// Look up a built-in unit that may have an SI prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the unit is unknown.
+    #[must_use]
+    pub const fn symbol(symbol: &str) -> Self {
+        match lookup_builtin(symbol.as_bytes()) {
+            Some((factor, unit)) => Self {
+                factor,
+                exponents: unit.exponents(),
+            },
+            None => panic!("unknown unit"),
+        }
+    }
+
+    /// Parse a unit expression of built-in units like `km/h` or `kg*m^2/s^2`,
+    /// using the syntax of [`UnitRegistry::parse_unit`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expression can not be parsed or uses an unknown unit.
+    ///
+    /// This accepts exactly the expressions that [`UnitRegistry::parse_unit`]
+    /// of the built-in registry accepts:
+    ///
+    /// ```
+    /// use std::panic::{catch_unwind, set_hook, take_hook};
+    /// use tiny_uom::macro_support::ConstUnit;
+    /// use tiny_uom::registry::UnitRegistry;
+    ///
+    /// let nested = format!("{}m{}", "(".repeat(33), ")".repeat(33));
+    /// let cases = [
+    ///     "km/h", "kg·m/s^2", "m ^ 2", "m^ -2", "m^- 2", "m^+3", "m^", "m^2.5", "s^-128",
+    ///     "s^128", "m\u{a0}*\u{a0}s", "m s", "°", "μm", "1/s", "(m)/(s", "", "parsec",
+    ///     &nested[1..nested.len() - 1], &nested,
+    /// ];
+    ///
+    /// set_hook(Box::new(|_| {}));
+    /// let constants = cases.map(|case| catch_unwind(|| ConstUnit::parse(case)).ok());
+    /// drop(take_hook());
+    ///
+    /// for (case, constant) in cases.into_iter().zip(constants) {
+    ///     let runtime = UnitRegistry::builtin().parse_unit(case).ok();
+    ///     match (runtime, constant) {
+    ///         (Some((factor, unit)), Some(constant)) => {
+    ///             assert_eq!(unit.exponents(), constant.exponents, "{case}");
+    ///             assert!((factor - constant.factor).abs() <= 1e-15 * factor, "{case}");
+    ///         }
+    ///         (None, None) => {}
+    ///         (runtime, constant) => panic!("{case}: {runtime:?} and {constant:?}"),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`UnitRegistry::parse_unit`]: crate::registry::UnitRegistry::parse_unit
+    #[must_use]
+    pub const fn parse(src: &str) -> Self {
+        let mut parser = ConstParser {
+            lexer: Lexer::new(src),
+            depth: 0,
+        };
+        let unit = parser.expr();
+        let done = parser.lexer.is_done();
+        assert!(done, "invalid unit expression");
+        unit
+    }
+
+    /// Returns the factor that converts values in this unit into SI units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this unit has a different dimension than `expected`.
+    #[must_use]
+    pub const fn factor_of(self, expected: Unit) -> f64 {
+        let expected = expected.exponents();
+        let mut i = 0;
+        while i < 7 {
+            assert!(
+                self.exponents[i] == expected[i],
+                "the unit has a different dimension than the quantity"
+            );
+            i += 1;
+        }
+        self.factor
+    }
+
+    /// Multiply two units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows an `i8`.
+    #[must_use]
+    pub const fn mul(self, rhs: Self) -> Self {
+        self.combine(rhs, 1)
+    }
+
+    /// Divide two units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows an `i8`.
+    #[must_use]
+    pub const fn div(self, rhs: Self) -> Self {
+        self.combine(rhs, -1)
+    }
+
+    /// Raise a unit to an integer power.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an exponent overflows an `i8`.
+    #[must_use]
+    pub const fn powi(self, n: i8) -> Self {
+        let mut factor = 1.0;
+        let mut i = 0;
+        while i < n.unsigned_abs() {
+            factor *= self.factor;
+            i += 1;
+        }
+        let mut exponents = self.exponents;
+        let mut i = 0;
+        while i < 7 {
+            exponents[i] = crate::exponent::mul(exponents[i], n);
+            i += 1;
+        }
+        Self {
+            factor: if n < 0 { 1.0 / factor } else { factor },
+            exponents,
+        }
+    }
+
+    /// Returns the product of `self` and `rhs` raised to the power of `sign`.
+    const fn combine(self, rhs: Self, sign: i8) -> Self {
+        let mut exponents = self.exponents;
+        let mut i = 0;
+        while i < 7 {
+            exponents[i] =
+                crate::exponent::add(exponents[i], crate::exponent::mul(rhs.exponents[i], sign));
+            i += 1;
+        }
+        Self {
+            factor: if sign < 0 {
+                self.factor / rhs.factor
+            } else {
+                self.factor * rhs.factor
+            },
+            exponents,
+        }
+    }
+
+    /// Convert a value in this unit into SI units.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn convert(self, value: f64) -> f32 {
+        (value * self.factor) as f32
+    }
+}
+
+/// A parser of unit expressions in a constant, like the one of
+/// [`UnitRegistry::parse_unit`] and using the same [`Lexer`].
+///
+/// [`UnitRegistry::parse_unit`]: crate::registry::UnitRegistry::parse_unit
+#[cfg(feature = "derive")]
+struct ConstParser<'a> {
+    lexer: Lexer<'a>,
+    depth: usize,
+}
+
+#[cfg(feature = "derive")]
+impl ConstParser<'_> {
+    const fn expr(&mut self) -> ConstUnit {
+        let mut unit = self.factor();
+        loop {
+            if self.lexer.eat("*") || self.lexer.eat("·") {
+                unit = unit.mul(self.factor());
+            } else if self.lexer.eat("/") {
+                unit = unit.div(self.factor());
+            } else {
+                return unit;
+            }
+        }
+    }
+
+    const fn factor(&mut self) -> ConstUnit {
+        let unit = if self.lexer.eat("(") {
+            assert!(self.depth < MAX_DEPTH, "invalid unit expression");
+            self.depth += 1;
+            let inner = self.expr();
+            self.depth -= 1;
+            let closed = self.lexer.eat(")");
+            assert!(closed, "invalid unit expression");
+            inner
+        } else if self.lexer.eat("1") {
+            ConstUnit::ONE
+        } else {
+            let Some(symbol) = self.lexer.symbol() else {
+                panic!("invalid unit expression");
+            };
+            match lookup_builtin(symbol.as_bytes()) {
+                Some((factor, unit)) => ConstUnit {
+                    factor,
+                    exponents: unit.exponents(),
+                },
+                None => panic!("unknown unit"),
+            }
+        };
+
+        if !self.lexer.eat("^") {
+            return unit;
+        }
+        match self.lexer.exponent() {
+            Ok(exp) => unit.powi(exp),
+            Err(ExponentError::InvalidSyntax) => panic!("invalid unit expression"),
+            Err(ExponentError::Overflow) => panic!("unit exponent overflowed"),
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Math functions on quantities.
+//!
+//! With the `std` feature these use the methods of `f32`. Without it,
+//! the `libm` feature implements them using [`libm`], so they are also
+//! available on bare-metal targets.
+//!
+//! Functions that keep the unit, like [`Quantity::hypot`], are available
+//! for all quantities. Functions like [`Quantity::sqrt`] or [`Quantity::sin`]
+//! are only available for dimensionless quantities, like angles in radians.
+//!
+//! ```
+//! use std::f32::consts::FRAC_PI_4;
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! assert_eq!((3.0 * m).hypot(4.0 * m), 5.0 * m);
+//! assert_eq!((1.0 * m).atan2(1.0 * m), Quantity::new(FRAC_PI_4));
+//! assert_eq!(Quantity::new(16.0).sqrt(), Quantity::new(4.0));
+//! ```
+//!
+//! [`libm`]: https://docs.rs/libm
+
+use crate::Quantity;
+
+#[cfg(feature = "std")]
+pub(crate) mod imp {
+    pub fn sqrt(x: f32) -> f32 {
+        x.sqrt()
+    }
+    pub fn sqrt64(x: f64) -> f64 {
+        x.sqrt()
+    }
+    pub fn powf(x: f32, n: f32) -> f32 {
+        x.powf(n)
+    }
+    pub fn powi64(x: f64, n: i32) -> f64 {
+        x.powi(n)
+    }
+    pub fn hypot(x: f32, y: f32) -> f32 {
+        x.hypot(y)
+    }
+    pub fn mul_add(x: f32, a: f32, b: f32) -> f32 {
+        x.mul_add(a, b)
+    }
+    pub fn exp(x: f32) -> f32 {
+        x.exp()
+    }
+    pub fn ln(x: f32) -> f32 {
+        x.ln()
+    }
+    pub fn sin(x: f32) -> f32 {
+        x.sin()
+    }
+    pub fn cos(x: f32) -> f32 {
+        x.cos()
+    }
+    pub fn tan(x: f32) -> f32 {
+        x.tan()
+    }
+    pub fn asin(x: f32) -> f32 {
+        x.asin()
+    }
+    pub fn acos(x: f32) -> f32 {
+        x.acos()
+    }
+    pub fn atan(x: f32) -> f32 {
+        x.atan()
+    }
+    pub fn atan2(y: f32, x: f32) -> f32 {
+        y.atan2(x)
+    }
+    pub fn round(x: f32) -> f32 {
+        x.round()
+    }
+    pub fn round64(x: f64) -> f64 {
+        x.round()
+    }
+    pub fn trunc64(x: f64) -> f64 {
+        x.trunc()
+    }
+    pub fn floor(x: f32) -> f32 {
+        x.floor()
+    }
+    pub fn log10(x: f32) -> f32 {
+        x.log10()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) mod imp {
+    pub use libm::{
+        acosf as acos, asinf as asin, atan2f as atan2, atanf as atan, cosf as cos, expf as exp,
+        floorf as floor, fmaf as mul_add, hypotf as hypot, log10f as log10, logf as ln, powf,
+        round as round64, roundf as round, sinf as sin, sqrt as sqrt64, sqrtf as sqrt, tanf as tan,
+        trunc as trunc64,
+    };
+
+    pub fn powi64(x: f64, n: i32) -> f64 {
+        libm::pow(x, f64::from(n))
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Returns the length of the hypotenuse of a right triangle with legs `self` and `other`.
+    #[must_use]
+    #[inline]
+    pub fn hypot(self, other: Self) -> Self {
+        Quantity::new(imp::hypot(self.value, other.value))
+    }
+
+    /// Returns the angle of the vector to the point (`other`, `self`) in radians,
+    /// like [`f32::atan2`].
+    #[must_use]
+    #[inline]
+    pub fn atan2(self, other: Self) -> Quantity<0, 0, 0, 0, 0, 0, 0> {
+        Quantity::new(imp::atan2(self.value, other.value))
+    }
+
+    /// Round the value to `digits` significant figures.
+    ///
+    /// Zero and values that are not finite are returned unchanged. The
+    /// value is scaled in `f64`, so this also works close to the limits of
+    /// the range of `f32`. If the nearest value with `digits` figures
+    /// overflows an `f32`, the value is rounded toward zero instead, so a
+    /// finite value stays finite.
+    ///
+    /// ```
+    /// use tiny_uom::values::m;
+    ///
+    /// assert_eq!((1234.5 * m).round_sig_figs(2), 1200.0 * m);
+    /// assert_eq!((0.012_345 * m).round_sig_figs(3), 0.0123 * m);
+    /// assert_eq!((1.234_567_8e-33 * m).round_sig_figs(7), 1.234_568e-33 * m);
+    /// assert_eq!((1.5e-45 * m).round_sig_figs(1), 1e-45 * m);
+    /// assert_eq!((3.402_823_5e38 * m).round_sig_figs(3), 3.4e38 * m);
+    /// assert_eq!((f32::MAX * m).round_sig_figs(4), 3.402e38 * m);
+    /// assert_eq!((f32::MIN * m).round_sig_figs(1), -3e38 * m);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digits` is zero.
+    #[must_use]
+    pub fn round_sig_figs(self, digits: u8) -> Self {
+        assert!(digits > 0, "at least one significant figure is needed");
+        if self.value == 0.0 || !self.value.is_finite() {
+            return self;
+        }
+        let magnitude = imp::floor(imp::log10(self.value.abs()));
+        #[allow(clippy::cast_possible_truncation)]
+        let factor = imp::powi64(10.0, i32::from(digits) - 1 - magnitude as i32);
+        let scaled = f64::from(self.value) * factor;
+        let mut rounded = imp::round64(scaled) / factor;
+        if rounded.abs() > f64::from(f32::MAX) {
+            rounded = imp::trunc64(scaled) / factor;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        Quantity::new(rounded as f32)
+    }
+
+    /// Round the value to the nearest multiple of `step`.
+    ///
+    /// ```
+    /// use tiny_uom::values::m;
+    ///
+    /// // snap a length to the nearest 5 mm
+    /// assert_eq!((0.123 * m).round_to_nearest(0.005 * m), 0.125 * m);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn round_to_nearest(self, step: Self) -> Self {
+        Quantity::new(imp::round(self.value / step.value) * step.value)
+    }
+}
+
+impl Quantity<0, 0, 0, 0, 0, 0, 0> {
+    /// Returns the square root of the value.
+    #[must_use]
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        Quantity::new(imp::sqrt(self.value))
+    }
+
+    /// Raises the value to a floating point power.
+    #[must_use]
+    #[inline]
+    pub fn powf(self, n: f32) -> Self {
+        Quantity::new(imp::powf(self.value, n))
+    }
+
+    /// Returns `e` raised to the power of the value.
+    #[must_use]
+    #[inline]
+    pub fn exp(self) -> Self {
+        Quantity::new(imp::exp(self.value))
+    }
+
+    /// Returns the natural logarithm of the value.
+    #[must_use]
+    #[inline]
+    pub fn ln(self) -> Self {
+        Quantity::new(imp::ln(self.value))
+    }
+
+    /// Returns the sine of this angle in radians.
+    #[must_use]
+    #[inline]
+    pub fn sin(self) -> Self {
+        Quantity::new(imp::sin(self.value))
+    }
+
+    /// Returns the cosine of this angle in radians.
+    #[must_use]
+    #[inline]
+    pub fn cos(self) -> Self {
+        Quantity::new(imp::cos(self.value))
+    }
+
+    /// Returns the tangent of this angle in radians.
+    #[must_use]
+    #[inline]
+    pub fn tan(self) -> Self {
+        Quantity::new(imp::tan(self.value))
+    }
+
+    /// Returns the arcsine of the value as an angle in radians.
+    #[must_use]
+    #[inline]
+    pub fn asin(self) -> Self {
+        Quantity::new(imp::asin(self.value))
+    }
+
+    /// Returns the arccosine of the value as an angle in radians.
+    #[must_use]
+    #[inline]
+    pub fn acos(self) -> Self {
+        Quantity::new(imp::acos(self.value))
+    }
+
+    /// Returns the arctangent of the value as an angle in radians.
+    #[must_use]
+    #[inline]
+    pub fn atan(self) -> Self {
+        Quantity::new(imp::atan(self.value))
+    }
+}
@@ -0,0 +1,40 @@
+//! Quantities of mechanics, using only the metre, kilogram and second.
+//!
+//! [`Quantity`] has three const parameters instead of seven, which reduces
+//! the work of the compiler for code that never uses the other base units,
+//! like firmware for motor control. They convert from and into the
+//! [`Quantity`](crate::Quantity) with the same exponents and format the same way.
+//!
+//! ```
+//! use tiny_uom::mechanics::{values::{m, s}, Quantity};
+//!
+//! let distance = 10.0 * m + 2.0 * m;
+//! let speed: Quantity<1, 0, -1> = distance.div_quantity(4.0 * s);
+//! assert_eq!(speed.to_string(), "3 m/s");
+//!
+//! let speed: tiny_uom::Quantity<1, 0, -1, 0, 0, 0, 0> = speed.into();
+//! assert_eq!(speed, 3.0 * (tiny_uom::values::m / tiny_uom::values::s));
+//! assert_eq!(Quantity::from(speed), Quantity::<1, 0, -1>::new(3.0));
+//! ```
+//!
+//! ```compile_fail
+//! use tiny_uom::mechanics::{values::{m, s}, Quantity};
+//!
+//! let speed: Quantity<1, 0, 1> = (12.0 * m).div_quantity(4.0 * s);
+//! ```
+
+#![allow(non_upper_case_globals)]
+
+crate::reduced::reduced_quantity!([m, kg, s], crate::Quantity<m, kg, s, 0, 0, 0, 0>);
+
+/// Constants for the multiplicative identities of each unit
+pub mod values {
+    use super::Quantity;
+
+    /// Time in seconds
+    pub const s: Quantity<0, 0, 1> = Quantity { value: 1.0 };
+    /// Length in metre
+    pub const m: Quantity<1, 0, 0> = Quantity { value: 1.0 };
+    /// Mass in kilogram
+    pub const kg: Quantity<0, 1, 0> = Quantity { value: 1.0 };
+}
@@ -0,0 +1,102 @@
+//! Vectors and matrices of quantities using [`nalgebra`].
+//!
+//! Quantities satisfy the bounds nalgebra places on the elements of a
+//! matrix, so `Vector3<Quantity<..>>` can be added, subtracted and compared.
+//! nalgebra only multiplies matrices with the same element type, so the
+//! functions of this module scale and transform matrices of quantities by
+//! plain numbers instead.
+//!
+//! ```
+//! use nalgebra::{Matrix3, Vector3};
+//! use tiny_uom::nalgebra::{mul, scale};
+//! use tiny_uom::values::m;
+//!
+//! let a = Vector3::new(1.0 * m, 2.0 * m, 3.0 * m);
+//! let b = Vector3::new(0.5 * m, 0.5 * m, 0.5 * m);
+//! assert_eq!(a + b, Vector3::new(1.5 * m, 2.5 * m, 3.5 * m));
+//! assert_eq!(scale(&a, 2.0), a + a);
+//!
+//! let rotation = Matrix3::new(0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+//! assert_eq!(mul(&rotation, &a), Vector3::new(-2.0 * m, 1.0 * m, 3.0 * m));
+//! ```
+//!
+//! [`nalgebra`]: https://nalgebra.org
+
+use crate::Quantity;
+use nalgebra::SMatrix;
+
+/// Wrap each raw value of the matrix into a quantity.
+#[must_use]
+pub fn from_values<
+    const R: usize,
+    const C: usize,
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    values: &SMatrix<f32, R, C>,
+) -> SMatrix<Quantity<m, kg, s, A, K, mol, cd>, R, C> {
+    values.map(Quantity::new)
+}
+
+/// Returns the raw values of the quantities in the matrix.
+#[must_use]
+pub fn values<
+    const R: usize,
+    const C: usize,
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    matrix: &SMatrix<Quantity<m, kg, s, A, K, mol, cd>, R, C>,
+) -> SMatrix<f32, R, C> {
+    matrix.map(|q| q.value)
+}
+
+/// Multiply every quantity of the matrix with a number.
+#[must_use]
+pub fn scale<
+    const R: usize,
+    const C: usize,
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    matrix: &SMatrix<Quantity<m, kg, s, A, K, mol, cd>, R, C>,
+    factor: f32,
+) -> SMatrix<Quantity<m, kg, s, A, K, mol, cd>, R, C> {
+    matrix.map(|q| q * factor)
+}
+
+/// Multiply a matrix of numbers with a matrix or vector of quantities,
+/// like a rotation applied to a force.
+#[must_use]
+pub fn mul<
+    const R: usize,
+    const C: usize,
+    const N: usize,
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    lhs: &SMatrix<f32, R, C>,
+    rhs: &SMatrix<Quantity<m, kg, s, A, K, mol, cd>, C, N>,
+) -> SMatrix<Quantity<m, kg, s, A, K, mol, cd>, R, N> {
+    from_values(&(lhs * values(rhs)))
+}
@@ -0,0 +1,32 @@
+//! [`ndarray`](https://docs.rs/ndarray) support for quantities.
+
+use crate::Quantity;
+use ndarray::ScalarOperand;
+
+/// Allows adding or subtracting a single quantity to every element of an array.
+///
+/// ```
+/// use ndarray::{array, Array2};
+/// use tiny_uom::values::K;
+///
+/// let readings = array![[273.15 * K, 283.15 * K], [293.15 * K, 303.15 * K]];
+/// let offset = readings.clone() - 273.15 * K;
+/// assert_eq!(offset[[1, 1]], 30.0 * K);
+///
+/// let doubled = &readings * 2.0 + &readings;
+/// assert_eq!(doubled, readings * 3.0);
+///
+/// let grid = Array2::<tiny_uom::Quantity<0, 0, 0, 0, 1, 0, 0>>::zeros((2, 3));
+/// assert_eq!(grid.sum(), 0.0 * K);
+/// ```
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > ScalarOperand for Quantity<m, kg, s, A, K, mol, cd>
+{
+}
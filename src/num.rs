@@ -0,0 +1,99 @@
+//! [`num-traits`](https://docs.rs/num-traits) support for quantities.
+//!
+//! `Zero` and `Bounded` are implemented for all quantities, while `One`,
+//! `Num` and `Signed` require multiplying quantities and are therefore only
+//! implemented for the dimensionless quantity.
+//!
+//! ```
+//! use num_traits::{Bounded, One, Signed, Zero};
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! fn sum<T: Zero + Copy>(values: &[T]) -> T {
+//!     values.iter().fold(T::zero(), |sum, &x| sum + x)
+//! }
+//! assert_eq!(sum(&[1.0 * m, 2.0 * m]), 3.0 * m);
+//! assert_eq!(Quantity::<1, 0, 0, 0, 0, 0, 0>::max_value(), f32::MAX * m);
+//!
+//! let ratio = Quantity::<0, 0, 0, 0, 0, 0, 0>::new(-0.5);
+//! assert_eq!(ratio.abs() + ratio.abs(), Quantity::one());
+//! ```
+
+use crate::Quantity;
+use num_traits::{Bounded, Num, One, Signed, Zero};
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Zero for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn zero() -> Self {
+        Self::new(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0.0
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Bounded for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn min_value() -> Self {
+        Self::new(f32::MIN)
+    }
+
+    fn max_value() -> Self {
+        Self::new(f32::MAX)
+    }
+}
+
+type Dimensionless = Quantity<0, 0, 0, 0, 0, 0, 0>;
+
+impl One for Dimensionless {
+    fn one() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl Num for Dimensionless {
+    type FromStrRadixErr = <f32 as Num>::FromStrRadixErr;
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f32::from_str_radix(src, radix).map(Self::new)
+    }
+}
+
+impl Signed for Dimensionless {
+    fn abs(&self) -> Self {
+        Self::new(self.value.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        Self::new(Signed::abs_sub(&self.value, &other.value))
+    }
+
+    fn signum(&self) -> Self {
+        Self::new(Signed::signum(&self.value))
+    }
+
+    fn is_positive(&self) -> bool {
+        Signed::is_positive(&self.value)
+    }
+
+    fn is_negative(&self) -> bool {
+        Signed::is_negative(&self.value)
+    }
+}
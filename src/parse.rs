@@ -0,0 +1,305 @@
+//! Parsing of quantities and unit expressions.
+
+use crate::{
+    error::{DimensionMismatch, ParseError},
+    registry::UnitRegistry,
+    Dimensioned, Quantity, Si, Unit,
+};
+use std::str::FromStr;
+
+/// The maximum nesting depth of parentheses, so malformed input can not overflow the stack.
+pub(crate) const MAX_DEPTH: usize = 32;
+
+/// An error of [`Lexer::exponent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ExponentError {
+    /// There are no digits at the position of the lexer.
+    InvalidSyntax,
+    /// The exponent does not fit into an `i8`.
+    Overflow,
+}
+
+/// The tokenizer of unit expressions.
+///
+/// It is shared by the parser of this module and the parser that the macros
+/// run in constants, so both accept exactly the same expressions.
+pub(crate) struct Lexer<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub(crate) const fn new(src: &'a str) -> Self {
+        Self {
+            src: src.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    /// Returns the byte offset of the next token.
+    pub(crate) const fn pos(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns `true` if only whitespace is left.
+    pub(crate) const fn is_done(&mut self) -> bool {
+        self.skip_whitespace();
+        self.pos == self.src.len()
+    }
+
+    /// Returns the character at `pos` and its length in bytes.
+    const fn char_at(&self, pos: usize) -> Option<(char, usize)> {
+        if pos >= self.src.len() {
+            return None;
+        }
+        let first = self.src[pos];
+        let (len, mut code) = match first {
+            0x00..=0x7F => (1, first as u32),
+            0xC0..=0xDF => (2, (first & 0x1F) as u32),
+            0xE0..=0xEF => (3, (first & 0x0F) as u32),
+            _ => (4, (first & 0x07) as u32),
+        };
+        let mut i = 1;
+        while i < len {
+            code = (code << 6) | (self.src[pos + i] & 0x3F) as u32;
+            i += 1;
+        }
+        match char::from_u32(code) {
+            Some(c) => Some((c, len)),
+            None => None,
+        }
+    }
+
+    pub(crate) const fn skip_whitespace(&mut self) {
+        while let Some((c, len)) = self.char_at(self.pos) {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.pos += len;
+        }
+    }
+
+    /// Skip whitespace and `token`, returning whether the token was found.
+    pub(crate) const fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let (token, rest) = (token.as_bytes(), self.src.split_at(self.pos).1);
+        if rest.len() < token.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < token.len() {
+            if rest[i] != token[i] {
+                return false;
+            }
+            i += 1;
+        }
+        self.pos += token.len();
+        true
+    }
+
+    /// Skip whitespace and return the symbol of a unit, which ends at
+    /// whitespace or an operator, or `None` if there is none.
+    pub(crate) const fn symbol(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let mut end = start;
+        while let Some((c, len)) = self.char_at(end) {
+            if c.is_whitespace() || matches!(c, '*' | '·' | '/' | '^' | '(' | ')') {
+                break;
+            }
+            end += len;
+        }
+        if end == start {
+            return None;
+        }
+        self.pos = end;
+        match core::str::from_utf8(self.src.split_at(end).0.split_at(start).1) {
+            Ok(symbol) => Some(symbol),
+            Err(_) => None,
+        }
+    }
+
+    /// Skip whitespace and return an integer exponent with an optional sign,
+    /// which has to be directly followed by the digits.
+    pub(crate) const fn exponent(&mut self) -> Result<i8, ExponentError> {
+        self.skip_whitespace();
+        let mut end = self.pos;
+        let negative = end < self.src.len() && self.src[end] == b'-';
+        if end < self.src.len() && matches!(self.src[end], b'-' | b'+') {
+            end += 1;
+        }
+        let digits = end;
+        let mut value: i16 = 0;
+        let mut overflow = false;
+        while end < self.src.len() && self.src[end].is_ascii_digit() {
+            if value > i8::MAX as i16 + 1 {
+                overflow = true;
+            } else {
+                value = value * 10 + (self.src[end] - b'0') as i16;
+            }
+            end += 1;
+        }
+        if end == digits {
+            return Err(ExponentError::InvalidSyntax);
+        }
+        self.pos = end;
+        let value = if negative { -value } else { value };
+        if overflow || value < i8::MIN as i16 || value > i8::MAX as i16 {
+            return Err(ExponentError::Overflow);
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(value as i8)
+    }
+}
+
+struct Parser<'a> {
+    lexer: Lexer<'a>,
+    depth: usize,
+    registry: &'a UnitRegistry,
+}
+
+impl Parser<'_> {
+    fn expr(&mut self) -> Result<(f64, Unit), ParseError> {
+        let (mut factor, mut unit) = self.factor()?;
+        loop {
+            let divide = if self.lexer.eat("*") || self.lexer.eat("·") {
+                false
+            } else if self.lexer.eat("/") {
+                true
+            } else {
+                return Ok((factor, unit));
+            };
+
+            let (rhs_factor, rhs_unit) = self.factor()?;
+            let (rhs_factor, rhs_unit) = if divide {
+                (rhs_factor.recip(), rhs_unit.checked_powi(-1))
+            } else {
+                (rhs_factor, Some(rhs_unit))
+            };
+
+            factor *= rhs_factor;
+            unit = rhs_unit
+                .and_then(|rhs| unit.checked_mul(rhs))
+                .ok_or(ParseError::ExponentOverflow)?;
+        }
+    }
+
+    fn factor(&mut self) -> Result<(f64, Unit), ParseError> {
+        self.lexer.skip_whitespace();
+        let start = self.lexer.pos();
+
+        let (factor, unit) = if self.lexer.eat("(") {
+            if self.depth == MAX_DEPTH {
+                return Err(ParseError::InvalidSyntax(start));
+            }
+            self.depth += 1;
+            let inner = self.expr()?;
+            self.depth -= 1;
+            if !self.lexer.eat(")") {
+                return Err(ParseError::InvalidSyntax(self.lexer.pos()));
+            }
+            inner
+        } else if self.lexer.eat("1") {
+            (1.0, Unit::DIMENSIONLESS)
+        } else {
+            let symbol = self
+                .lexer
+                .symbol()
+                .ok_or(ParseError::InvalidSyntax(start))?;
+            let (prefix, def) = self
+                .registry
+                .lookup(symbol)
+                .ok_or_else(|| ParseError::UnknownUnit(symbol.to_string()))?;
+            (prefix * def.factor, def.dimension)
+        };
+
+        if !self.lexer.eat("^") {
+            return Ok((factor, unit));
+        }
+
+        let exp = self.lexer.exponent().map_err(|err| match err {
+            ExponentError::InvalidSyntax => ParseError::InvalidSyntax(self.lexer.pos()),
+            ExponentError::Overflow => ParseError::ExponentOverflow,
+        })?;
+        let unit = unit.checked_powi(exp).ok_or(ParseError::ExponentOverflow)?;
+        Ok((factor.powi(exp.into()), unit))
+    }
+}
+
+/// Parse a unit expression like `km/h` or `kg*m^2/s^2` into the factor that
+/// converts values into SI units and the resulting [`Unit`].
+pub(crate) fn parse_unit(registry: &UnitRegistry, src: &str) -> Result<(f64, Unit), ParseError> {
+    let mut parser = Parser {
+        lexer: Lexer::new(src),
+        depth: 0,
+        registry,
+    };
+    let unit = parser.expr()?;
+    if parser.lexer.is_done() {
+        Ok(unit)
+    } else {
+        Err(ParseError::InvalidSyntax(parser.lexer.pos()))
+    }
+}
+
+/// Parse a value followed by a unit expression, separated by whitespace,
+/// into a quantity of type `Q`.
+pub(crate) fn parse_quantity<Q: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>>(
+    registry: &UnitRegistry,
+    src: &str,
+) -> Result<Q, ParseError> {
+    let src = src.trim();
+    let (value, unit) = src.split_once(char::is_whitespace).unwrap_or((src, "1"));
+    let value = value.parse::<f32>().map_err(ParseError::InvalidNumber)?;
+
+    let expected = Unit::from_exponents(Q::EXPONENTS);
+    let (factor, unit) = parse_unit(registry, unit)?;
+    if unit != expected {
+        return Err(DimensionMismatch {
+            expected,
+            found: unit,
+        }
+        .into());
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    Ok(Q::from_raw((f64::from(value) * factor) as f32))
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > FromStr for Quantity<m, kg, s, A, K, mol, cd>
+{
+    type Err = ParseError;
+
+    /// Parse a value followed by a unit expression, separated by whitespace.
+    ///
+    /// The unit may use any unit of the built-in [`UnitRegistry`],
+    /// and the value is converted into SI units.
+    ///
+    /// ```
+    /// use tiny_uom::values::{m, s};
+    /// use tiny_uom::ParseQuantityError;
+    ///
+    /// let velocity = 5.0 * (m / s);
+    /// assert_eq!(velocity.to_string(), "5 m/s");
+    /// assert_eq!(velocity.to_string().parse(), Ok(velocity));
+    ///
+    /// let length: tiny_uom::Quantity<1, 0, 0, 0, 0, 0, 0> = "2.5 km".parse().unwrap();
+    /// assert_eq!(length, 2500.0 * m);
+    ///
+    /// assert!(matches!(
+    ///     "3 s".parse::<tiny_uom::Quantity<1, 0, 0, 0, 0, 0, 0>>(),
+    ///     Err(ParseQuantityError::DimensionMismatch(_))
+    /// ));
+    /// ```
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        UnitRegistry::builtin().parse_quantity(src)
+    }
+}
@@ -0,0 +1,211 @@
+//! Quantity-typed chart axes for [`plotters`].
+//!
+//! A [`QuantityRange`] can be passed to `ChartBuilder::build_cartesian_2d`
+//! like a `Range<f32>`. Its tick labels are formatted using [`label`], which
+//! adds the unit of the axis with an SI prefix, like `1.5 km` or `250 ms`.
+//!
+//! ```
+//! use plotters::coord::ranged1d::{Ranged, ValueFormatter};
+//! use tiny_uom::plotters::{label, QuantityRange};
+//! use tiny_uom::values::{kg, m, s};
+//!
+//! let axis = QuantityRange::new(0.0 * m..2000.0 * m);
+//! assert_eq!(axis.map(&(1000.0 * m), (0, 100)), 50);
+//! assert_eq!(axis.format_ext(&(1500.0 * m)), "1.5 km");
+//!
+//! assert_eq!(label(0.25 * s), "250 ms");
+//! assert_eq!(label(0.002 * kg), "2 g");
+//! assert_eq!(label(3.0 * (m / s)), "3 m/s");
+//! ```
+//!
+//! [`plotters`]: https://docs.rs/plotters
+
+use crate::{Quantity, Unit};
+use plotters::coord::ranged1d::{
+    KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+use plotters::coord::types::RangedCoordf32;
+use std::{marker::PhantomData, ops::Range};
+
+#[rustfmt::skip]
+static PREFIXES: &[(i32, &str)] = &[
+    (30, "Q"), (27, "R"), (24, "Y"), (21, "Z"), (18, "E"), (15, "P"), (12, "T"), (9, "G"),
+    (6, "M"), (3, "k"), (0, ""), (-3, "m"), (-6, "µ"), (-9, "n"), (-12, "p"), (-15, "f"),
+    (-18, "a"), (-21, "z"), (-24, "y"), (-27, "r"), (-30, "q"),
+];
+
+/// The number of significant digits used for labels.
+const DIGITS: i32 = 6;
+
+/// Format a number with [`DIGITS`] significant digits and without trailing zeros.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_number(value: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return value.to_string();
+    }
+
+    let precision = (DIGITS - 1 - value.abs().log10().floor() as i32).max(0);
+    let out = format!("{value:.0$}", precision as usize);
+    if out.contains('.') {
+        out.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        out
+    }
+}
+
+/// Format a quantity as a tick label with an SI prefix.
+///
+/// Prefixes are only used for the base units and the derived units
+/// with a special name. All other quantities are formatted like `3 m/s`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn label<
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    quantity: Quantity<m, kg, s, A, K, mol, cd>,
+) -> String {
+    let unit = Quantity::<m, kg, s, A, K, mol, cd>::UNIT;
+    let value = f64::from(quantity.value);
+
+    let (value, symbol) = if unit == Unit::from_exponents([0, 1, 0, 0, 0, 0, 0]) {
+        (value * 1e3, "g")
+    } else if let Some(info) = unit.info() {
+        (value, info.symbol)
+    } else if unit.is_dimensionless() {
+        return format_number(value);
+    } else {
+        return format!("{} {unit}", format_number(value));
+    };
+
+    let exp = if value == 0.0 || !value.is_finite() {
+        0
+    } else {
+        value.abs().log10().floor() as i32
+    };
+    let (exp, prefix) = PREFIXES
+        .iter()
+        .find(|&&(prefix, _)| prefix <= exp)
+        .unwrap_or(&PREFIXES[PREFIXES.len() - 1]);
+    format!(
+        "{} {prefix}{symbol}",
+        format_number(value / 10f64.powi(*exp))
+    )
+}
+
+/// A chart axis for quantities of type `Q`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuantityRange<Q> {
+    start: f32,
+    end: f32,
+    unit: PhantomData<Q>,
+}
+
+impl<Q> QuantityRange<Q> {
+    fn inner(&self) -> RangedCoordf32 {
+        (self.start..self.end).into()
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > QuantityRange<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    /// Create an axis covering the given range.
+    #[must_use]
+    pub fn new(range: Range<Quantity<m, kg, s, A, K, mol, cd>>) -> Self {
+        Self {
+            start: range.start.value,
+            end: range.end.value,
+            unit: PhantomData,
+        }
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > From<Range<Quantity<m, kg, s, A, K, mol, cd>>>
+    for QuantityRange<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    fn from(range: Range<Quantity<m, kg, s, A, K, mol, cd>>) -> Self {
+        Self::new(range)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Ranged for QuantityRange<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = Quantity<m, kg, s, A, K, mol, cd>;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.inner().map(&value.value, limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        self.inner()
+            .key_points(hint)
+            .into_iter()
+            .map(Quantity::new)
+            .collect()
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        Quantity::new(self.start)..Quantity::new(self.end)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > ReversibleRanged for QuantityRange<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        self.inner().unmap(input, limit).map(Quantity::new)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > ValueFormatter<Quantity<m, kg, s, A, K, mol, cd>>
+    for QuantityRange<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    fn format(value: &Quantity<m, kg, s, A, K, mol, cd>) -> String {
+        label(*value)
+    }
+}
@@ -0,0 +1,116 @@
+//! Points of affine spaces, like positions, absolute temperatures and timestamps.
+//!
+//! A [`Point`] is a quantity measured from an origin. The difference of two
+//! points is a quantity, and a quantity can be added to or subtracted from a
+//! point, but adding two points fails to compile, because the result would
+//! depend on the choice of the origin.
+//!
+//! ```
+//! use tiny_uom::point::{Point, Position, Timestamp};
+//! use tiny_uom::values::{m, s};
+//!
+//! let start = Position::new(10.0 * m);
+//! let end = start + 5.0 * m;
+//! assert_eq!(end - start, 5.0 * m);
+//! assert_eq!(end.from_origin(), 15.0 * m);
+//!
+//! let launch = Timestamp::new(100.0 * s);
+//! assert_eq!(launch - 30.0 * s, Point::new(70.0 * s));
+//! ```
+//!
+//! ```compile_fail
+//! use tiny_uom::point::Position;
+//! use tiny_uom::values::m;
+//!
+//! let sum = Position::new(1.0 * m) + Position::new(2.0 * m);
+//! ```
+
+use crate::Quantity;
+use core::fmt;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A point in the affine space of the quantity `Q`, stored as its offset from the origin.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Point<Q> {
+    offset: Q,
+}
+
+/// A position, measured in metres from an origin.
+pub type Position = Point<Quantity<1, 0, 0, 0, 0, 0, 0>>;
+
+/// An absolute temperature, measured in kelvin from absolute zero.
+pub type AbsoluteTemperature = Point<Quantity<0, 0, 0, 0, 1, 0, 0>>;
+
+/// A point in time, measured in seconds from an epoch.
+pub type Timestamp = Point<Quantity<0, 0, 1, 0, 0, 0, 0>>;
+
+impl<Q> Point<Q> {
+    /// Create the point at `offset` from the origin.
+    #[must_use]
+    #[inline]
+    pub const fn new(offset: Q) -> Self {
+        Self { offset }
+    }
+}
+
+impl<Q: Copy> Point<Q> {
+    /// Returns the offset of this point from the origin.
+    #[must_use]
+    #[inline]
+    pub const fn from_origin(self) -> Q {
+        self.offset
+    }
+}
+
+impl<Q: Sub<Output = Q>> Sub for Point<Q> {
+    type Output = Q;
+
+    /// The displacement from `rhs` to `self`.
+    #[inline]
+    fn sub(self, rhs: Self) -> Q {
+        self.offset - rhs.offset
+    }
+}
+
+impl<Q: Add<Output = Q>> Add<Q> for Point<Q> {
+    type Output = Self;
+
+    /// Move this point by a displacement.
+    #[inline]
+    fn add(self, rhs: Q) -> Self {
+        Self::new(self.offset + rhs)
+    }
+}
+
+impl<Q: Sub<Output = Q>> Sub<Q> for Point<Q> {
+    type Output = Self;
+
+    /// Move this point backwards by a displacement.
+    #[inline]
+    fn sub(self, rhs: Q) -> Self {
+        Self::new(self.offset - rhs)
+    }
+}
+
+impl<Q: AddAssign> AddAssign<Q> for Point<Q> {
+    /// Move this point by a displacement.
+    #[inline]
+    fn add_assign(&mut self, rhs: Q) {
+        self.offset += rhs;
+    }
+}
+
+impl<Q: SubAssign> SubAssign<Q> for Point<Q> {
+    /// Move this point backwards by a displacement.
+    #[inline]
+    fn sub_assign(&mut self, rhs: Q) {
+        self.offset -= rhs;
+    }
+}
+
+impl<Q: fmt::Display> fmt::Display for Point<Q> {
+    /// Formats the offset from the origin, like `15 m`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.offset, f)
+    }
+}
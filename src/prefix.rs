@@ -0,0 +1,95 @@
+//! Scaling quantities by SI prefixes.
+//!
+//! Every prefix has a function that multiplies any quantity with its factor,
+//! so prefixed units are composed from the base units instead of being
+//! defined one by one. The [`Prefix`] enum selects a prefix at runtime.
+//!
+//! ```
+//! use tiny_uom::prefix::{kilo, milli, Prefix};
+//! use tiny_uom::values::{m, s, A};
+//!
+//! assert_eq!(kilo(m), 1000.0 * m);
+//! assert_eq!(kilo(2.5 * m) / milli(s), 2_500_000.0 * (m / s));
+//! assert_eq!(Prefix::Milli.apply(3.0 * A), milli(3.0 * A));
+//! assert_eq!(Prefix::Micro.symbol(), "µ");
+//! ```
+
+use crate::Dimensioned;
+
+/// Define the [`Prefix`] enum and a function for every prefix.
+macro_rules! prefixes {
+    ($($variant:ident, $fn:ident, $symbol:literal, $power:literal, $factor:literal;)+) => {
+        /// An SI prefix.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum Prefix {
+            $(
+                #[doc = concat!("The prefix `", $symbol, "` for a factor of ", $power, ".")]
+                $variant,
+            )+
+        }
+
+        impl Prefix {
+            /// All prefixes, from the smallest to the largest factor.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            /// Returns the factor of this prefix.
+            #[must_use]
+            pub const fn factor(self) -> f32 {
+                match self {
+                    $(Self::$variant => $factor,)+
+                }
+            }
+
+            /// Returns the symbol of this prefix, like `k` for kilo.
+            #[must_use]
+            pub const fn symbol(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $symbol,)+
+                }
+            }
+
+            /// Multiply a quantity with the factor of this prefix.
+            #[must_use]
+            #[inline]
+            pub fn apply<Q: Dimensioned<Scalar = f32>>(self, quantity: Q) -> Q {
+                Q::from_raw(quantity.raw() * self.factor())
+            }
+        }
+
+        $(
+            #[doc = concat!("Multiply a quantity with ", $power, ", the factor of the prefix `", $symbol, "`.")]
+            #[must_use]
+            #[inline]
+            pub fn $fn<Q: Dimensioned<Scalar = f32>>(quantity: Q) -> Q {
+                Prefix::$variant.apply(quantity)
+            }
+        )+
+    };
+}
+
+prefixes! {
+    Quecto, quecto, "q", "10⁻³⁰", 1e-30;
+    Ronto, ronto, "r", "10⁻²⁷", 1e-27;
+    Yocto, yocto, "y", "10⁻²⁴", 1e-24;
+    Zepto, zepto, "z", "10⁻²¹", 1e-21;
+    Atto, atto, "a", "10⁻¹⁸", 1e-18;
+    Femto, femto, "f", "10⁻¹⁵", 1e-15;
+    Pico, pico, "p", "10⁻¹²", 1e-12;
+    Nano, nano, "n", "10⁻⁹", 1e-9;
+    Micro, micro, "µ", "10⁻⁶", 1e-6;
+    Milli, milli, "m", "10⁻³", 1e-3;
+    Centi, centi, "c", "10⁻²", 1e-2;
+    Deci, deci, "d", "10⁻¹", 1e-1;
+    Deca, deca, "da", "10¹", 1e1;
+    Hecto, hecto, "h", "10²", 1e2;
+    Kilo, kilo, "k", "10³", 1e3;
+    Mega, mega, "M", "10⁶", 1e6;
+    Giga, giga, "G", "10⁹", 1e9;
+    Tera, tera, "T", "10¹²", 1e12;
+    Peta, peta, "P", "10¹⁵", 1e15;
+    Exa, exa, "E", "10¹⁸", 1e18;
+    Zetta, zetta, "Z", "10²¹", 1e21;
+    Yotta, yotta, "Y", "10²⁴", 1e24;
+    Ronna, ronna, "R", "10²⁷", 1e27;
+    Quetta, quetta, "Q", "10³⁰", 1e30;
+}
@@ -0,0 +1,40 @@
+//! The most-used items of this crate, for importing them all at once.
+//!
+//! This includes the type aliases of [`quantities`](crate::quantities) and
+//! the traits whose methods are used on quantities, like
+//! [`SameDimension::into_same`]. The unit constants are in [`units`], so
+//! their short names like `m` and `s` do not turn the bindings of the
+//! importing code into constant patterns.
+//!
+//! ```
+//! use tiny_uom::prelude::*;
+//! use tiny_uom::prelude::units::{m, s};
+//!
+//! let velocity: Velocity = 10.0 * m / (2.0 * s);
+//! assert_eq!(velocity, 5.0 * (m / s));
+//!
+//! let start = Position::new(1.0 * m);
+//! assert_eq!((start + 2.0 * m).from_origin(), 3.0 * m);
+//! assert_eq!(Length::UNIT, Unit::from_exponents([1, 0, 0, 0, 0, 0, 0]));
+//! ```
+//!
+//! Names like `s` stay usable for local bindings:
+//!
+//! ```
+//! use tiny_uom::prelude::*;
+//!
+//! let s = String::from("hi");
+//! let m = 3;
+//! assert_eq!((s.len(), m), (2, 3));
+//! ```
+
+pub use crate::point::{AbsoluteTemperature, Point, Position, Timestamp};
+pub use crate::quantities::*;
+#[cfg(feature = "derive")]
+pub use crate::{qty, QuantityStruct};
+pub use crate::{Dimensioned, Quantity, SameDimension, Unit, UnitInfo};
+
+/// The constants of the SI base units, like [`m`](units::m) and [`s`](units::s).
+pub mod units {
+    pub use crate::values::*;
+}
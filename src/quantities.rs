@@ -0,0 +1,72 @@
+//! Type aliases for the most common quantities of the [ISQ].
+//!
+//! ```
+//! use tiny_uom::quantities::{Length, Time, Velocity};
+//! use tiny_uom::values::{m, s};
+//!
+//! let distance: Length = 10.0 * m;
+//! let time: Time = 2.0 * s;
+//! let velocity: Velocity = distance / time;
+//! assert_eq!(velocity, 5.0 * (m / s));
+//! ```
+//!
+//! [ISQ]: https://jcgm.bipm.org/vim/en/1.6.html
+
+use crate::Quantity;
+
+/// A length, in metres.
+pub type Length = Quantity<1, 0, 0, 0, 0, 0, 0>;
+
+/// A mass, in kilograms.
+pub type Mass = Quantity<0, 1, 0, 0, 0, 0, 0>;
+
+/// A duration, in seconds.
+pub type Time = Quantity<0, 0, 1, 0, 0, 0, 0>;
+
+/// An electric current, in amperes.
+pub type ElectricCurrent = Quantity<0, 0, 0, 1, 0, 0, 0>;
+
+/// A thermodynamic temperature, in kelvin.
+pub type Temperature = Quantity<0, 0, 0, 0, 1, 0, 0>;
+
+/// An amount of substance, in moles.
+pub type AmountOfSubstance = Quantity<0, 0, 0, 0, 0, 1, 0>;
+
+/// A luminous intensity, in candelas.
+pub type LuminousIntensity = Quantity<0, 0, 0, 0, 0, 0, 1>;
+
+/// An area, in square metres.
+pub type Area = Quantity<2, 0, 0, 0, 0, 0, 0>;
+
+/// A volume, in cubic metres.
+pub type Volume = Quantity<3, 0, 0, 0, 0, 0, 0>;
+
+/// A velocity, in metres per second.
+pub type Velocity = Quantity<1, 0, -1, 0, 0, 0, 0>;
+
+/// An acceleration, in metres per second squared.
+pub type Acceleration = Quantity<1, 0, -2, 0, 0, 0, 0>;
+
+/// A frequency, in hertz.
+pub type Frequency = Quantity<0, 0, -1, 0, 0, 0, 0>;
+
+/// A force, in newtons.
+pub type Force = Quantity<1, 1, -2, 0, 0, 0, 0>;
+
+/// A pressure, in pascals.
+pub type Pressure = Quantity<-1, 1, -2, 0, 0, 0, 0>;
+
+/// An energy, in joules.
+pub type Energy = Quantity<2, 1, -2, 0, 0, 0, 0>;
+
+/// A power, in watts.
+pub type Power = Quantity<2, 1, -3, 0, 0, 0, 0>;
+
+/// An electric charge, in coulombs.
+pub type ElectricCharge = Quantity<0, 0, 1, 1, 0, 0, 0>;
+
+/// A voltage, in volts.
+pub type Voltage = Quantity<2, 1, -3, -1, 0, 0, 0>;
+
+/// An electrical resistance, in ohms.
+pub type Resistance = Quantity<2, 1, -3, -2, 0, 0, 0>;
@@ -0,0 +1,37 @@
+//! [`quickcheck`](https://docs.rs/quickcheck) support for quantities.
+//!
+//! Quantities are generated and shrunk like their raw `f32` value,
+//! so failing inputs shrink toward zero.
+//!
+//! ```
+//! use quickcheck::quickcheck;
+//! use tiny_uom::Quantity;
+//!
+//! fn double_is_sum(length: Quantity<1, 0, 0, 0, 0, 0, 0>) -> bool {
+//!     !length.raw().is_finite() || length * 2.0 == length + length
+//! }
+//! quickcheck(double_is_sum as fn(_) -> bool);
+//! ```
+
+use crate::Quantity;
+use alloc::boxed::Box;
+use quickcheck::{Arbitrary, Gen};
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Arbitrary for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self::new(f32::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.value.shrink().map(Self::new))
+    }
+}
@@ -0,0 +1,191 @@
+//! Sampling quantities using [`rand`].
+//!
+//! Quantities implement [`SampleUniform`] and [`PartialOrd`], so uniform
+//! ranges of quantities can be sampled directly. [`Normal`] samples normally
+//! distributed quantities, and [`Scaled`] turns any distribution of `f32`
+//! into one of quantities.
+//!
+//! ```
+//! use rand::RngExt;
+//! use rand_distr::{Distribution, Exp};
+//! use tiny_uom::rand::{Normal, Scaled};
+//! use tiny_uom::values::{m, s};
+//!
+//! let mut rng = rand::rng();
+//! let length = rng.random_range(1.0 * m..2.0 * m);
+//! assert!(length >= 1.0 * m && length < 2.0 * m);
+//!
+//! let measured = Normal::new(10.0 * m, 0.5 * m).unwrap();
+//! let samples: Vec<_> = measured.sample_iter(&mut rng).take(1000).collect();
+//! let mean = samples.iter().fold(0.0 * m, |sum, &x| sum + x) / 1000.0;
+//! assert!(mean > 9.8 * m && mean < 10.2 * m);
+//!
+//! let arrival = Scaled::new(Exp::new(1.0).unwrap(), 60.0 * s);
+//! assert!(arrival.sample(&mut rng) >= 0.0 * s);
+//! ```
+//!
+//! [`rand`]: https://docs.rs/rand
+
+use crate::Quantity;
+use rand::{
+    distr::{
+        uniform::{Error, SampleBorrow, SampleUniform, UniformFloat, UniformSampler},
+        Distribution,
+    },
+    Rng,
+};
+use rand_distr::NormalError;
+use std::marker::PhantomData;
+
+/// The [`UniformSampler`] of quantities.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformQuantity<Q> {
+    inner: UniformFloat<f32>,
+    unit: PhantomData<Q>,
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > UniformSampler for UniformQuantity<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    type X = Quantity<m, kg, s, A, K, mol, cd>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Ok(Self {
+            inner: UniformFloat::new(low.borrow().value, high.borrow().value)?,
+            unit: PhantomData,
+        })
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Ok(Self {
+            inner: UniformFloat::new_inclusive(low.borrow().value, high.borrow().value)?,
+            unit: PhantomData,
+        })
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Quantity::new(self.inner.sample(rng))
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > SampleUniform for Quantity<m, kg, s, A, K, mol, cd>
+{
+    type Sampler = UniformQuantity<Self>;
+}
+
+/// The normal distribution of quantities of type `Q`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normal<Q> {
+    inner: rand_distr::Normal<f32>,
+    unit: PhantomData<Q>,
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Normal<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    /// Create a normal distribution with the given mean and standard deviation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the standard deviation is negative or not finite.
+    pub fn new(
+        mean: Quantity<m, kg, s, A, K, mol, cd>,
+        std_dev: Quantity<m, kg, s, A, K, mol, cd>,
+    ) -> Result<Self, NormalError> {
+        Ok(Self {
+            inner: rand_distr::Normal::new(mean.value, std_dev.value)?,
+            unit: PhantomData,
+        })
+    }
+
+    /// Returns the mean of the distribution.
+    #[must_use]
+    pub fn mean(&self) -> Quantity<m, kg, s, A, K, mol, cd> {
+        Quantity::new(self.inner.mean())
+    }
+
+    /// Returns the standard deviation of the distribution.
+    #[must_use]
+    pub fn std_dev(&self) -> Quantity<m, kg, s, A, K, mol, cd> {
+        Quantity::new(self.inner.std_dev())
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Distribution<Quantity<m, kg, s, A, K, mol, cd>>
+    for Normal<Quantity<m, kg, s, A, K, mol, cd>>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quantity<m, kg, s, A, K, mol, cd> {
+        Quantity::new(self.inner.sample(rng))
+    }
+}
+
+/// A distribution of `f32` whose samples are multiplied with a quantity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scaled<D, Q> {
+    /// The distribution of the numbers.
+    pub distribution: D,
+    /// The quantity the samples are multiplied with.
+    pub unit: Q,
+}
+
+impl<D, Q> Scaled<D, Q> {
+    /// Create a distribution of `unit` multiplied with the samples of `distribution`.
+    pub const fn new(distribution: D, unit: Q) -> Self {
+        Self { distribution, unit }
+    }
+}
+
+impl<
+        D: Distribution<f32>,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Distribution<Quantity<m, kg, s, A, K, mol, cd>>
+    for Scaled<D, Quantity<m, kg, s, A, K, mol, cd>>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quantity<m, kg, s, A, K, mol, cd> {
+        self.unit * self.distribution.sample(rng)
+    }
+}
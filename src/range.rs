@@ -0,0 +1,341 @@
+//! Iterators over evenly spaced quantities.
+//!
+//! [`linspace`] yields a fixed number of quantities between two endpoints,
+//! and [`QuantityRange::step_by`] yields the quantities of a half-open range
+//! with a fixed step. The values are computed from their index, so rounding
+//! errors do not add up over long sweeps.
+//!
+//! [`iter_steps`] instead adds the step repeatedly, which is exact for
+//! quantities backed by integers or fixed-point numbers, like the ones
+//! defined with [`quantity_ops!`](crate::quantity_ops).
+//!
+//! ```
+//! use tiny_uom::range::{linspace, QuantityRange};
+//! use tiny_uom::values::{m, s};
+//!
+//! let positions: Vec<_> = linspace(0.0 * m, 1.0 * m, 5).collect();
+//! assert_eq!(positions, [0.0 * m, 0.25 * m, 0.5 * m, 0.75 * m, 1.0 * m]);
+//!
+//! let times: Vec<_> = QuantityRange::new(0.0 * s, 1.0 * s).step_by(0.4 * s).collect();
+//! assert_eq!(times, [0.0 * s, 0.4 * s, 0.8 * s]);
+//! ```
+
+use crate::Dimensioned;
+use core::cmp::Ordering;
+use core::ops::{Add, Range, Sub};
+
+/// Returns an iterator over `n` evenly spaced quantities from `start` to `end`,
+/// including both endpoints.
+///
+/// A single quantity is `start`, and no quantities are yielded for `n == 0`.
+#[must_use]
+pub fn linspace<Q: Dimensioned<Scalar = f32>>(start: Q, end: Q, n: usize) -> Linspace<Q> {
+    Linspace {
+        start,
+        end,
+        n,
+        idx: 0,
+    }
+}
+
+/// An iterator over evenly spaced quantities, returned by [`linspace`].
+#[derive(Clone, Debug)]
+pub struct Linspace<Q> {
+    start: Q,
+    end: Q,
+    n: usize,
+    idx: usize,
+}
+
+impl<Q: Dimensioned<Scalar = f32>> Iterator for Linspace<Q> {
+    type Item = Q;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn next(&mut self) -> Option<Q> {
+        if self.idx >= self.n {
+            return None;
+        }
+        let (start, end) = (self.start.raw(), self.end.raw());
+        let value = match self.idx {
+            0 => start,
+            idx if idx + 1 == self.n => end,
+            idx => start + (end - start) * (idx as f32 / (self.n - 1) as f32),
+        };
+        self.idx += 1;
+        Some(Q::from_raw(value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.n - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> ExactSizeIterator for Linspace<Q> {}
+
+/// The half-open range of quantities from `start` up to, but excluding, `end`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct QuantityRange<Q> {
+    /// The lower bound, which is included.
+    pub start: Q,
+    /// The upper bound, which is excluded.
+    pub end: Q,
+}
+
+impl<Q: Dimensioned<Scalar = f32>> QuantityRange<Q> {
+    /// Create the range from `start` up to `end`.
+    #[must_use]
+    #[inline]
+    pub const fn new(start: Q, end: Q) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns `true` if `quantity` lies within this range.
+    ///
+    /// ```
+    /// use tiny_uom::range::QuantityRange;
+    /// use tiny_uom::values::K;
+    ///
+    /// let envelope = QuantityRange::new(233.15 * K, 358.15 * K);
+    /// assert!(envelope.contains(293.15 * K));
+    /// assert!(!envelope.contains(358.15 * K));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, quantity: Q) -> bool {
+        self.start.raw() <= quantity.raw() && quantity.raw() < self.end.raw()
+    }
+
+    /// Returns `true` if this range contains no quantities.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start.raw().partial_cmp(&self.end.raw()) != Some(Ordering::Less)
+    }
+
+    /// Returns the distance from `start` to `end`, which is negative if
+    /// `end` is below `start`.
+    #[must_use]
+    #[inline]
+    pub fn length(&self) -> Q {
+        Q::from_raw(self.end.raw() - self.start.raw())
+    }
+
+    /// Restrict `quantity` to the bounds of this range, including `end`.
+    ///
+    /// ```
+    /// use tiny_uom::range::QuantityRange;
+    /// use tiny_uom::Quantity;
+    ///
+    /// type Velocity = Quantity<1, 0, -1, 0, 0, 0, 0>;
+    ///
+    /// let limit = QuantityRange::new(Velocity::new(0.0), Velocity::new(13.9));
+    /// assert_eq!(limit.clamp(Velocity::new(15.0)), Velocity::new(13.9));
+    /// assert_eq!(limit.clamp(Velocity::new(-1.0)), Velocity::new(0.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is greater than `end` or a bound is NaN, like [`f32::clamp`].
+    #[must_use]
+    #[inline]
+    pub fn clamp(&self, quantity: Q) -> Q {
+        Q::from_raw(quantity.raw().clamp(self.start.raw(), self.end.raw()))
+    }
+
+    /// Returns the range of quantities contained in both ranges, or `None`
+    /// if they do not overlap.
+    ///
+    /// ```
+    /// use tiny_uom::range::QuantityRange;
+    /// use tiny_uom::values::K;
+    ///
+    /// let sensor = QuantityRange::new(233.0 * K, 398.0 * K);
+    /// let battery = QuantityRange::new(253.0 * K, 333.0 * K);
+    /// assert_eq!(sensor.intersect(battery), Some(QuantityRange::new(253.0 * K, 333.0 * K)));
+    /// assert_eq!(sensor.intersect(QuantityRange::new(400.0 * K, 500.0 * K)), None);
+    /// ```
+    #[must_use]
+    pub fn intersect(&self, other: Self) -> Option<Self> {
+        let range = Self::new(
+            Q::from_raw(self.start.raw().max(other.start.raw())),
+            Q::from_raw(self.end.raw().min(other.end.raw())),
+        );
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// Returns an iterator over the quantities `start + i * step` of this range.
+    ///
+    /// A negative step yields the quantities from `start` down to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is zero or NaN.
+    #[must_use]
+    pub fn step_by(self, step: Q) -> Steps<Q> {
+        assert!(
+            step.raw() != 0.0 && !step.raw().is_nan(),
+            "the step must not be zero or NaN"
+        );
+        Steps {
+            range: self,
+            step,
+            idx: 0,
+        }
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> From<Range<Q>> for QuantityRange<Q> {
+    fn from(range: Range<Q>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+/// An iterator over the quantities of a range with a fixed step,
+/// returned by [`QuantityRange::step_by`].
+#[derive(Clone, Debug)]
+pub struct Steps<Q> {
+    range: QuantityRange<Q>,
+    step: Q,
+    idx: usize,
+}
+
+impl<Q: Dimensioned<Scalar = f32>> Steps<Q> {
+    /// Returns the quantity with the index `idx` if it lies within the range.
+    #[allow(clippy::cast_precision_loss)]
+    fn get(&self, idx: usize) -> Option<f32> {
+        let step = self.step.raw();
+        let value = self.range.start.raw() + idx as f32 * step;
+        let inside = if step > 0.0 {
+            value < self.range.end.raw()
+        } else {
+            value > self.range.end.raw()
+        };
+        inside.then_some(value)
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> Iterator for Steps<Q> {
+    type Item = Q;
+
+    fn next(&mut self) -> Option<Q> {
+        let value = self.get(self.idx)?;
+        self.idx += 1;
+        Some(Q::from_raw(value))
+    }
+
+    /// Returns the exact number of remaining quantities, which is estimated
+    /// by dividing the length of the range by the step and then corrected
+    /// with the same comparison as [`next`](Self::next).
+    ///
+    /// ```
+    /// use tiny_uom::range::QuantityRange;
+    /// use tiny_uom::values::s;
+    ///
+    /// let mut steps = QuantityRange::new(0.0 * s, 1.0 * s).step_by(0.1 * s);
+    /// assert_eq!(steps.size_hint(), (10, Some(10)));
+    /// steps.next();
+    /// assert_eq!(steps.size_hint(), (9, Some(9)));
+    /// assert_eq!(steps.count(), 9);
+    /// ```
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let estimate = f64::from(self.range.length().raw()) / f64::from(self.step.raw());
+        // saturates for infinite ranges, and is zero for NaN bounds
+        let mut len = estimate as usize;
+        if len == usize::MAX {
+            return (usize::MAX, None);
+        }
+        while self.get(len).is_some() {
+            len += 1;
+        }
+        while len > 0 && self.get(len - 1).is_none() {
+            len -= 1;
+        }
+        let remaining = len.saturating_sub(self.idx);
+        (remaining, Some(remaining))
+    }
+}
+
+/// Returns an iterator over the quantities of a half-open range, adding
+/// `step` to `start` until `end` is reached.
+///
+/// This works for any quantity type, including ones backed by integers,
+/// for which it replaces the unstable [`Step`](core::iter::Step) trait that
+/// `for t in start..end` loops would need.
+///
+/// ```
+/// use tiny_uom::range::iter_steps;
+///
+/// /// A duration in integer milliseconds.
+/// #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+/// pub struct Quantity<const s: i8> {
+///     pub value: i32,
+/// }
+///
+/// tiny_uom::quantity_ops!(i32, Quantity, i8, s);
+///
+/// #[allow(non_upper_case_globals)]
+/// const ms: Quantity<1> = Quantity::new(1);
+///
+/// let ticks: Vec<_> = iter_steps(0 * ms..100 * ms, 40 * ms).collect();
+/// assert_eq!(ticks, [0 * ms, 40 * ms, 80 * ms]);
+/// ```
+///
+/// The step is only added while it stays below `end`, so ranges up to the
+/// largest value of an integer do not overflow:
+///
+/// ```
+/// # use tiny_uom::range::iter_steps;
+/// # #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+/// # pub struct Quantity<const s: i8> {
+/// #     pub value: i32,
+/// # }
+/// # tiny_uom::quantity_ops!(i32, Quantity, i8, s);
+/// let end = Quantity::<1>::new(i32::MAX);
+/// let last = iter_steps(Quantity::new(0)..end, Quantity::new(1 << 29)).last();
+/// assert_eq!(last, Some(Quantity::new(3 << 29)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the step is not positive.
+pub fn iter_steps<Q>(range: Range<Q>, step: Q) -> IterSteps<Q>
+where
+    Q: Copy + PartialOrd + Sub<Output = Q>,
+{
+    // `step - step` is the zero of `Q`, which has no bound for it
+    #[allow(clippy::eq_op)]
+    let zero = step - step;
+    assert!(step > zero, "the step must be positive");
+    IterSteps { range, step }
+}
+
+/// An iterator over the quantities of a range that adds a fixed step,
+/// returned by [`iter_steps`].
+#[derive(Clone, Debug)]
+pub struct IterSteps<Q> {
+    range: Range<Q>,
+    step: Q,
+}
+
+impl<Q> Iterator for IterSteps<Q>
+where
+    Q: Copy + PartialOrd + Add<Output = Q> + Sub<Output = Q>,
+{
+    type Item = Q;
+
+    fn next(&mut self) -> Option<Q> {
+        let value = self.range.start;
+        if value.partial_cmp(&self.range.end) != Some(Ordering::Less) {
+            return None;
+        }
+        // the distance to `end` is positive, so comparing it does not overflow like the sum
+        self.range.start = if self.range.end - value > self.step {
+            value + self.step
+        } else {
+            self.range.end
+        };
+        Some(value)
+    }
+}
@@ -0,0 +1,471 @@
+//! Quantities with rational exponents, like the noise density in V/√Hz.
+//!
+//! Every exponent of the [`Quantity`] of this module is a fraction. The first
+//! seven const parameters are the numerators in the order of the const
+//! parameters of [`Quantity`](crate::Quantity), the last seven are the
+//! denominators. Exponents must be reduced fractions with a positive
+//! denominator, so every dimension has exactly one type, like `s^-1/2` as
+//! `-1` and `2`. Operations that look at the exponents fail to compile for a
+//! quantity that is not reduced.
+//!
+//! The type of the result of [`Quantity::sqrt`], [`Quantity::mul_quantity`] and
+//! [`Quantity::div_quantity`] has to be written out, and a type with the wrong
+//! exponents fails to compile. Quantities with integer exponents convert from
+//! and into the [`Quantity`](crate::Quantity) with the same exponents.
+//!
+//! ```
+//! use tiny_uom::rational::Quantity;
+//!
+//! let volt = Quantity::<2, 1, -3, -1, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1>::new(1.0);
+//! let hertz = Quantity::<0, 0, -1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1>::new(100.0);
+//!
+//! let sqrt_hz: Quantity<0, 0, -1, 0, 0, 0, 0, 1, 1, 2, 1, 1, 1, 1> = hertz.sqrt();
+//! let density: Quantity<2, 1, -5, -1, 0, 0, 0, 1, 1, 2, 1, 1, 1, 1> =
+//!     (2e-6 * volt).div_quantity(sqrt_hz);
+//! assert_eq!(density.to_string(), "0.0000002 m^2*kg/(s^(5/2)*A)");
+//!
+//! let psd: Quantity<4, 2, -5, -2, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1> = density.mul_quantity(density);
+//! let psd: tiny_uom::Quantity<4, 2, -5, -2, 0, 0, 0> = psd.into();
+//! assert_eq!(psd.raw(), 0.0000002 * 0.0000002);
+//! ```
+//!
+//! ```compile_fail
+//! use tiny_uom::rational::Quantity;
+//!
+//! let hertz = Quantity::<0, 0, -1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1>::new(100.0);
+//! let wrong: Quantity<0, 0, -1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1> = hertz.sqrt();
+//! ```
+
+#![allow(non_upper_case_globals)]
+
+use crate::{Dimensioned, Si};
+use core::fmt;
+
+crate::quantity_impl!(
+    f32, Quantity, i8, m, kg, s, A, K, mol, cd, m_d, kg_d, s_d, A_d, K_d, mol_d, cd_d
+);
+
+/// The numerators followed by the denominators of the exponents.
+type Exponents = [i8; 14];
+
+/// Returns the greatest common divisor of two non-negative numbers.
+const fn gcd(mut a: i32, mut b: i32) -> i32 {
+    while b != 0 {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+/// Returns `true` if every exponent is a reduced fraction with a positive denominator.
+const fn is_reduced(exps: Exponents) -> bool {
+    let mut i = 0;
+    while i < 7 {
+        let (num, den) = (exps[i] as i32, exps[i + 7] as i32);
+        if den <= 0 || gcd(num.abs(), den) != 1 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if every exponent of `out` is equal to the exponent of `lhs`
+/// plus `factor` times the exponent of `rhs`.
+const fn is_sum(out: Exponents, lhs: Exponents, rhs: Exponents, factor: i32) -> bool {
+    let mut i = 0;
+    while i < 7 {
+        let (a, a_d) = (lhs[i] as i32, lhs[i + 7] as i32);
+        let (b, b_d) = (rhs[i] as i32, rhs[i + 7] as i32);
+        let (c, c_d) = (out[i] as i32, out[i + 7] as i32);
+        if c * a_d * b_d != (a * b_d + factor * b * a_d) * c_d {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if every exponent of `out` is half the exponent of `exps`.
+#[cfg(any(feature = "std", feature = "libm"))]
+const fn is_half(out: Exponents, exps: Exponents) -> bool {
+    let mut i = 0;
+    while i < 7 {
+        let (a, a_d) = (exps[i] as i32, exps[i + 7] as i32);
+        let (c, c_d) = (out[i] as i32, out[i + 7] as i32);
+        if 2 * c * a_d != a * c_d {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if the rational exponents are equal to the integer exponents.
+const fn is_integer(exps: Exponents, ints: [i8; 7]) -> bool {
+    let mut i = 0;
+    while i < 7 {
+        if exps[i + 7] != 1 || exps[i] != ints[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+        const m_d: i8,
+        const kg_d: i8,
+        const s_d: i8,
+        const A_d: i8,
+        const K_d: i8,
+        const mol_d: i8,
+        const cd_d: i8,
+    > crate::dimension::sealed::Sealed
+    for Quantity<m, kg, s, A, K, mol, cd, m_d, kg_d, s_d, A_d, K_d, mol_d, cd_d>
+{
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+        const m_d: i8,
+        const kg_d: i8,
+        const s_d: i8,
+        const A_d: i8,
+        const K_d: i8,
+        const mol_d: i8,
+        const cd_d: i8,
+    > Dimensioned for Quantity<m, kg, s, A, K, mol, cd, m_d, kg_d, s_d, A_d, K_d, mol_d, cd_d>
+{
+    type Scalar = f32;
+    type Exponents = Exponents;
+    type System = Si;
+
+    const EXPONENTS: Exponents = [
+        m, kg, s, A, K, mol, cd, m_d, kg_d, s_d, A_d, K_d, mol_d, cd_d,
+    ];
+
+    #[inline]
+    fn from_raw(value: f32) -> Self {
+        Quantity::new(value)
+    }
+
+    #[inline]
+    fn raw(self) -> f32 {
+        self.value
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+        const m_d: i8,
+        const kg_d: i8,
+        const s_d: i8,
+        const A_d: i8,
+        const K_d: i8,
+        const mol_d: i8,
+        const cd_d: i8,
+    > Quantity<m, kg, s, A, K, mol, cd, m_d, kg_d, s_d, A_d, K_d, mol_d, cd_d>
+{
+    /// Returns the square root of this quantity, halving every exponent.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn sqrt<O: Dimensioned<Scalar = f32, Exponents = [i8; 14], System = Si>>(self) -> O {
+        const {
+            assert!(
+                is_reduced(Self::EXPONENTS) && is_reduced(O::EXPONENTS),
+                "the exponents must be reduced fractions"
+            );
+            assert!(
+                is_half(O::EXPONENTS, Self::EXPONENTS),
+                "the exponents of the result must be half of the exponents"
+            );
+        }
+        O::from_raw(crate::math::imp::sqrt(self.value))
+    }
+
+    /// Multiply two quantities, adding their exponents.
+    #[must_use]
+    pub fn mul_quantity<
+        R: Dimensioned<Scalar = f32, Exponents = [i8; 14], System = Si>,
+        O: Dimensioned<Scalar = f32, Exponents = [i8; 14], System = Si>,
+    >(
+        self,
+        rhs: R,
+    ) -> O {
+        const {
+            assert!(
+                is_reduced(Self::EXPONENTS) && is_reduced(R::EXPONENTS) && is_reduced(O::EXPONENTS),
+                "the exponents must be reduced fractions"
+            );
+            assert!(
+                is_sum(O::EXPONENTS, Self::EXPONENTS, R::EXPONENTS, 1),
+                "the exponents of the result must be the sum of the exponents"
+            );
+        }
+        O::from_raw(self.value * rhs.raw())
+    }
+
+    /// Divide two quantities, subtracting their exponents.
+    #[must_use]
+    pub fn div_quantity<
+        R: Dimensioned<Scalar = f32, Exponents = [i8; 14], System = Si>,
+        O: Dimensioned<Scalar = f32, Exponents = [i8; 14], System = Si>,
+    >(
+        self,
+        rhs: R,
+    ) -> O {
+        const {
+            assert!(
+                is_reduced(Self::EXPONENTS) && is_reduced(R::EXPONENTS) && is_reduced(O::EXPONENTS),
+                "the exponents must be reduced fractions"
+            );
+            assert!(
+                is_sum(O::EXPONENTS, Self::EXPONENTS, R::EXPONENTS, -1),
+                "the exponents of the result must be the difference of the exponents"
+            );
+        }
+        O::from_raw(self.value / rhs.raw())
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+        const r_m: i8,
+        const r_kg: i8,
+        const r_s: i8,
+        const r_A: i8,
+        const r_K: i8,
+        const r_mol: i8,
+        const r_cd: i8,
+        const r_m_d: i8,
+        const r_kg_d: i8,
+        const r_s_d: i8,
+        const r_A_d: i8,
+        const r_K_d: i8,
+        const r_mol_d: i8,
+        const r_cd_d: i8,
+    > From<crate::Quantity<m, kg, s, A, K, mol, cd>>
+    for Quantity<
+        r_m,
+        r_kg,
+        r_s,
+        r_A,
+        r_K,
+        r_mol,
+        r_cd,
+        r_m_d,
+        r_kg_d,
+        r_s_d,
+        r_A_d,
+        r_K_d,
+        r_mol_d,
+        r_cd_d,
+    >
+{
+    fn from(quantity: crate::Quantity<m, kg, s, A, K, mol, cd>) -> Self {
+        const {
+            assert!(
+                is_integer(Self::EXPONENTS, [m, kg, s, A, K, mol, cd]),
+                "the quantities have different dimensions"
+            );
+        }
+        Quantity::new(quantity.value)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+        const r_m: i8,
+        const r_kg: i8,
+        const r_s: i8,
+        const r_A: i8,
+        const r_K: i8,
+        const r_mol: i8,
+        const r_cd: i8,
+        const r_m_d: i8,
+        const r_kg_d: i8,
+        const r_s_d: i8,
+        const r_A_d: i8,
+        const r_K_d: i8,
+        const r_mol_d: i8,
+        const r_cd_d: i8,
+    >
+    From<
+        Quantity<
+            r_m,
+            r_kg,
+            r_s,
+            r_A,
+            r_K,
+            r_mol,
+            r_cd,
+            r_m_d,
+            r_kg_d,
+            r_s_d,
+            r_A_d,
+            r_K_d,
+            r_mol_d,
+            r_cd_d,
+        >,
+    > for crate::Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn from(
+        quantity: Quantity<
+            r_m,
+            r_kg,
+            r_s,
+            r_A,
+            r_K,
+            r_mol,
+            r_cd,
+            r_m_d,
+            r_kg_d,
+            r_s_d,
+            r_A_d,
+            r_K_d,
+            r_mol_d,
+            r_cd_d,
+        >,
+    ) -> Self {
+        const {
+            assert!(
+                is_integer(
+                    Quantity::<
+                        r_m,
+                        r_kg,
+                        r_s,
+                        r_A,
+                        r_K,
+                        r_mol,
+                        r_cd,
+                        r_m_d,
+                        r_kg_d,
+                        r_s_d,
+                        r_A_d,
+                        r_K_d,
+                        r_mol_d,
+                        r_cd_d,
+                    >::EXPONENTS,
+                    [m, kg, s, A, K, mol, cd]
+                ),
+                "the quantities have different dimensions"
+            );
+        }
+        crate::Quantity::new(quantity.value)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+        const m_d: i8,
+        const kg_d: i8,
+        const s_d: i8,
+        const A_d: i8,
+        const K_d: i8,
+        const mol_d: i8,
+        const cd_d: i8,
+    > fmt::Display for Quantity<m, kg, s, A, K, mol, cd, m_d, kg_d, s_d, A_d, K_d, mol_d, cd_d>
+{
+    /// Formats the value followed by the symbols of the base units, like `1 m/s^(1/2)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_product(
+            f: &mut fmt::Formatter<'_>,
+            factors: impl Iterator<Item = (&'static str, i32, i32)>,
+        ) -> fmt::Result {
+            for (idx, (symbol, num, den)) in factors.enumerate() {
+                if idx > 0 {
+                    f.write_str("*")?;
+                }
+                f.write_str(symbol)?;
+                match (num, den) {
+                    (1, 1) => {}
+                    (num, 1) => write!(f, "^{num}")?,
+                    (num, den) => write!(f, "^({num}/{den})")?,
+                }
+            }
+            Ok(())
+        }
+
+        const {
+            assert!(
+                is_reduced(Self::EXPONENTS),
+                "the exponents must be reduced fractions"
+            );
+        }
+
+        fmt::Display::fmt(&self.value, f)?;
+        let exps = Self::EXPONENTS;
+        let factors = (0..7).map(|i| {
+            (
+                crate::SYMBOLS[i],
+                i32::from(exps[i]),
+                i32::from(exps[i + 7]),
+            )
+        });
+        let numerator = factors.clone().filter(|&(_, num, _)| num > 0);
+        let denominator = factors
+            .filter(|&(_, num, _)| num < 0)
+            .map(|(symbol, num, den)| (symbol, -num, den));
+
+        if numerator.clone().next().is_none() && denominator.clone().next().is_none() {
+            return Ok(());
+        }
+        f.write_str(" ")?;
+        if numerator.clone().next().is_some() {
+            write_product(f, numerator)?;
+        } else {
+            f.write_str("1")?;
+        }
+        match denominator.clone().count() {
+            0 => Ok(()),
+            1 => {
+                f.write_str("/")?;
+                write_product(f, denominator)
+            }
+            _ => {
+                f.write_str("/(")?;
+                write_product(f, denominator)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
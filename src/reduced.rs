@@ -0,0 +1,92 @@
+//! Quantities with fewer base units than [`Quantity`](crate::Quantity).
+
+/// Define a quantity with a subset of the SI base units in the current module.
+///
+/// `$full` is the type of [`Quantity`](crate::Quantity) with the same exponents,
+/// where every base unit that is left out is zero.
+macro_rules! reduced_quantity {
+    ([$($unit:ident),+], $full:ty) => {
+        crate::quantity_impl!(f32, Quantity, i8, $($unit),+);
+
+        impl<$(const $unit: i8,)*> Quantity<$($unit,)*> {
+            /// The [`Unit`](crate::Unit) of this `Quantity`.
+            pub const UNIT: crate::Unit = <$full>::UNIT;
+
+            /// Multiply two quantities, adding their exponents.
+            ///
+            /// Like the [`mul_quantity`](crate::Quantity::mul_quantity) of
+            /// [`Quantity`](crate::Quantity), the type of the result has to
+            /// be written out, and wrong or overflowing exponents fail to compile.
+            #[must_use]
+            #[inline]
+            pub fn mul_quantity<
+                R: crate::Dimensioned<Scalar = f32, Exponents = <Self as crate::Dimensioned>::Exponents, System = crate::Si>,
+                O: crate::Dimensioned<Scalar = f32, Exponents = <Self as crate::Dimensioned>::Exponents, System = crate::Si>,
+            >(
+                self,
+                rhs: R,
+            ) -> O {
+                let () = crate::ExponentCheck::<Self, R, O, { [$(stringify!($unit)),+].len() }>::PRODUCT;
+                O::from_raw(self.value * rhs.raw())
+            }
+
+            /// Divide two quantities, subtracting their exponents.
+            ///
+            /// Like for [`mul_quantity`](Self::mul_quantity), the type of the result
+            /// has to be written out, and exponents that overflow fail to compile.
+            #[must_use]
+            #[inline]
+            pub fn div_quantity<
+                R: crate::Dimensioned<Scalar = f32, Exponents = <Self as crate::Dimensioned>::Exponents, System = crate::Si>,
+                O: crate::Dimensioned<Scalar = f32, Exponents = <Self as crate::Dimensioned>::Exponents, System = crate::Si>,
+            >(
+                self,
+                rhs: R,
+            ) -> O {
+                let () = crate::ExponentCheck::<Self, R, O, { [$(stringify!($unit)),+].len() }>::QUOTIENT;
+                O::from_raw(self.value / rhs.raw())
+            }
+        }
+
+        impl<$(const $unit: i8,)*> crate::dimension::sealed::Sealed for Quantity<$($unit,)*> {}
+
+        impl<$(const $unit: i8,)*> crate::Dimensioned for Quantity<$($unit,)*> {
+            type Scalar = f32;
+            type Exponents = [i8; [$(stringify!($unit)),+].len()];
+            type System = crate::Si;
+
+            const EXPONENTS: Self::Exponents = [$($unit),+];
+
+            #[inline]
+            fn from_raw(value: f32) -> Self {
+                Quantity::new(value)
+            }
+
+            #[inline]
+            fn raw(self) -> f32 {
+                self.value
+            }
+        }
+
+        impl<$(const $unit: i8,)*> From<Quantity<$($unit,)*>> for $full {
+            fn from(quantity: Quantity<$($unit,)*>) -> Self {
+                Self::new(quantity.value)
+            }
+        }
+
+        impl<$(const $unit: i8,)*> From<$full> for Quantity<$($unit,)*> {
+            fn from(quantity: $full) -> Self {
+                Self::new(quantity.value)
+            }
+        }
+
+        impl<$(const $unit: i8,)*> ::core::fmt::Display for Quantity<$($unit,)*> {
+            /// Formats the quantity like the [`Quantity`](crate::Quantity) with the same exponents.
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&<$full>::from(*self), f)
+            }
+        }
+    };
+}
+
+pub(crate) use reduced_quantity;
@@ -0,0 +1,448 @@
+//! A runtime registry of units.
+//!
+//! The [`UnitRegistry`] knows the SI units and a set of common non-SI units,
+//! and can be extended by applications with their own units, either by calling
+//! [`UnitRegistry::register`] or by loading a definitions file.
+//!
+//! ```
+//! use tiny_uom::registry::{UnitDef, UnitRegistry};
+//! use tiny_uom::{Quantity, Unit};
+//!
+//! let mut registry = UnitRegistry::new();
+//! registry
+//!     .register(UnitDef {
+//!         symbol: "bbl".to_string(),
+//!         factor: 0.158_987_294_928,
+//!         dimension: Unit { m: 3, ..Unit::DIMENSIONLESS },
+//!         aliases: vec!["barrel".to_string()],
+//!         prefixes: false,
+//!     })
+//!     .unwrap();
+//!
+//! let litres = registry.convert(2.0, "barrel", "L").unwrap();
+//! assert!((litres - 317.974_589_856).abs() < 1e-9);
+//!
+//! let tank: Quantity<3, 0, 0, 0, 0, 0, 0> = registry.parse_quantity("2 bbl").unwrap();
+//! let barrels = registry.format_in(tank, "bbl").unwrap();
+//! assert_eq!(format!("{barrels:.1}"), "2.0 bbl");
+//! ```
+//!
+//! # Definitions files
+//!
+//! With the `toml` or `json` feature, units can be loaded from a file that
+//! contains a list of `unit` entries:
+//!
+//! ```toml
+//! [[unit]]
+//! symbol = "TEU"
+//! factor = 38.5
+//! dimension = { m = 3 }
+//! aliases = ["teu"]
+//! ```
+//!
+//! The `dimension` table lists the non-zero exponents of the SI base units,
+//! `aliases` and `prefixes` are optional.
+
+use crate::{
+    dynamic::DynQuantity,
+    error::{ConversionError, DimensionMismatch, ParseError},
+    parse, Dimensioned, Si, Unit, UnitInfo,
+};
+use std::{collections::HashMap, fmt, sync::OnceLock};
+
+/// The definition of a unit in a [`UnitRegistry`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitDef {
+    /// The symbol of this unit, like `km` or `bbl`.
+    pub symbol: String,
+    /// The factor a value in this unit is multiplied with to get its value in SI units.
+    pub factor: f64,
+    /// The SI unit of values in this unit.
+    pub dimension: Unit,
+    /// Other symbols or names that refer to this unit.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub aliases: Vec<String>,
+    /// Whether the symbol of this unit can be combined with SI prefixes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub prefixes: bool,
+}
+
+/// An error that occurred while using or extending a [`UnitRegistry`].
+#[derive(Debug)]
+pub enum RegistryError {
+    /// A symbol or alias is already registered.
+    Duplicate(String),
+    /// The factor of a unit is zero or not finite.
+    InvalidFactor(String),
+    /// A TOML definitions file could not be parsed.
+    #[cfg(feature = "toml")]
+    Toml(toml::de::Error),
+    /// A JSON definitions file could not be parsed.
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::Duplicate(symbol) => write!(f, "unit `{symbol}` is already defined"),
+            RegistryError::InvalidFactor(symbol) => {
+                write!(f, "unit `{symbol}` has an invalid factor")
+            }
+            #[cfg(feature = "toml")]
+            RegistryError::Toml(err) => write!(f, "invalid unit definitions: {err}"),
+            #[cfg(feature = "json")]
+            RegistryError::Json(err) => write!(f, "invalid unit definitions: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "toml")]
+            RegistryError::Toml(err) => Some(err),
+            #[cfg(feature = "json")]
+            RegistryError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// The contents of a definitions file.
+#[cfg(any(feature = "toml", feature = "json"))]
+#[derive(serde::Deserialize)]
+struct Definitions {
+    #[serde(default)]
+    unit: Vec<UnitDef>,
+}
+
+#[rustfmt::skip]
+pub(crate) static PREFIXES: &[(&str, f64)] = &[
+    ("da", 1e1),
+    ("Q", 1e30), ("R", 1e27), ("Y", 1e24), ("Z", 1e21), ("E", 1e18), ("P", 1e15),
+    ("T", 1e12), ("G", 1e9), ("M", 1e6), ("k", 1e3), ("h", 1e2), ("d", 1e-1),
+    ("c", 1e-2), ("m", 1e-3),
+    // micro as the micro sign, the Greek letter mu and the ASCII replacement
+    ("µ", 1e-6), ("μ", 1e-6), ("u", 1e-6),
+    ("n", 1e-9), ("p", 1e-12), ("f", 1e-15), ("a", 1e-18), ("z", 1e-21), ("y", 1e-24),
+    ("r", 1e-27), ("q", 1e-30),
+];
+
+const ONE: [i8; 7] = [0, 0, 0, 0, 0, 0, 0];
+const LENGTH: [i8; 7] = [1, 0, 0, 0, 0, 0, 0];
+const MASS: [i8; 7] = [0, 1, 0, 0, 0, 0, 0];
+const TIME: [i8; 7] = [0, 0, 1, 0, 0, 0, 0];
+const VOLUME: [i8; 7] = [3, 0, 0, 0, 0, 0, 0];
+const VELOCITY: [i8; 7] = [1, 0, -1, 0, 0, 0, 0];
+const PRESSURE: [i8; 7] = [-1, 1, -2, 0, 0, 0, 0];
+const ENERGY: [i8; 7] = [2, 1, -2, 0, 0, 0, 0];
+
+/// A built-in unit as `(symbol, factor, dimension, prefixes, aliases)`.
+type Builtin = (&'static str, f64, [i8; 7], bool, &'static [&'static str]);
+
+#[rustfmt::skip]
+pub(crate) static BUILTIN: &[Builtin] = &[
+    // the named SI units are taken from `UnitInfo`, the kilogram is prefixed through the gram
+    ("g", 1e-3, MASS, true, &["gram"]),
+    ("rad", 1.0, ONE, true, &["radian"]),
+    ("sr", 1.0, ONE, true, &["steradian"]),
+    // non-SI units accepted for use with the SI
+    ("min", 60.0, TIME, false, &["minute"]),
+    ("h", 3_600.0, TIME, false, &["hour"]),
+    ("d", 86_400.0, TIME, false, &["day"]),
+    ("L", 1e-3, VOLUME, true, &["l", "litre", "liter"]),
+    ("t", 1e3, MASS, true, &["tonne"]),
+    ("bar", 1e5, PRESSURE, true, &[]),
+    ("eV", 1.602_176_634e-19, ENERGY, true, &["electronvolt"]),
+    ("°", std::f64::consts::PI / 180.0, ONE, false, &["deg", "degree"]),
+    ("%", 1e-2, ONE, false, &["percent"]),
+    // imperial and US customary units
+    ("in", 0.0254, LENGTH, false, &["inch"]),
+    ("ft", 0.3048, LENGTH, false, &["foot", "feet"]),
+    ("yd", 0.9144, LENGTH, false, &["yard"]),
+    ("mi", 1_609.344, LENGTH, false, &["mile"]),
+    ("nmi", 1_852.0, LENGTH, false, &["NM"]),
+    ("lb", 0.453_592_37, MASS, false, &["pound"]),
+    ("oz", 0.028_349_523_125, MASS, false, &["ounce"]),
+    ("gal", 3.785_411_784e-3, VOLUME, false, &["gallon"]),
+    ("mph", 0.447_04, VELOCITY, false, &[]),
+    ("kn", 1_852.0 / 3_600.0, VELOCITY, false, &["knot"]),
+    ("psi", 6_894.757_293_168, PRESSURE, false, &[]),
+    ("atm", 101_325.0, PRESSURE, false, &[]),
+    ("cal", 4.184, ENERGY, true, &["calorie"]),
+];
+
+/// A set of units that can be looked up by their symbol or aliases at runtime.
+#[derive(Clone, Debug)]
+pub struct UnitRegistry {
+    units: Vec<UnitDef>,
+    symbols: HashMap<String, usize>,
+}
+
+impl UnitRegistry {
+    /// Create a registry containing the named SI units of [`UnitInfo`]
+    /// and common non-SI units.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for info in UnitInfo::all() {
+            registry.insert(UnitDef {
+                symbol: info.symbol.to_string(),
+                factor: 1.0,
+                dimension: info.unit,
+                aliases: vec![info.name.to_string()],
+                prefixes: info.symbol != "kg",
+            });
+        }
+        for &(symbol, factor, dimension, prefixes, aliases) in BUILTIN {
+            registry.insert(UnitDef {
+                symbol: symbol.to_string(),
+                factor,
+                dimension: Unit::from_exponents(dimension),
+                aliases: aliases.iter().map(|alias| (*alias).to_string()).collect(),
+                prefixes,
+            });
+        }
+        registry
+    }
+
+    /// Returns a shared registry containing the built-in units.
+    ///
+    /// This is the registry used by the [`FromStr`](std::str::FromStr)
+    /// implementation of [`Quantity`](crate::Quantity).
+    pub fn builtin() -> &'static UnitRegistry {
+        static REGISTRY: OnceLock<UnitRegistry> = OnceLock::new();
+        REGISTRY.get_or_init(UnitRegistry::new)
+    }
+
+    /// Create a registry without any units.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            units: Vec::new(),
+            symbols: HashMap::new(),
+        }
+    }
+
+    /// Add a new unit to this registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the symbol or one of the aliases is already registered,
+    /// or if the factor is zero or not finite.
+    pub fn register(&mut self, def: UnitDef) -> Result<(), RegistryError> {
+        if !def.factor.is_normal() {
+            return Err(RegistryError::InvalidFactor(def.symbol));
+        }
+
+        let names = std::iter::once(&def.symbol).chain(&def.aliases);
+        for (idx, name) in names.clone().enumerate() {
+            let repeated = names.clone().take(idx).any(|other| other == name);
+            if repeated || self.symbols.contains_key(name) {
+                return Err(RegistryError::Duplicate(name.clone()));
+            }
+        }
+
+        self.insert(def);
+        Ok(())
+    }
+
+    fn insert(&mut self, def: UnitDef) {
+        let idx = self.units.len();
+        for name in std::iter::once(&def.symbol).chain(&def.aliases) {
+            self.symbols.insert(name.clone(), idx);
+        }
+        self.units.push(def);
+    }
+
+    /// Look up a unit by its symbol or one of its aliases.
+    #[must_use]
+    pub fn get(&self, symbol: &str) -> Option<&UnitDef> {
+        self.symbols.get(symbol).map(|&idx| &self.units[idx])
+    }
+
+    /// Look up a symbol that may have an SI prefix, returning the factor
+    /// of the prefix and the definition of the unit.
+    ///
+    /// The micro prefix may be written as the micro sign `µ`, the Greek
+    /// letter `μ` or `u`.
+    ///
+    /// ```
+    /// use tiny_uom::registry::UnitRegistry;
+    ///
+    /// let registry = UnitRegistry::new();
+    /// let (factor, tonne) = registry.lookup("kt").unwrap();
+    /// assert_eq!((factor, tonne.symbol.as_str()), (1e3, "t"));
+    /// assert_eq!(registry.convert(1.0, "kt", "t").unwrap(), 1e3);
+    /// for micrometre in ["\u{b5}m", "\u{3bc}m", "um"] {
+    ///     assert_eq!(registry.lookup(micrometre).unwrap().0, 1e-6);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn lookup(&self, symbol: &str) -> Option<(f64, &UnitDef)> {
+        if let Some(def) = self.get(symbol) {
+            return Some((1.0, def));
+        }
+
+        PREFIXES.iter().find_map(|&(prefix, factor)| {
+            let def = self.get(symbol.strip_prefix(prefix)?)?;
+            def.prefixes.then_some((factor, def))
+        })
+    }
+
+    /// Parse a unit expression like `km/h` or `kg*m^2/s^2` into the factor
+    /// that converts values into SI units and the resulting [`Unit`].
+    ///
+    /// Units are combined using `*` (or `·`), `/`, integer powers with `^`
+    /// and parentheses, which can be nested up to 32 levels deep.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression is malformed or contains an unknown unit.
+    pub fn parse_unit(&self, expr: &str) -> Result<(f64, Unit), ParseError> {
+        parse::parse_unit(self, expr)
+    }
+
+    /// Parse a value followed by a unit expression, separated by whitespace,
+    /// into a quantity of type `Q`.
+    ///
+    /// This is the [`FromStr`](std::str::FromStr) implementation of
+    /// [`Quantity`](crate::Quantity), using the units of this registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value or the unit expression can not be parsed,
+    /// or if the unit has a different dimension than `Q`.
+    pub fn parse_quantity<Q: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>>(
+        &self,
+        src: &str,
+    ) -> Result<Q, ParseError> {
+        parse::parse_quantity(self, src)
+    }
+
+    /// Express a quantity in a unit expression of this registry, for formatting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression can not be parsed
+    /// or if it has a different dimension than the quantity.
+    pub fn format_in<'a>(
+        &self,
+        quantity: impl Into<DynQuantity>,
+        unit: &'a str,
+    ) -> Result<InUnit<'a>, ConversionError> {
+        let quantity = quantity.into();
+        let (factor, dim) = self.parse_unit(unit)?;
+        if dim != quantity.dim {
+            return Err(DimensionMismatch {
+                expected: dim,
+                found: quantity.dim,
+            }
+            .into());
+        }
+
+        Ok(InUnit {
+            value: quantity.value / factor,
+            unit,
+        })
+    }
+
+    /// Returns an iterator over all units of this registry.
+    pub fn iter(&self) -> impl Iterator<Item = &UnitDef> {
+        self.units.iter()
+    }
+
+    /// Convert a value from one unit expression into another.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the expressions can not be parsed
+    /// or if the units have different dimensions.
+    pub fn convert(&self, value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+        let (from_factor, from) = self.parse_unit(from)?;
+        let (to_factor, to) = self.parse_unit(to)?;
+
+        if from != to {
+            return Err(DimensionMismatch {
+                expected: to,
+                found: from,
+            }
+            .into());
+        }
+
+        Ok(value * from_factor / to_factor)
+    }
+
+    /// Register all units of a TOML definitions file.
+    ///
+    /// Units that were registered before an error occurred stay registered.
+    ///
+    /// ```
+    /// # use tiny_uom::registry::UnitRegistry;
+    /// let mut registry = UnitRegistry::new();
+    /// registry
+    ///     .load_toml(
+    ///         r#"
+    ///         [[unit]]
+    ///         symbol = "TEU"
+    ///         factor = 38.5
+    ///         dimension = { m = 3 }
+    ///         "#,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(registry.convert(1.0, "TEU", "L").unwrap(), 38_500.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file is malformed or a unit can not be registered.
+    #[cfg(feature = "toml")]
+    pub fn load_toml(&mut self, src: &str) -> Result<(), RegistryError> {
+        let defs: Definitions = toml::from_str(src).map_err(RegistryError::Toml)?;
+        self.extend_defs(defs)
+    }
+
+    /// Register all units of a JSON definitions file.
+    ///
+    /// Units that were registered before an error occurred stay registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file is malformed or a unit can not be registered.
+    #[cfg(feature = "json")]
+    pub fn load_json(&mut self, src: &str) -> Result<(), RegistryError> {
+        let defs: Definitions = serde_json::from_str(src).map_err(RegistryError::Json)?;
+        self.extend_defs(defs)
+    }
+
+    #[cfg(any(feature = "toml", feature = "json"))]
+    fn extend_defs(&mut self, defs: Definitions) -> Result<(), RegistryError> {
+        defs.unit.into_iter().try_for_each(|def| self.register(def))
+    }
+}
+
+/// A value expressed in a unit expression, returned by [`UnitRegistry::format_in`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InUnit<'a> {
+    /// The value in the unit.
+    pub value: f64,
+    /// The unit expression.
+    pub unit: &'a str,
+}
+
+impl fmt::Display for InUnit<'_> {
+    /// Formats the value followed by the unit expression, like `2 bbl`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, " {}", self.unit)
+    }
+}
+
+impl Default for UnitRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
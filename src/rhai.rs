@@ -0,0 +1,136 @@
+//! Integration with the [Rhai] scripting language.
+//!
+//! [`register`] adds a `Quantity` type to an [`Engine`] whose units are
+//! checked while the script is evaluated, so users can write small formulas
+//! without being able to add volts to amperes. Scripts return a
+//! [`DynQuantity`], which converts into a quantity type of the same dimension.
+//!
+//! ```
+//! use tiny_uom::dynamic::DynQuantity;
+//! use tiny_uom::rhai::{push_constants, register};
+//! use tiny_uom::Quantity;
+//!
+//! let mut engine = rhai::Engine::new();
+//! register(&mut engine);
+//!
+//! let mut scope = rhai::Scope::new();
+//! push_constants(&mut scope);
+//! scope.push("voltage", DynQuantity::from(Quantity::<2, 1, -3, -1, 0, 0, 0>::new(230.0)));
+//!
+//! let power: DynQuantity = engine
+//!     .eval_with_scope(&mut scope, "voltage * 2.0 * A")
+//!     .unwrap();
+//! let power = Quantity::<2, 1, -3, 0, 0, 0, 0>::try_from(power).unwrap();
+//! assert_eq!(power.raw(), 460.0);
+//!
+//! assert!(engine.eval_with_scope::<DynQuantity>(&mut scope, "voltage + A").is_err());
+//! ```
+//!
+//! [Rhai]: https://rhai.rs
+
+use crate::{dynamic::DynQuantity, error::DimensionMismatch, registry::UnitRegistry, UnitInfo};
+use rhai::{Engine, EvalAltResult, Module, Scope, FLOAT, INT};
+
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// Returns an error of the script if `rhs` has a different dimension than `lhs`.
+fn same_unit(lhs: DynQuantity, rhs: DynQuantity) -> ScriptResult<()> {
+    if lhs.dim == rhs.dim {
+        Ok(())
+    } else {
+        let err = DimensionMismatch {
+            expected: lhs.dim,
+            found: rhs.dim,
+        };
+        Err(err.to_string().into())
+    }
+}
+
+/// Returns the named SI units whose symbols are valid Rhai identifiers.
+fn constants() -> impl Iterator<Item = (&'static str, DynQuantity)> {
+    UnitInfo::all()
+        .iter()
+        .filter(|info| info.symbol.chars().all(|c| c.is_ascii_alphabetic()))
+        .map(|info| (info.symbol, DynQuantity::new(1.0, info.unit)))
+}
+
+/// Register the `Quantity` type, its operators, the `unit` function
+/// and the `units` module containing the named SI units with the engine.
+///
+/// The `unit` function parses a unit expression like `unit("km/h")`
+/// using the built-in [`UnitRegistry`].
+#[allow(clippy::cast_precision_loss)]
+pub fn register(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<DynQuantity>("Quantity")
+        .register_get("value", |q: &mut DynQuantity| q.value)
+        .register_get("unit", |q: &mut DynQuantity| q.dim.to_string())
+        .register_fn("to_string", |q: &mut DynQuantity| q.to_string())
+        .register_fn("to_debug", |q: &mut DynQuantity| format!("{q:?}"))
+        .register_fn("abs", |q: DynQuantity| {
+            DynQuantity::new(q.value.abs(), q.dim)
+        })
+        .register_fn("unit", |expr: &str| -> ScriptResult<DynQuantity> {
+            let (value, unit) = UnitRegistry::builtin()
+                .parse_unit(expr)
+                .map_err(|err| err.to_string())?;
+            Ok(DynQuantity::new(value, unit))
+        });
+
+    engine
+        .register_fn("+", |a: DynQuantity, b: DynQuantity| -> ScriptResult<_> {
+            a.checked_add(b).map_err(|err| err.to_string().into())
+        })
+        .register_fn("-", |a: DynQuantity, b: DynQuantity| -> ScriptResult<_> {
+            a.checked_sub(b).map_err(|err| err.to_string().into())
+        })
+        .register_fn("-", |a: DynQuantity| -a)
+        .register_fn("*", |a: DynQuantity, b: DynQuantity| -> ScriptResult<_> {
+            a.checked_mul(b).map_err(|err| err.to_string().into())
+        })
+        .register_fn("/", |a: DynQuantity, b: DynQuantity| -> ScriptResult<_> {
+            a.checked_div(b).map_err(|err| err.to_string().into())
+        })
+        .register_fn("*", |a: DynQuantity, b: FLOAT| a * b)
+        .register_fn("*", |a: FLOAT, b: DynQuantity| a * b)
+        .register_fn("*", |a: DynQuantity, b: INT| a * b as FLOAT)
+        .register_fn("*", |a: INT, b: DynQuantity| a as FLOAT * b)
+        .register_fn("/", |a: DynQuantity, b: FLOAT| a / b)
+        .register_fn("/", |a: DynQuantity, b: INT| a / b as FLOAT)
+        .register_fn("/", |a: FLOAT, b: DynQuantity| -> ScriptResult<_> {
+            DynQuantity::dimensionless(a)
+                .checked_div(b)
+                .map_err(|err| err.to_string().into())
+        });
+
+    macro_rules! register_cmp {
+        ($($op:literal => $cmp:expr),*) => {$(
+            engine.register_fn($op, |a: DynQuantity, b: DynQuantity| -> ScriptResult<bool> {
+                same_unit(a, b)?;
+                Ok($cmp(&a.value, &b.value))
+            });
+        )*};
+    }
+    register_cmp! {
+        "==" => f64::eq,
+        "!=" => f64::ne,
+        "<" => f64::lt,
+        "<=" => f64::le,
+        ">" => f64::gt,
+        ">=" => f64::ge
+    }
+
+    let mut units = Module::new();
+    for (symbol, unit) in constants() {
+        units.set_var(symbol, unit);
+    }
+    engine.register_static_module("units", units.into());
+}
+
+/// Push the named SI units as constants into the scope,
+/// so scripts can use `m` instead of `units::m`.
+pub fn push_constants(scope: &mut Scope<'_>) {
+    for (symbol, unit) in constants() {
+        scope.push_constant(symbol, unit);
+    }
+}
@@ -0,0 +1,196 @@
+//! Quantities stored in a scaled unit, like millimetres instead of metres.
+//!
+//! A [`Scaled`] quantity keeps its value in the unit given by a [`Ratio`] of
+//! the SI unit, so code that must store exact millimetres does not normalize
+//! them into metres. The scale is part of the type, so millimetres and metres
+//! can not be mixed up, and converting between scales is explicit.
+//!
+//! ```
+//! use tiny_uom::scale::{Micro, Milli, Scaled};
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! type Length = Quantity<1, 0, 0, 0, 0, 0, 0>;
+//!
+//! let depth = Scaled::<Length, Milli>::new(12.5);
+//! let total = depth + Scaled::new(0.5);
+//! assert_eq!(total.value, 13.0);
+//!
+//! assert_eq!(total.into_scale::<Micro>().value, 13_000.0);
+//! assert_eq!(total.to_quantity(), 0.013 * m);
+//! assert_eq!(Scaled::<Length, Milli>::from_quantity(2.0 * m).value, 2_000.0);
+//! ```
+//!
+//! ```compile_fail
+//! use tiny_uom::scale::{Milli, Scaled, Unscaled};
+//! use tiny_uom::Quantity;
+//!
+//! type Length = Quantity<1, 0, 0, 0, 0, 0, 0>;
+//!
+//! let sum = Scaled::<Length, Milli>::new(1.0) + Scaled::<Length, Unscaled>::new(1.0);
+//! ```
+
+use crate::Dimensioned;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// The scale `NUM / DEN` of the SI unit, like `Ratio<1, 1000>` for a milli unit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ratio<const NUM: u64, const DEN: u64>;
+
+/// Implemented by the scales of a [`Scaled`] quantity.
+pub trait Scale: Copy {
+    /// The numerator of the scale.
+    const NUM: u64;
+    /// The denominator of the scale.
+    const DEN: u64;
+}
+
+impl<const NUM: u64, const DEN: u64> Scale for Ratio<NUM, DEN> {
+    const NUM: u64 = NUM;
+    const DEN: u64 = DEN;
+}
+
+/// The SI unit itself.
+pub type Unscaled = Ratio<1, 1>;
+/// A scale of 10⁻⁹.
+pub type Nano = Ratio<1, 1_000_000_000>;
+/// A scale of 10⁻⁶.
+pub type Micro = Ratio<1, 1_000_000>;
+/// A scale of 10⁻³.
+pub type Milli = Ratio<1, 1_000>;
+/// A scale of 10⁻².
+pub type Centi = Ratio<1, 100>;
+/// A scale of 10³.
+pub type Kilo = Ratio<1_000, 1>;
+/// A scale of 10⁶.
+pub type Mega = Ratio<1_000_000, 1>;
+
+/// Returns the factor that converts values in scale `F` into values in scale `T`.
+#[allow(clippy::cast_precision_loss)]
+fn factor<F: Scale, T: Scale>() -> f64 {
+    (F::NUM as f64 * T::DEN as f64) / (F::DEN as f64 * T::NUM as f64)
+}
+
+/// A quantity of type `Q` whose value is stored in the scale `S` of its SI unit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Scaled<Q, S = Unscaled> {
+    /// The raw value in the scaled unit.
+    pub value: f32,
+    marker: PhantomData<(Q, S)>,
+}
+
+impl<Q: Dimensioned<Scalar = f32>, S: Scale> Scaled<Q, S> {
+    /// Create a scaled quantity from its value in the scaled unit.
+    #[must_use]
+    #[inline]
+    pub const fn new(value: f32) -> Self {
+        Self {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    /// Create a scaled quantity from a quantity in SI units.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_quantity(quantity: Q) -> Self {
+        Self::new((f64::from(quantity.raw()) * factor::<Unscaled, S>()) as f32)
+    }
+
+    /// Convert this quantity into SI units.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_quantity(self) -> Q {
+        Q::from_raw((f64::from(self.value) * factor::<S, Unscaled>()) as f32)
+    }
+
+    /// Convert the value into the scale `T`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn into_scale<T: Scale>(self) -> Scaled<Q, T> {
+        Scaled::new((f64::from(self.value) * factor::<S, T>()) as f32)
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>, S: Scale> Add for Scaled<Q, S> {
+    type Output = Self;
+
+    /// Add the values of two quantities in the same scale.
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.value + rhs.value)
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>, S: Scale> AddAssign for Scaled<Q, S> {
+    /// Add the values of two quantities in the same scale.
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>, S: Scale> Sub for Scaled<Q, S> {
+    type Output = Self;
+
+    /// Subtract the values of two quantities in the same scale.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.value - rhs.value)
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>, S: Scale> SubAssign for Scaled<Q, S> {
+    /// Subtract the values of two quantities in the same scale.
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>, S: Scale> Mul<f32> for Scaled<Q, S> {
+    type Output = Self;
+
+    /// Multiply the value with a number.
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::new(self.value * rhs)
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>, S: Scale> MulAssign<f32> for Scaled<Q, S> {
+    /// Multiply the value with a number.
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        self.value *= rhs;
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>, S: Scale> Div<f32> for Scaled<Q, S> {
+    type Output = Self;
+
+    /// Divide the value by a number.
+    #[inline]
+    fn div(self, rhs: f32) -> Self::Output {
+        Self::new(self.value / rhs)
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>, S: Scale> DivAssign<f32> for Scaled<Q, S> {
+    /// Divide the value by a number.
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        self.value /= rhs;
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>, S: Scale> Neg for Scaled<Q, S> {
+    type Output = Self;
+
+    /// Negate the value.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value)
+    }
+}
@@ -0,0 +1,46 @@
+//! [`schemars`](https://docs.rs/schemars) support for quantities.
+
+use crate::Quantity;
+use alloc::{borrow::Cow, format, string::ToString};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > JsonSchema for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Quantity".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("tiny_uom::Quantity<{m}, {kg}, {s}, {A}, {K}, {mol}, {cd}>").into()
+    }
+
+    /// A number schema with the unit of the quantity in the `x-unit` keyword,
+    /// matching the raw value representation of the `serde` feature.
+    ///
+    /// ```
+    /// let schema = schemars::schema_for!(tiny_uom::Quantity<1, 0, -1, 0, 0, 0, 0>);
+    /// assert_eq!(schema.get("type").unwrap(), "number");
+    /// assert_eq!(schema.get("x-unit").unwrap(), "m/s");
+    /// ```
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let unit = Self::UNIT.to_string();
+        json_schema!({
+            "type": "number",
+            "format": "float",
+            "description": format!("A quantity in `{unit}`"),
+            "x-unit": unit,
+        })
+    }
+}
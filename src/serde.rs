@@ -0,0 +1,362 @@
+//! [`serde`] support for quantities.
+//!
+//! By default a [`Quantity`] is (de)serialized as its raw value in SI units,
+//! exactly like the `f32` it wraps. This way fields can be changed from `f32`
+//! to a `Quantity` without changing the serialized schema.
+//!
+//! ```
+//! use tiny_uom::Quantity;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Sample {
+//!     distance: Quantity<1, 0, 0, 0, 0, 0, 0>,
+//! }
+//!
+//! let json = r#"{"distance":12.5}"#;
+//! let sample: Sample = serde_json::from_str(json).unwrap();
+//! assert_eq!(sample.distance.raw(), 12.5);
+//! assert_eq!(serde_json::to_string(&sample).unwrap(), json);
+//! ```
+//!
+//! [`serde`]: https://serde.rs
+
+use crate::Quantity;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Serialize for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<
+        'de,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Deserialize<'de> for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f32::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// (De)serialize a [`Quantity`] together with its unit in human-readable formats.
+///
+/// Human-readable formats like JSON or TOML use the [`Display`](core::fmt::Display)
+/// output, like `"9.81 m/s^2"`, and deserializing checks that the unit has
+/// the dimension of the field. Values may be written in any unit known to
+/// the built-in [`UnitRegistry`](crate::registry::UnitRegistry) and are
+/// converted into SI units. Binary formats keep using the raw value.
+///
+/// ```
+/// use tiny_uom::Quantity;
+///
+/// #[derive(Debug, serde::Serialize, serde::Deserialize)]
+/// struct Reading {
+///     #[serde(with = "tiny_uom::serde::with_unit")]
+///     acceleration: Quantity<1, 0, -2, 0, 0, 0, 0>,
+/// }
+///
+/// let json = r#"{"acceleration":"9.81 m/s^2"}"#;
+/// let reading: Reading = serde_json::from_str(json).unwrap();
+/// assert_eq!(reading.acceleration.raw(), 9.81);
+/// assert_eq!(serde_json::to_string(&reading).unwrap(), json);
+///
+/// assert!(serde_json::from_str::<Reading>(r#"{"acceleration":"9.81 kg"}"#).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub mod with_unit {
+    use crate::Quantity;
+    use ::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serialize a quantity as a string with its unit in human-readable formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of the serializer.
+    pub fn serialize<
+        S: Serializer,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    >(
+        quantity: &Quantity<m, kg, s, A, K, mol, cd>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(quantity)
+        } else {
+            quantity.value.serialize(serializer)
+        }
+    }
+
+    /// Deserialize a quantity from a string with its unit in human-readable formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string can not be parsed or has the wrong dimension.
+    pub fn deserialize<
+        'de,
+        D: Deserializer<'de>,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    >(
+        deserializer: D,
+    ) -> Result<Quantity<m, kg, s, A, K, mol, cd>, D::Error> {
+        if deserializer.is_human_readable() {
+            String::deserialize(deserializer)?
+                .parse()
+                .map_err(D::Error::custom)
+        } else {
+            f32::deserialize(deserializer).map(Quantity::new)
+        }
+    }
+}
+
+/// [`serde_with`] adapters to choose the representation of each field.
+///
+/// - [`Raw`](adapters::Raw) uses the raw value in SI units.
+/// - [`WithUnit`](adapters::WithUnit) uses the representation of [`with_unit`].
+/// - [`InUnit`](adapters::InUnit) uses a bare number in the given [`DisplayUnit`](adapters::DisplayUnit).
+///
+/// ```
+/// use serde_with::{serde_as, As};
+/// use tiny_uom::serde::adapters::{units::km, InUnit, Raw, WithUnit};
+/// use tiny_uom::Quantity;
+///
+/// type Length = Quantity<1, 0, 0, 0, 0, 0, 0>;
+///
+/// #[serde_as]
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Trip {
+///     #[serde_as(as = "Raw")]
+///     raw: Length,
+///     #[serde_as(as = "WithUnit")]
+///     tagged: Length,
+///     #[serde_as(as = "InUnit<km>")]
+///     distance: Length,
+/// }
+///
+/// let trip = Trip {
+///     raw: Length::new(1500.0),
+///     tagged: Length::new(1500.0),
+///     distance: Length::new(1500.0),
+/// };
+/// let json = serde_json::to_string(&trip).unwrap();
+/// assert_eq!(json, r#"{"raw":1500.0,"tagged":"1500 m","distance":1.5}"#);
+///
+/// let back: Trip = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back.distance.raw(), 1500.0);
+/// ```
+///
+/// [`serde_with`]: https://docs.rs/serde_with
+#[cfg(feature = "serde_with")]
+pub mod adapters {
+    use crate::{error::DimensionMismatch, registry::UnitRegistry, Quantity, Unit};
+    use ::serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs};
+    use std::marker::PhantomData;
+
+    /// Uses the raw value of a quantity in SI units.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Raw;
+
+    /// Uses a string with the unit in human-readable formats, see [`with_unit`](super::with_unit).
+    #[derive(Clone, Copy, Debug)]
+    pub struct WithUnit;
+
+    /// Uses a bare number in the unit `U`.
+    #[derive(Clone, Copy, Debug)]
+    pub struct InUnit<U>(PhantomData<U>);
+
+    /// A unit in which [`InUnit`] (de)serializes values.
+    pub trait DisplayUnit {
+        /// The unit expression, which must be known to the built-in [`UnitRegistry`].
+        const UNIT: &'static str;
+    }
+
+    /// Markers for commonly used units.
+    #[allow(non_camel_case_types)]
+    pub mod units {
+        macro_rules! display_units {
+            ($($name:ident => $unit:literal),* $(,)?) => {$(
+                #[doc = concat!("The unit `", $unit, "`.")]
+                #[derive(Clone, Copy, Debug)]
+                pub struct $name;
+
+                impl super::DisplayUnit for $name {
+                    const UNIT: &'static str = $unit;
+                }
+            )*};
+        }
+
+        display_units! {
+            km => "km", cm => "cm", mm => "mm", um => "µm", nm => "nm",
+            inch => "in", ft => "ft", mi => "mi",
+            g => "g", mg => "mg", t => "t", lb => "lb",
+            ms => "ms", us => "µs", ns => "ns", min => "min", h => "h", d => "d",
+            km_per_h => "km/h", mph => "mph",
+            kPa => "kPa", hPa => "hPa", MPa => "MPa", bar => "bar", mbar => "mbar",
+            mA => "mA", mV => "mV", kV => "kV",
+            kW => "kW", MW => "MW", kJ => "kJ", kWh => "kW*h",
+            deg => "°", percent => "%",
+        }
+    }
+
+    impl<
+            const m: i8,
+            const kg: i8,
+            const s: i8,
+            const A: i8,
+            const K: i8,
+            const mol: i8,
+            const cd: i8,
+        > SerializeAs<Quantity<m, kg, s, A, K, mol, cd>> for Raw
+    {
+        fn serialize_as<S: Serializer>(
+            source: &Quantity<m, kg, s, A, K, mol, cd>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            source.value.serialize(serializer)
+        }
+    }
+
+    impl<
+            'de,
+            const m: i8,
+            const kg: i8,
+            const s: i8,
+            const A: i8,
+            const K: i8,
+            const mol: i8,
+            const cd: i8,
+        > DeserializeAs<'de, Quantity<m, kg, s, A, K, mol, cd>> for Raw
+    {
+        fn deserialize_as<D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Quantity<m, kg, s, A, K, mol, cd>, D::Error> {
+            f32::deserialize(deserializer).map(Quantity::new)
+        }
+    }
+
+    impl<
+            const m: i8,
+            const kg: i8,
+            const s: i8,
+            const A: i8,
+            const K: i8,
+            const mol: i8,
+            const cd: i8,
+        > SerializeAs<Quantity<m, kg, s, A, K, mol, cd>> for WithUnit
+    {
+        fn serialize_as<S: Serializer>(
+            source: &Quantity<m, kg, s, A, K, mol, cd>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            super::with_unit::serialize(source, serializer)
+        }
+    }
+
+    impl<
+            'de,
+            const m: i8,
+            const kg: i8,
+            const s: i8,
+            const A: i8,
+            const K: i8,
+            const mol: i8,
+            const cd: i8,
+        > DeserializeAs<'de, Quantity<m, kg, s, A, K, mol, cd>> for WithUnit
+    {
+        fn deserialize_as<D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Quantity<m, kg, s, A, K, mol, cd>, D::Error> {
+            super::with_unit::deserialize(deserializer)
+        }
+    }
+
+    /// Returns the factor of the unit `U`, checking that it has the dimension of `Q`.
+    fn factor<U: DisplayUnit>(expected: Unit) -> Result<f64, String> {
+        let (factor, unit) = UnitRegistry::builtin()
+            .parse_unit(U::UNIT)
+            .map_err(|err| err.to_string())?;
+        if unit == expected {
+            Ok(factor)
+        } else {
+            Err(DimensionMismatch {
+                expected,
+                found: unit,
+            }
+            .to_string())
+        }
+    }
+
+    impl<
+            U: DisplayUnit,
+            const m: i8,
+            const kg: i8,
+            const s: i8,
+            const A: i8,
+            const K: i8,
+            const mol: i8,
+            const cd: i8,
+        > SerializeAs<Quantity<m, kg, s, A, K, mol, cd>> for InUnit<U>
+    {
+        fn serialize_as<S: Serializer>(
+            source: &Quantity<m, kg, s, A, K, mol, cd>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let factor = factor::<U>(Quantity::<m, kg, s, A, K, mol, cd>::UNIT)
+                .map_err(ser::Error::custom)?;
+            #[allow(clippy::cast_possible_truncation)]
+            let value = (f64::from(source.value) / factor) as f32;
+            value.serialize(serializer)
+        }
+    }
+
+    impl<
+            'de,
+            U: DisplayUnit,
+            const m: i8,
+            const kg: i8,
+            const s: i8,
+            const A: i8,
+            const K: i8,
+            const mol: i8,
+            const cd: i8,
+        > DeserializeAs<'de, Quantity<m, kg, s, A, K, mol, cd>> for InUnit<U>
+    {
+        fn deserialize_as<D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Quantity<m, kg, s, A, K, mol, cd>, D::Error> {
+            let factor = factor::<U>(Quantity::<m, kg, s, A, K, mol, cd>::UNIT)
+                .map_err(de::Error::custom)?;
+            let value = f32::deserialize(deserializer)?;
+            #[allow(clippy::cast_possible_truncation)]
+            Ok(Quantity::new((f64::from(value) * factor) as f32))
+        }
+    }
+}
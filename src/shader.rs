@@ -0,0 +1,105 @@
+//! [`encase`](https://docs.rs/encase) support for quantities.
+//!
+//! Quantities have the same layout as an `f32` in WGSL, so a shader can
+//! declare their fields as `f32`.
+//!
+//! ```
+//! use encase::{ShaderType, UniformBuffer};
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! #[derive(ShaderType)]
+//! struct Params {
+//!     gravity: Quantity<1, 0, -2, 0, 0, 0, 0>,
+//!     height: Quantity<1, 0, 0, 0, 0, 0, 0>,
+//! }
+//!
+//! let params = Params {
+//!     gravity: Quantity::new(9.81),
+//!     height: 2.0 * m,
+//! };
+//! let mut buffer = UniformBuffer::new(Vec::<u8>::new());
+//! buffer.write(&params).unwrap();
+//!
+//! let bytes = buffer.into_inner();
+//! assert_eq!(bytes[..4], 9.81f32.to_le_bytes());
+//! assert_eq!(bytes[4..8], 2.0f32.to_le_bytes());
+//! ```
+
+use crate::Quantity;
+use encase::{
+    internal::{BufferMut, BufferRef, CreateFrom, ReadFrom, Reader, WriteInto, Writer},
+    private::Metadata,
+    ShaderSize, ShaderType,
+};
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > ShaderType for Quantity<m, kg, s, A, K, mol, cd>
+{
+    type ExtraMetadata = <f32 as ShaderType>::ExtraMetadata;
+    const METADATA: Metadata<Self::ExtraMetadata> = <f32 as ShaderType>::METADATA;
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > ShaderSize for Quantity<m, kg, s, A, K, mol, cd>
+{
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > WriteInto for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn write_into<B: BufferMut>(&self, writer: &mut Writer<B>) {
+        self.value.write_into(writer);
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > ReadFrom for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn read_from<B: BufferRef>(&mut self, reader: &mut Reader<B>) {
+        self.value.read_from(reader);
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > CreateFrom for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn create_from<B: BufferRef>(reader: &mut Reader<B>) -> Self {
+        Self::new(f32::create_from(reader))
+    }
+}
@@ -2,9 +2,23 @@
 
 #![allow(non_upper_case_globals, dead_code)]
 
-
 // TODO: Use a macro to parameterize this?
 /// Constants for the multiplicative identities of each unit
+///
+/// Each constant has an exponent of one for its own base unit only.
+///
+/// ```
+/// use tiny_uom::values::{cd, kg, m, mol, s, A, K};
+/// use tiny_uom::Quantity;
+///
+/// let _: Quantity<1, 0, 0, 0, 0, 0, 0> = m;
+/// let _: Quantity<0, 1, 0, 0, 0, 0, 0> = kg;
+/// let _: Quantity<0, 0, 1, 0, 0, 0, 0> = s;
+/// let _: Quantity<0, 0, 0, 1, 0, 0, 0> = A;
+/// let _: Quantity<0, 0, 0, 0, 1, 0, 0> = K;
+/// let _: Quantity<0, 0, 0, 0, 0, 1, 0> = mol;
+/// let _: Quantity<0, 0, 0, 0, 0, 0, 1> = cd;
+/// ```
 pub mod values {
     use crate::Quantity;
 
@@ -15,11 +29,11 @@ pub mod values {
     /// Mass in kilogram
     pub const kg: Quantity<0, 1, 0, 0, 0, 0, 0> = Quantity { value: 1.0 };
     /// Electric current in ampere
-    pub const A: Quantity<0, 0, 0, 0, 0, 0, 0> = Quantity { value: 1.0 };
+    pub const A: Quantity<0, 0, 0, 1, 0, 0, 0> = Quantity { value: 1.0 };
     /// Temperature in kelvin
-    pub const K: Quantity<0, 0, 0, 0, 0, 0, 0> = Quantity { value: 1.0 };
+    pub const K: Quantity<0, 0, 0, 0, 1, 0, 0> = Quantity { value: 1.0 };
     /// Amount of substance in mole
-    pub const mol: Quantity<0, 0, 0, 0, 0, 0, 0> = Quantity { value: 1.0 };
+    pub const mol: Quantity<0, 0, 0, 0, 0, 1, 0> = Quantity { value: 1.0 };
     /// Luminous intensity in candela
-    pub const cd: Quantity<0, 0, 0, 0, 0, 0, 0> = Quantity { value: 1.0 };
+    pub const cd: Quantity<0, 0, 0, 0, 0, 0, 1> = Quantity { value: 1.0 };
 }
@@ -0,0 +1,72 @@
+//! Sorting and searching slices of quantities using a total order.
+//!
+//! Quantities only implement [`PartialOrd`], because their value may be NaN.
+//! The functions of this module use [`Quantity::total_cmp`], which orders
+//! all values deterministically and sorts NaN last.
+//!
+//! ```
+//! use tiny_uom::sort::{binary_search_quantity, sort_quantities};
+//! use tiny_uom::values::m;
+//!
+//! assert!(-1.0 * m < 2.0 * m);
+//! assert_eq!((f32::NAN * m).partial_cmp(&(2.0 * m)), None);
+//!
+//! let mut lengths = [3.0 * m, f32::NAN * m, -1.0 * m, 2.0 * m];
+//! sort_quantities(&mut lengths);
+//! assert_eq!(lengths[..3], [-1.0 * m, 2.0 * m, 3.0 * m]);
+//! assert!(lengths[3].raw().is_nan());
+//!
+//! assert_eq!(binary_search_quantity(&lengths, 2.0 * m), Ok(1));
+//! assert_eq!(binary_search_quantity(&lengths, 2.5 * m), Err(2));
+//! ```
+
+use crate::{Dimensioned, Quantity};
+use core::cmp::Ordering;
+
+/// Compare two raw values like [`f32::total_cmp`], except that NaN of both signs sorts last.
+fn total_cmp(lhs: f32, rhs: f32) -> Ordering {
+    match (lhs.is_nan(), rhs.is_nan()) {
+        (false, true) => Ordering::Less,
+        (true, false) => Ordering::Greater,
+        _ => lhs.total_cmp(&rhs),
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Returns the ordering between `self` and `other` in a total order.
+    ///
+    /// Values are ordered like by [`f32::total_cmp`], so `-0.0` is less than
+    /// `0.0`, except that NaN sorts after all other values regardless of its sign.
+    #[must_use]
+    #[inline]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        total_cmp(self.value, other.value)
+    }
+}
+
+/// Sort a slice of quantities in the order of [`Quantity::total_cmp`].
+pub fn sort_quantities<Q: Dimensioned<Scalar = f32>>(quantities: &mut [Q]) {
+    quantities.sort_unstable_by(|lhs, rhs| total_cmp(lhs.raw(), rhs.raw()));
+}
+
+/// Search a slice sorted by [`sort_quantities`] for a quantity, like
+/// [`slice::binary_search`].
+///
+/// # Errors
+///
+/// Returns the index where the quantity could be inserted if it is not found.
+pub fn binary_search_quantity<Q: Dimensioned<Scalar = f32>>(
+    quantities: &[Q],
+    quantity: Q,
+) -> Result<usize, usize> {
+    quantities.binary_search_by(|probe| total_cmp(probe.raw(), quantity.raw()))
+}
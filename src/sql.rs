@@ -0,0 +1,99 @@
+//! [`sqlx`](https://docs.rs/sqlx) support for quantities.
+//!
+//! Quantities are stored as their raw value in SI units, using the same
+//! column types as `f32`, like `REAL`. They can also be read from the column
+//! types of `f64`, like `DOUBLE PRECISION`, narrowing the value to `f32`.
+//!
+//! ```no_run
+//! use sqlx::SqlitePool;
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! type Length = Quantity<1, 0, 0, 0, 0, 0, 0>;
+//!
+//! async fn store(pool: &SqlitePool) -> sqlx::Result<Length> {
+//!     sqlx::query("INSERT INTO parts (length) VALUES (?)")
+//!         .bind(1.5 * m)
+//!         .execute(pool)
+//!         .await?;
+//!     sqlx::query_scalar("SELECT CAST(length AS DOUBLE PRECISION) FROM parts")
+//!         .fetch_one(pool)
+//!         .await
+//! }
+//! ```
+
+use crate::Quantity;
+use sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type, ValueRef};
+
+impl<
+        DB: Database,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Type<DB> for Quantity<m, kg, s, A, K, mol, cd>
+where
+    f32: Type<DB>,
+    f64: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <f32 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <f32 as Type<DB>>::compatible(ty) || <f64 as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<
+        'q,
+        DB: Database,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Encode<'q, DB> for Quantity<m, kg, s, A, K, mol, cd>
+where
+    f32: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        self.value.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.value.size_hint()
+    }
+}
+
+impl<
+        'r,
+        DB: Database,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Decode<'r, DB> for Quantity<m, kg, s, A, K, mol, cd>
+where
+    f32: Type<DB> + Decode<'r, DB>,
+    f64: Type<DB> + Decode<'r, DB>,
+{
+    #[allow(clippy::cast_possible_truncation)]
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let ty = value.type_info();
+        let wide = !<f32 as Type<DB>>::compatible(&ty) && <f64 as Type<DB>>::compatible(&ty);
+        if wide {
+            f64::decode(value).map(|value| Self::new(value as f32))
+        } else {
+            f32::decode(value).map(Self::new)
+        }
+    }
+}
@@ -0,0 +1,419 @@
+//! Statistics of slices of quantities.
+//!
+//! The results are quantities with the dimensions of the samples, except for
+//! the [`variance`], whose exponents are doubled. Functions that are not
+//! defined for an empty slice return `None`.
+//!
+//! ```
+//! use tiny_uom::stats;
+//! use tiny_uom::values::m;
+//! use tiny_uom::Quantity;
+//!
+//! let samples = [2.0 * m, 4.0 * m, 4.0 * m, 4.0 * m, 5.0 * m, 5.0 * m, 7.0 * m, 9.0 * m];
+//!
+//! assert_eq!(stats::sum(&samples), 40.0 * m);
+//! assert_eq!(stats::mean(&samples), Some(5.0 * m));
+//! assert_eq!(stats::min(&samples), Some(2.0 * m));
+//! assert_eq!(stats::max(&samples), Some(9.0 * m));
+//! assert_eq!(stats::variance(&samples), Some(Quantity::<2, 0, 0, 0, 0, 0, 0>::new(4.0)));
+//! assert_eq!(stats::mean::<Quantity<1, 0, 0, 0, 0, 0, 0>>(&[]), None);
+//! ```
+//!
+//! [`RunningStats`] computes the mean and the variance of a stream of samples
+//! without storing them, and with the `std` feature a [`Histogram`] counts
+//! them in bins of equal width.
+
+use crate::{Dimensioned, ExponentCheck, Quantity, Si};
+use core::marker::PhantomData;
+
+/// Returns the sum of the samples, which is zero for an empty slice.
+#[must_use]
+pub fn sum<Q: Dimensioned<Scalar = f32>>(samples: &[Q]) -> Q {
+    Q::from_raw(samples.iter().map(|sample| sample.raw()).sum())
+}
+
+/// Returns the arithmetic mean of the samples.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn mean<Q: Dimensioned<Scalar = f32>>(samples: &[Q]) -> Option<Q> {
+    if samples.is_empty() {
+        return None;
+    }
+    Some(Q::from_raw(sum(samples).raw() / samples.len() as f32))
+}
+
+/// Returns the mean of the samples, each weighted by the dimensionless
+/// weight at the same index.
+///
+/// Returns `None` if there are no samples, if the number of weights differs
+/// from the number of samples, or if the weights sum up to zero.
+///
+/// ```
+/// use tiny_uom::stats::weighted_mean;
+/// use tiny_uom::values::K;
+/// use tiny_uom::Quantity;
+///
+/// let readings = [290.0 * K, 300.0 * K];
+/// let confidence = [Quantity::new(3.0), Quantity::new(1.0)];
+/// assert_eq!(weighted_mean(&readings, &confidence), Some(292.5 * K));
+/// ```
+#[must_use]
+pub fn weighted_mean<Q: Dimensioned<Scalar = f32>>(
+    samples: &[Q],
+    weights: &[Quantity<0, 0, 0, 0, 0, 0, 0>],
+) -> Option<Q> {
+    if samples.len() != weights.len() {
+        return None;
+    }
+    let total: f32 = weights.iter().map(|weight| weight.value).sum();
+    if total == 0.0 {
+        return None;
+    }
+    let sum: f32 = samples
+        .iter()
+        .zip(weights)
+        .map(|(sample, weight)| sample.raw() * weight.value)
+        .sum();
+    Some(Q::from_raw(sum / total))
+}
+
+/// Returns the smallest sample, ignoring NaN values.
+#[must_use]
+pub fn min<Q: Dimensioned<Scalar = f32>>(samples: &[Q]) -> Option<Q> {
+    samples
+        .iter()
+        .map(|sample| sample.raw())
+        .reduce(f32::min)
+        .map(Q::from_raw)
+}
+
+/// Returns the largest sample, ignoring NaN values.
+#[must_use]
+pub fn max<Q: Dimensioned<Scalar = f32>>(samples: &[Q]) -> Option<Q> {
+    samples
+        .iter()
+        .map(|sample| sample.raw())
+        .reduce(f32::max)
+        .map(Q::from_raw)
+}
+
+/// Returns the population variance of the samples, in the square of their unit.
+///
+/// Like for [`Quantity::mul_quantity`](crate::Quantity::mul_quantity), the
+/// type of the result has to be known and must have the doubled exponents.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn variance<Q, O>(samples: &[Q]) -> Option<O>
+where
+    Q: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+{
+    let () = ExponentCheck::<Q, Q, O>::PRODUCT;
+    let mean = mean(samples)?.raw();
+    let squares: f32 = samples
+        .iter()
+        .map(|sample| (sample.raw() - mean) * (sample.raw() - mean))
+        .sum();
+    Some(O::from_raw(squares / samples.len() as f32))
+}
+
+/// Returns the population standard deviation of the samples.
+///
+/// ```
+/// use tiny_uom::stats;
+/// use tiny_uom::values::m;
+///
+/// let samples = [2.0 * m, 4.0 * m, 4.0 * m, 4.0 * m, 5.0 * m, 5.0 * m, 7.0 * m, 9.0 * m];
+/// assert_eq!(stats::std_dev(&samples), Some(2.0 * m));
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn std_dev<Q: Dimensioned<Scalar = f32>>(samples: &[Q]) -> Option<Q> {
+    let mean = mean(samples)?.raw();
+    let squares: f32 = samples
+        .iter()
+        .map(|sample| (sample.raw() - mean) * (sample.raw() - mean))
+        .sum();
+    Some(Q::from_raw(crate::math::imp::sqrt(
+        squares / samples.len() as f32,
+    )))
+}
+
+/// An accumulator of the count, mean and variance of a stream of samples,
+/// using Welford's online algorithm.
+///
+/// The statistics are accumulated in `f64`, so they stay accurate over
+/// long runs.
+///
+/// ```
+/// use tiny_uom::stats::RunningStats;
+/// use tiny_uom::values::K;
+///
+/// let mut stats = RunningStats::new();
+/// for reading in [291.0, 293.0, 292.0, 296.0] {
+///     stats.push(reading * K);
+/// }
+///
+/// assert_eq!(stats.count(), 4);
+/// assert_eq!(stats.mean(), Some(293.0 * K));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RunningStats<Q> {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    quantity: PhantomData<Q>,
+}
+
+impl<Q: Dimensioned<Scalar = f32>> RunningStats<Q> {
+    /// Create an accumulator without any samples.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            quantity: PhantomData,
+        }
+    }
+
+    /// Add a sample.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn push(&mut self, sample: Q) {
+        let value = f64::from(sample.raw());
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Returns the number of samples.
+    #[must_use]
+    #[inline]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the arithmetic mean of the samples.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn mean(&self) -> Option<Q> {
+        (self.count > 0).then(|| Q::from_raw(self.mean as f32))
+    }
+
+    /// Returns the population variance of the samples as a raw value
+    /// in the square of their unit.
+    #[allow(clippy::cast_precision_loss)]
+    fn raw_variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Returns the population variance of the samples, in the square of their unit.
+    ///
+    /// Like for [`variance`], the type of the result must have the doubled exponents.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn variance<O>(&self) -> Option<O>
+    where
+        Q: Dimensioned<Exponents = [i8; 7], System = Si>,
+        O: Dimensioned<Scalar = f32, Exponents = [i8; 7], System = Si>,
+    {
+        let () = ExponentCheck::<Q, Q, O>::PRODUCT;
+        Some(O::from_raw(self.raw_variance()? as f32))
+    }
+
+    /// Returns the population standard deviation of the samples.
+    ///
+    /// ```
+    /// use tiny_uom::stats::RunningStats;
+    /// use tiny_uom::values::K;
+    ///
+    /// let stats: RunningStats<_> = [291.0 * K, 293.0 * K, 292.0 * K, 296.0 * K]
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(stats.std_dev(), Some(1.870_828_7 * K));
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn std_dev(&self) -> Option<Q> {
+        let std_dev = crate::math::imp::sqrt64(self.raw_variance()?);
+        Some(Q::from_raw(std_dev as f32))
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> Default for RunningStats<Q> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> Extend<Q> for RunningStats<Q> {
+    fn extend<I: IntoIterator<Item = Q>>(&mut self, samples: I) {
+        samples.into_iter().for_each(|sample| self.push(sample));
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> FromIterator<Q> for RunningStats<Q> {
+    fn from_iter<I: IntoIterator<Item = Q>>(samples: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(samples);
+        stats
+    }
+}
+
+/// A histogram of samples in bins of equal width.
+///
+/// Samples below the first bin or not below the end of the last bin are
+/// counted separately, and NaN samples are ignored.
+///
+/// ```
+/// use tiny_uom::stats::Histogram;
+/// use tiny_uom::values::m;
+///
+/// let mut lengths = Histogram::new(0.0 * m, 1.0 * m, 4);
+/// for length in [0.5, 0.7, 1.2, 1.5, 2.1, 2.5, 3.1, 9.0] {
+///     lengths.push(length * m);
+/// }
+///
+/// assert_eq!(lengths.counts(), [2, 2, 2, 1]);
+/// assert_eq!(lengths.overflow(), 1);
+/// assert_eq!(lengths.bin(1), (1.0 * m, 2.0 * m));
+/// assert_eq!(lengths.percentile(37.5), Some(1.5 * m));
+/// assert_eq!(lengths.percentile(100.0), Some(4.0 * m));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram<Q> {
+    start: Q,
+    width: Q,
+    counts: Vec<u64>,
+    underflow: u64,
+    overflow: u64,
+}
+
+#[cfg(feature = "std")]
+impl<Q: Dimensioned<Scalar = f32>> Histogram<Q> {
+    /// Create a histogram of `bins` bins of the given width, starting at `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is not positive.
+    #[must_use]
+    pub fn new(start: Q, width: Q, bins: usize) -> Self {
+        assert!(width.raw() > 0.0, "the width of the bins must be positive");
+        Self {
+            start,
+            width,
+            counts: vec![0; bins],
+            underflow: 0,
+            overflow: 0,
+        }
+    }
+
+    /// Count a sample.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn push(&mut self, sample: Q) {
+        let pos = (sample.raw() - self.start.raw()) / self.width.raw();
+        if pos.is_nan() {
+            return;
+        }
+        if pos < 0.0 {
+            self.underflow += 1;
+        } else if pos >= self.counts.len() as f32 {
+            self.overflow += 1;
+        } else {
+            // rounding can put samples just below the end into the bin after the last one
+            let idx = (pos as usize).min(self.counts.len() - 1);
+            self.counts[idx] += 1;
+        }
+    }
+
+    /// Returns the width of the bins.
+    #[must_use]
+    #[inline]
+    pub fn width(&self) -> Q {
+        self.width
+    }
+
+    /// Returns the number of samples in every bin.
+    #[must_use]
+    #[inline]
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Returns the number of samples below the first bin.
+    #[must_use]
+    #[inline]
+    pub fn underflow(&self) -> u64 {
+        self.underflow
+    }
+
+    /// Returns the number of samples not below the end of the last bin.
+    #[must_use]
+    #[inline]
+    pub fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// Returns the number of all counted samples.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.underflow + self.counts.iter().sum::<u64>() + self.overflow
+    }
+
+    /// Returns the edge of the bin `idx`, or the end of the last bin for `idx == bins`.
+    #[allow(clippy::cast_precision_loss)]
+    fn edge(&self, idx: usize) -> f32 {
+        self.start.raw() + idx as f32 * self.width.raw()
+    }
+
+    /// Returns the lower and the upper edge of the bin `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not the index of a bin.
+    #[must_use]
+    pub fn bin(&self, idx: usize) -> (Q, Q) {
+        assert!(idx < self.counts.len(), "the histogram has no bin {idx}");
+        (Q::from_raw(self.edge(idx)), Q::from_raw(self.edge(idx + 1)))
+    }
+
+    /// Returns the sample below which `percent` percent of the samples lie,
+    /// interpolated linearly within its bin.
+    ///
+    /// Samples outside of the bins are treated as lying at the start of the
+    /// first bin or the end of the last bin. Returns `None` if no samples
+    /// were counted.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn percentile(&self, percent: f32) -> Option<Q> {
+        let total = self.total();
+        if total == 0 {
+            return None;
+        }
+        let rank = f64::from(percent.clamp(0.0, 100.0)) / 100.0 * total as f64;
+
+        let mut below = self.underflow as f64;
+        if rank <= below {
+            return Some(Q::from_raw(self.edge(0)));
+        }
+        for (idx, &count) in self.counts.iter().enumerate() {
+            let count = count as f64;
+            if count > 0.0 && rank <= below + count {
+                #[allow(clippy::cast_possible_truncation)]
+                let fraction = ((rank - below) / count) as f32;
+                return Some(Q::from_raw(self.edge(idx) + fraction * self.width.raw()));
+            }
+            below += count;
+        }
+        Some(Q::from_raw(self.edge(self.counts.len())))
+    }
+}
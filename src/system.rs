@@ -0,0 +1,263 @@
+//! Quantities with user-defined base dimensions.
+
+/// Define a module with a quantity type for a custom set of base dimensions.
+///
+/// Every base dimension becomes an `i8` const parameter of the `Quantity` of
+/// the module, in the order they are listed. The module gets the same
+/// operators as [`Quantity`](crate::Quantity), a `values` module with the
+/// multiplicative identity of every base dimension, and a [`Display`]
+/// implementation that uses the names of the dimensions as their symbols.
+/// The doc comments of the dimensions document their constants in `values`.
+///
+/// ```
+/// tiny_uom::unit_system! {
+///     /// Quantities of a sprite animation.
+///     pub mod screen {
+///         /// Length in pixels
+///         px,
+///         /// Time in frames
+///         frame,
+///     }
+/// }
+///
+/// use screen::values::{frame, px};
+/// use screen::Quantity;
+///
+/// let distance = 48.0 * px + 16.0 * px;
+/// let speed: Quantity<1, -1> = distance.div_quantity(4.0 * frame);
+/// assert_eq!(speed.to_string(), "16 px/frame");
+/// assert_eq!(screen::SYMBOLS, ["px", "frame"]);
+/// ```
+///
+/// The names of the dimensions can be any identifiers, including the ones
+/// the macro uses internally, like `f` or `rhs`:
+///
+/// ```
+/// tiny_uom::unit_system! {
+///     pub mod optics {
+///         /// Focal length in millimetres
+///         f,
+///         /// Aperture steps
+///         rhs,
+///         /// Frames
+///         R,
+///         /// Exposure value
+///         value,
+///     }
+/// }
+///
+/// use optics::values::{f, rhs};
+///
+/// let focal = 35.0 * f + 15.0 * f;
+/// assert_eq!(focal.to_string(), "50 f");
+/// assert_eq!((2.0 * rhs).const_add(rhs).to_string(), "3 rhs");
+/// ```
+///
+/// Adding quantities of different dimensions fails to compile, like for
+/// [`Quantity`](crate::Quantity):
+///
+/// ```compile_fail
+/// tiny_uom::unit_system! {
+///     pub mod shop {
+///         /// Money in euro
+///         eur,
+///         /// Number of items
+///         item,
+///     }
+/// }
+///
+/// use shop::values::{eur, item};
+///
+/// let total = 3.0 * eur + 2.0 * item;
+/// ```
+///
+/// The type of the result of `mul_quantity` and `div_quantity` has to be
+/// written out, and a type with the wrong exponents fails to compile:
+///
+/// ```compile_fail
+/// tiny_uom::unit_system! {
+///     pub mod shop {
+///         /// Money in euro
+///         eur,
+///         /// Number of items
+///         item,
+///     }
+/// }
+///
+/// use shop::values::{eur, item};
+///
+/// let price: shop::Quantity<1, 1> = (3.0 * eur).div_quantity(2.0 * item);
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+#[macro_export]
+macro_rules! unit_system {
+    (
+        $(#[$meta:meta])*
+        $vis:vis mod $name:ident {
+            $($(#[$unit_meta:meta])* $unit:ident),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[allow(non_upper_case_globals)]
+        $vis mod $name {
+            /// A `Quantity` represents a raw value and it's unit
+            /// that is represented as a const generic parameter.
+            ///
+            /// The default `Quantity` has a value of zero.
+            #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+            #[repr(transparent)]
+            pub struct Quantity<$(const $unit: i8,)*> {
+                /// The raw value of this `Quantity`
+                pub value: f32,
+            }
+
+            $crate::quantity_ops!(f32, Quantity, i8, $($unit),+);
+            $crate::__quantity_const_ops!(f32, Quantity, i8, $($unit),+);
+
+            /// The symbols of the base dimensions, in the order of the const parameters.
+            pub const SYMBOLS: [&str; [$(stringify!($unit)),+].len()] = [$(stringify!($unit)),+];
+
+            /// The [`System`]($crate::Dimensioned::System) of the quantities of this unit system.
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            pub enum System {}
+
+            /// Implemented by the quantities of this unit system.
+            pub trait SystemQuantity:
+                $crate::Dimensioned<Scalar = f32, Exponents = [i8; SYMBOLS.len()], System = System>
+            {
+            }
+
+            impl<$(const $unit: i8,)*> SystemQuantity for Quantity<$($unit,)*> {}
+
+            impl<$(const $unit: i8,)*> $crate::macro_support::Sealed for Quantity<$($unit,)*> {}
+
+            impl<$(const $unit: i8,)*> $crate::Dimensioned for Quantity<$($unit,)*> {
+                type Scalar = f32;
+                type Exponents = [i8; SYMBOLS.len()];
+                type System = System;
+
+                const EXPONENTS: Self::Exponents = [$($unit),+];
+
+                #[inline]
+                fn from_raw(__value: f32) -> Self {
+                    Quantity::new(__value)
+                }
+
+                #[inline]
+                fn raw(self) -> f32 {
+                    self.value
+                }
+            }
+
+            impl<$(const $unit: i8,)*> Quantity<$($unit,)*> {
+                /// Multiply two quantities, adding their exponents.
+                ///
+                /// The type of the result has to be written out, and a type
+                /// with the wrong exponents fails to compile.
+                #[must_use]
+                #[inline]
+                pub fn mul_quantity<__R: SystemQuantity, __O: SystemQuantity>(self, __rhs: __R) -> __O {
+                    const {
+                        ::core::assert!(
+                            $crate::macro_support::sum_in_range(
+                                &<Self as $crate::Dimensioned>::EXPONENTS,
+                                &__R::EXPONENTS,
+                                1,
+                            ),
+                            "the exponents of the result overflow an `i8`"
+                        );
+                        ::core::assert!(
+                            $crate::macro_support::is_sum(
+                                &__O::EXPONENTS,
+                                &<Self as $crate::Dimensioned>::EXPONENTS,
+                                &__R::EXPONENTS,
+                                1,
+                            ),
+                            "the exponents of the result must be the sum of the exponents"
+                        );
+                    }
+                    $crate::Dimensioned::from_raw(self.value * $crate::Dimensioned::raw(__rhs))
+                }
+
+                /// Divide two quantities, subtracting their exponents.
+                ///
+                /// The type of the result has to be written out, and a type
+                /// with the wrong exponents fails to compile.
+                #[must_use]
+                #[inline]
+                pub fn div_quantity<__R: SystemQuantity, __O: SystemQuantity>(self, __rhs: __R) -> __O {
+                    const {
+                        ::core::assert!(
+                            $crate::macro_support::sum_in_range(
+                                &<Self as $crate::Dimensioned>::EXPONENTS,
+                                &__R::EXPONENTS,
+                                -1,
+                            ),
+                            "the exponents of the result overflow an `i8`"
+                        );
+                        ::core::assert!(
+                            $crate::macro_support::is_sum(
+                                &__O::EXPONENTS,
+                                &<Self as $crate::Dimensioned>::EXPONENTS,
+                                &__R::EXPONENTS,
+                                -1,
+                            ),
+                            "the exponents of the result must be the difference of the exponents"
+                        );
+                    }
+                    $crate::Dimensioned::from_raw(self.value / $crate::Dimensioned::raw(__rhs))
+                }
+            }
+
+            impl ::core::ops::Mul for Quantity<$({ $crate::unit_system!(@zero $unit) },)*> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, __rhs: Self) -> Self::Output {
+                    Quantity {
+                        value: self.value * __rhs.value,
+                    }
+                }
+            }
+
+            impl ::core::ops::Div for Quantity<$({ $crate::unit_system!(@zero $unit) },)*> {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, __rhs: Self) -> Self::Output {
+                    Quantity {
+                        value: self.value / __rhs.value,
+                    }
+                }
+            }
+
+            impl<$(const $unit: i8,)*> ::core::fmt::Display for Quantity<$($unit,)*> {
+                /// Formats the value followed by the symbols of the base dimensions, like `16 px/frame`.
+                fn fmt(&self, __f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(&self.value, __f)?;
+                    $crate::macro_support::write_unit(__f, &SYMBOLS, &[$(i32::from($unit)),+])
+                }
+            }
+
+            /// Constants for the multiplicative identities of each unit
+            pub mod values {
+                $crate::unit_system!(@values [] [$($unit)+] $([$(#[$unit_meta])*])+);
+            }
+        }
+    };
+    (@zero $unit:ident) => {
+        0
+    };
+    (@values [$($before:ident)*] [$cur:ident $($after:ident)*] [$(#[$meta:meta])*] $($rest:tt)*) => {
+        $(#[$meta])*
+        pub const $cur: super::Quantity<
+            $({ $crate::unit_system!(@zero $before) },)*
+            1,
+            $({ $crate::unit_system!(@zero $after) },)*
+        > = super::Quantity::new(1.0);
+
+        $crate::unit_system!(@values [$($before)* $cur] [$($after)*] $($rest)*);
+    };
+    (@values [$($before:ident)*] []) => {};
+}
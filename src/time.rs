@@ -0,0 +1,37 @@
+//! Conversions between time quantities and [`time`] durations.
+//!
+//! ```
+//! use time::Duration;
+//! use tiny_uom::values::s;
+//! use tiny_uom::Quantity;
+//!
+//! let elapsed = Quantity::from(Duration::milliseconds(-1500));
+//! assert_eq!(elapsed, -1.5 * s);
+//! assert_eq!((2.5 * s).to_time_duration(), Ok(Duration::milliseconds(2500)));
+//! assert!((f32::INFINITY * s).to_time_duration().is_err());
+//! ```
+//!
+//! [`time`]: https://docs.rs/time
+
+use crate::duration::duration_error;
+use crate::error::ConversionError;
+use crate::Quantity;
+use time::Duration;
+
+impl From<Duration> for Quantity<0, 0, 1, 0, 0, 0, 0> {
+    fn from(duration: Duration) -> Self {
+        Quantity::new(duration.as_seconds_f32())
+    }
+}
+
+impl Quantity<0, 0, 1, 0, 0, 0, 0> {
+    /// Convert this time into a [`time::Duration`], which may be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::NonFinite`] if the time is not finite, and
+    /// [`ConversionError::OutOfRange`] if it is too large for a `Duration`.
+    pub fn to_time_duration(self) -> Result<Duration, ConversionError> {
+        Duration::checked_seconds_f32(self.value).ok_or_else(|| duration_error(self.value))
+    }
+}
@@ -0,0 +1,499 @@
+//! Parsing and formatting of [UCUM] unit codes.
+//!
+//! UCUM is the unit syntax mandated by HL7 FHIR and most laboratory
+//! interfaces. A code such as `mg/dL` is parsed into the factor and
+//! [`Unit`] needed to convert values into the SI units used by [`Quantity`].
+//!
+//! ```
+//! use tiny_uom::Quantity;
+//!
+//! type MassConcentration = Quantity<-3, 1, 0, 0, 0, 0, 0>;
+//! type Pressure = Quantity<-1, 1, -2, 0, 0, 0, 0>;
+//! type Temperature = Quantity<0, 0, 0, 0, 1, 0, 0>;
+//!
+//! let glucose = MassConcentration::from_ucum(90.0, "mg/dL").unwrap();
+//! assert!((glucose.raw() - 0.9).abs() < 1e-6);
+//! assert!((glucose.to_ucum("mg/dL").unwrap() - 90.0).abs() < 1e-4);
+//! assert_eq!(MassConcentration::ucum_code(), "kg.m-3");
+//!
+//! let blood = Pressure::from_ucum(100.0, "mm[Hg]").unwrap();
+//! assert!((blood.raw() - 13_332.2).abs() < 0.1);
+//!
+//! let body = Temperature::from_ucum(37.0, "Cel").unwrap();
+//! assert!((body.raw() - 310.15).abs() < 1e-4);
+//! assert!(Temperature::from_ucum(37.0, "mm[Hg]").is_err());
+//! ```
+//!
+//! [UCUM]: https://ucum.org/ucum
+
+use crate::{error::DimensionMismatch, Quantity, Unit};
+use std::fmt;
+
+/// The conversion described by a UCUM code.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UcumUnit {
+    /// The factor a value in this unit is multiplied with to get its value in SI units.
+    pub factor: f64,
+    /// The offset added after applying `factor`, only non-zero for `Cel` and `[degF]`.
+    pub offset: f64,
+    /// The SI unit of the converted value.
+    pub unit: Unit,
+}
+
+impl UcumUnit {
+    /// Convert a value in this unit into SI units.
+    #[must_use]
+    pub fn to_si(self, value: f64) -> f64 {
+        value * self.factor + self.offset
+    }
+
+    /// Convert a value in SI units into this unit.
+    #[must_use]
+    pub fn from_si(self, value: f64) -> f64 {
+        (value - self.offset) / self.factor
+    }
+}
+
+/// An error that occurred while parsing or applying a UCUM code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UcumError {
+    /// The code is not valid UCUM syntax at the given byte offset.
+    Syntax(usize),
+    /// The code contains a unit atom that is unknown or not supported.
+    UnknownUnit(String),
+    /// A unit with an offset (like `Cel`) was raised to a power or combined with other units.
+    SpecialUnit(String),
+    /// An exponent of the resulting unit does not fit into an `i8`.
+    ExponentOverflow,
+    /// The code describes a different dimension than the one requested.
+    DimensionMismatch(DimensionMismatch),
+}
+
+impl fmt::Display for UcumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UcumError::Syntax(pos) => write!(f, "invalid UCUM syntax at offset {pos}"),
+            UcumError::UnknownUnit(atom) => write!(f, "unknown UCUM unit `{atom}`"),
+            UcumError::SpecialUnit(atom) => {
+                write!(f, "UCUM unit `{atom}` can not be combined with other units")
+            }
+            UcumError::ExponentOverflow => f.write_str("UCUM unit exponent overflowed"),
+            UcumError::DimensionMismatch(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for UcumError {}
+
+impl From<DimensionMismatch> for UcumError {
+    fn from(err: DimensionMismatch) -> Self {
+        UcumError::DimensionMismatch(err)
+    }
+}
+
+struct Atom {
+    code: &'static str,
+    factor: f64,
+    offset: f64,
+    unit: Unit,
+    metric: bool,
+}
+
+const fn atom(code: &'static str, factor: f64, exps: [i8; 7], metric: bool) -> Atom {
+    Atom {
+        code,
+        factor,
+        offset: 0.0,
+        unit: Unit::from_exponents(exps),
+        metric,
+    }
+}
+
+const ONE: [i8; 7] = [0, 0, 0, 0, 0, 0, 0];
+const HZ: [i8; 7] = [0, 0, -1, 0, 0, 0, 0];
+const PA: [i8; 7] = [-1, 1, -2, 0, 0, 0, 0];
+const J: [i8; 7] = [2, 1, -2, 0, 0, 0, 0];
+const M3: [i8; 7] = [3, 0, 0, 0, 0, 0, 0];
+
+#[rustfmt::skip]
+static ATOMS: &[Atom] = &[
+    // base units
+    atom("m", 1.0, [1, 0, 0, 0, 0, 0, 0], true),
+    atom("s", 1.0, [0, 0, 1, 0, 0, 0, 0], true),
+    atom("g", 1e-3, [0, 1, 0, 0, 0, 0, 0], true),
+    atom("rad", 1.0, ONE, true),
+    atom("K", 1.0, [0, 0, 0, 0, 1, 0, 0], true),
+    atom("C", 1.0, [0, 0, 1, 1, 0, 0, 0], true),
+    atom("cd", 1.0, [0, 0, 0, 0, 0, 0, 1], true),
+    // derived SI units
+    atom("mol", 1.0, [0, 0, 0, 0, 0, 1, 0], true),
+    atom("sr", 1.0, ONE, true),
+    atom("Hz", 1.0, HZ, true),
+    atom("N", 1.0, [1, 1, -2, 0, 0, 0, 0], true),
+    atom("Pa", 1.0, PA, true),
+    atom("J", 1.0, J, true),
+    atom("W", 1.0, [2, 1, -3, 0, 0, 0, 0], true),
+    atom("A", 1.0, [0, 0, 0, 1, 0, 0, 0], true),
+    atom("V", 1.0, [2, 1, -3, -1, 0, 0, 0], true),
+    atom("F", 1.0, [-2, -1, 4, 2, 0, 0, 0], true),
+    atom("Ohm", 1.0, [2, 1, -3, -2, 0, 0, 0], true),
+    atom("S", 1.0, [-2, -1, 3, 2, 0, 0, 0], true),
+    atom("Wb", 1.0, [2, 1, -2, -1, 0, 0, 0], true),
+    atom("T", 1.0, [0, 1, -2, -1, 0, 0, 0], true),
+    atom("H", 1.0, [2, 1, -2, -2, 0, 0, 0], true),
+    atom("lm", 1.0, [0, 0, 0, 0, 0, 0, 1], true),
+    atom("lx", 1.0, [-2, 0, 0, 0, 0, 0, 1], true),
+    atom("Bq", 1.0, HZ, true),
+    atom("Gy", 1.0, [2, 0, -2, 0, 0, 0, 0], true),
+    atom("Sv", 1.0, [2, 0, -2, 0, 0, 0, 0], true),
+    atom("kat", 1.0, [0, 0, -1, 0, 0, 1, 0], true),
+    // other metric units
+    atom("L", 1e-3, M3, true),
+    atom("l", 1e-3, M3, true),
+    atom("ar", 100.0, [2, 0, 0, 0, 0, 0, 0], true),
+    atom("t", 1e3, [0, 1, 0, 0, 0, 0, 0], true),
+    atom("bar", 1e5, PA, true),
+    atom("u", 1.660_540_2e-27, [0, 1, 0, 0, 0, 0, 0], true),
+    atom("eV", 1.602_177_33e-19, J, true),
+    atom("cal", 4.184, J, true),
+    atom("m[Hg]", 133_322.387_415, PA, true),
+    atom("m[H2O]", 9_806.65, PA, true),
+    // non-metric units
+    atom("10*", 10.0, ONE, false),
+    atom("10^", 10.0, ONE, false),
+    atom("%", 1e-2, ONE, false),
+    atom("[ppth]", 1e-3, ONE, false),
+    atom("[ppm]", 1e-6, ONE, false),
+    atom("[ppb]", 1e-9, ONE, false),
+    atom("deg", std::f64::consts::PI / 180.0, ONE, false),
+    atom("min", 60.0, [0, 0, 1, 0, 0, 0, 0], false),
+    atom("h", 3_600.0, [0, 0, 1, 0, 0, 0, 0], false),
+    atom("d", 86_400.0, [0, 0, 1, 0, 0, 0, 0], false),
+    atom("wk", 604_800.0, [0, 0, 1, 0, 0, 0, 0], false),
+    atom("mo", 2_629_800.0, [0, 0, 1, 0, 0, 0, 0], false),
+    atom("a", 31_557_600.0, [0, 0, 1, 0, 0, 0, 0], false),
+    atom("Ao", 1e-10, [1, 0, 0, 0, 0, 0, 0], false),
+    atom("atm", 101_325.0, PA, false),
+    atom("[Cal]", 4_184.0, J, false),
+    atom("[in_i]", 0.0254, [1, 0, 0, 0, 0, 0, 0], false),
+    atom("[ft_i]", 0.3048, [1, 0, 0, 0, 0, 0, 0], false),
+    atom("[yd_i]", 0.9144, [1, 0, 0, 0, 0, 0, 0], false),
+    atom("[mi_i]", 1_609.344, [1, 0, 0, 0, 0, 0, 0], false),
+    atom("[nmi_i]", 1_852.0, [1, 0, 0, 0, 0, 0, 0], false),
+    atom("[kn_i]", 1_852.0 / 3_600.0, [1, 0, -1, 0, 0, 0, 0], false),
+    atom("[lb_av]", 0.453_592_37, [0, 1, 0, 0, 0, 0, 0], false),
+    atom("[oz_av]", 0.028_349_523_125, [0, 1, 0, 0, 0, 0, 0], false),
+    atom("[gal_us]", 3.785_411_784e-3, M3, false),
+    atom("[psi]", 6_894.757_293_168, PA, false),
+    // units with an offset
+    Atom {
+        code: "Cel",
+        factor: 1.0,
+        offset: 273.15,
+        unit: Unit::from_exponents([0, 0, 0, 0, 1, 0, 0]),
+        metric: true,
+    },
+    Atom {
+        code: "[degF]",
+        factor: 5.0 / 9.0,
+        offset: 459.67 * 5.0 / 9.0,
+        unit: Unit::from_exponents([0, 0, 0, 0, 1, 0, 0]),
+        metric: false,
+    },
+];
+
+#[rustfmt::skip]
+static PREFIXES: &[(&str, f64)] = &[
+    ("da", 1e1),
+    ("Y", 1e24), ("Z", 1e21), ("E", 1e18), ("P", 1e15), ("T", 1e12), ("G", 1e9),
+    ("M", 1e6), ("k", 1e3), ("h", 1e2), ("d", 1e-1), ("c", 1e-2), ("m", 1e-3),
+    ("u", 1e-6), ("n", 1e-9), ("p", 1e-12), ("f", 1e-15), ("a", 1e-18),
+    ("z", 1e-21), ("y", 1e-24),
+];
+
+/// Look up a unit symbol, with an optional metric prefix.
+fn lookup(symbol: &str) -> Option<(f64, &'static Atom)> {
+    if let Some(atom) = ATOMS.iter().find(|atom| atom.code == symbol) {
+        return Some((1.0, atom));
+    }
+
+    PREFIXES.iter().find_map(|&(prefix, factor)| {
+        let rest = symbol.strip_prefix(prefix)?;
+        let atom = ATOMS.iter().find(|atom| atom.metric && atom.code == rest)?;
+        Some((factor, atom))
+    })
+}
+
+/// An intermediate result of the parser.
+#[derive(Clone, Copy)]
+struct Term {
+    factor: f64,
+    unit: Unit,
+    special: Option<&'static Atom>,
+}
+
+impl Term {
+    fn combine(self, rhs: Term, divide: bool) -> Result<Term, UcumError> {
+        if let Some(atom) = self.special.or(rhs.special) {
+            return Err(UcumError::SpecialUnit(atom.code.to_string()));
+        }
+
+        let (factor, unit) = if divide {
+            (rhs.factor.recip(), rhs.unit.checked_powi(-1))
+        } else {
+            (rhs.factor, Some(rhs.unit))
+        };
+        let unit = unit
+            .and_then(|unit| self.unit.checked_mul(unit))
+            .ok_or(UcumError::ExponentOverflow)?;
+
+        Ok(Term {
+            factor: self.factor * factor,
+            unit,
+            special: None,
+        })
+    }
+}
+
+struct Parser<'a> {
+    code: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.code.as_bytes().get(self.pos).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let matches = self.peek() == Some(byte);
+        if matches {
+            self.pos += 1;
+        }
+        matches
+    }
+
+    fn term(&mut self) -> Result<Term, UcumError> {
+        let mut term = self.component()?;
+        loop {
+            let divide = match self.peek() {
+                Some(b'.') => false,
+                Some(b'/') => true,
+                _ => return Ok(term),
+            };
+            self.pos += 1;
+            term = term.combine(self.component()?, divide)?;
+        }
+    }
+
+    fn annotation(&mut self) -> Result<bool, UcumError> {
+        if !self.eat(b'{') {
+            return Ok(false);
+        }
+        match self.code[self.pos..].find('}') {
+            Some(len) => {
+                self.pos += len + 1;
+                Ok(true)
+            }
+            None => Err(UcumError::Syntax(self.code.len())),
+        }
+    }
+
+    fn component(&mut self) -> Result<Term, UcumError> {
+        if self.eat(b'(') {
+            let term = self.term()?;
+            if !self.eat(b')') {
+                return Err(UcumError::Syntax(self.pos));
+            }
+            self.annotation()?;
+            return Ok(term);
+        }
+
+        let start = self.pos;
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'.' | b'/' | b'(' | b')' | b'{' | b'}' => break,
+                b'[' => match self.code[self.pos..].find(']') {
+                    Some(len) => self.pos += len + 1,
+                    None => return Err(UcumError::Syntax(self.code.len())),
+                },
+                _ => self.pos += 1,
+            }
+        }
+        let symbol = &self.code[start..self.pos];
+
+        if symbol.is_empty() {
+            return if self.annotation()? {
+                Ok(Term {
+                    factor: 1.0,
+                    unit: Unit::DIMENSIONLESS,
+                    special: None,
+                })
+            } else {
+                Err(UcumError::Syntax(start))
+            };
+        }
+        self.annotation()?;
+
+        if symbol.bytes().all(|b| b.is_ascii_digit()) {
+            let factor = symbol.parse().map_err(|_| UcumError::Syntax(start))?;
+            return Ok(Term {
+                factor,
+                unit: Unit::DIMENSIONLESS,
+                special: None,
+            });
+        }
+
+        let (symbol, exp) = split_exponent(symbol, start)?;
+        let (prefix, atom) =
+            lookup(symbol).ok_or_else(|| UcumError::UnknownUnit(symbol.to_string()))?;
+
+        if atom.offset != 0.0 {
+            return if exp == 1 && symbol == atom.code {
+                Ok(Term {
+                    factor: atom.factor,
+                    unit: atom.unit,
+                    special: Some(atom),
+                })
+            } else {
+                Err(UcumError::SpecialUnit(symbol.to_string()))
+            };
+        }
+
+        Ok(Term {
+            factor: (prefix * atom.factor).powi(exp.into()),
+            unit: atom
+                .unit
+                .checked_powi(exp)
+                .ok_or(UcumError::ExponentOverflow)?,
+            special: None,
+        })
+    }
+}
+
+/// Split a trailing, optionally signed, exponent from a unit symbol.
+fn split_exponent(symbol: &str, start: usize) -> Result<(&str, i8), UcumError> {
+    let digits = symbol.bytes().rev().take_while(u8::is_ascii_digit).count();
+    if digits == 0 {
+        return Ok((symbol, 1));
+    }
+
+    let mut split = symbol.len() - digits;
+    if split > 0 && matches!(symbol.as_bytes()[split - 1], b'+' | b'-') {
+        split -= 1;
+    }
+    if split == 0 {
+        return Err(UcumError::Syntax(start));
+    }
+
+    let exp = symbol[split..]
+        .trim_start_matches('+')
+        .parse()
+        .map_err(|_| UcumError::ExponentOverflow)?;
+    Ok((&symbol[..split], exp))
+}
+
+/// Parse a UCUM code into the conversion it describes.
+///
+/// # Errors
+///
+/// Returns an error if the code is malformed or contains an unsupported unit.
+pub fn parse(code: &str) -> Result<UcumUnit, UcumError> {
+    let mut parser = Parser { code, pos: 0 };
+    let leading_slash = parser.eat(b'/');
+    let mut term = parser.term()?;
+    if parser.pos != code.len() {
+        return Err(UcumError::Syntax(parser.pos));
+    }
+
+    if leading_slash {
+        let one = Term {
+            factor: 1.0,
+            unit: Unit::DIMENSIONLESS,
+            special: None,
+        };
+        term = one.combine(term, true)?;
+    }
+
+    Ok(UcumUnit {
+        factor: term.factor,
+        offset: term.special.map_or(0.0, |atom| atom.offset),
+        unit: term.unit,
+    })
+}
+
+/// Format a [`Unit`] as a UCUM code using the SI base units.
+#[must_use]
+pub fn format(unit: Unit) -> String {
+    const SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+    let exps = unit.exponents();
+    let positive = SYMBOLS.iter().zip(exps).filter(|&(_, exp)| exp > 0);
+    let negative = SYMBOLS.iter().zip(exps).filter(|&(_, exp)| exp < 0);
+
+    let code = positive
+        .chain(negative)
+        .map(|(symbol, exp)| match exp {
+            1 => (*symbol).to_string(),
+            _ => format!("{symbol}{exp}"),
+        })
+        .collect::<Vec<_>>()
+        .join(".");
+
+    if code.is_empty() {
+        "1".to_string()
+    } else {
+        code
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Create a `Quantity` from a value expressed in the given UCUM unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code can not be parsed or has a different dimension.
+    pub fn from_ucum(value: f32, code: &str) -> Result<Self, UcumError> {
+        let unit = Self::ucum_unit(code)?;
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(Self::new(unit.to_si(f64::from(value)) as f32))
+    }
+
+    /// Returns the value of this `Quantity` expressed in the given UCUM unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code can not be parsed or has a different dimension.
+    pub fn to_ucum(self, code: &str) -> Result<f32, UcumError> {
+        let unit = Self::ucum_unit(code)?;
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(unit.from_si(f64::from(self.value)) as f32)
+    }
+
+    /// Returns the UCUM code of the SI unit of this `Quantity`.
+    #[must_use]
+    pub fn ucum_code() -> String {
+        format(Self::UNIT)
+    }
+
+    fn ucum_unit(code: &str) -> Result<UcumUnit, UcumError> {
+        let unit = parse(code)?;
+        if unit.unit == Self::UNIT {
+            Ok(unit)
+        } else {
+            Err(UcumError::DimensionMismatch(DimensionMismatch {
+                expected: Self::UNIT,
+                found: unit.unit,
+            }))
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! Quantities with a single [`Unit`] as their const parameter.
+//!
+//! This is the encoding of the original [proof-of-concept], which needs the
+//! unstable `adt_const_params` feature of a nightly compiler. Compared to the
+//! seven integers of [`Quantity`](crate::Quantity), the names of the types in
+//! error messages are much easier to read, like
+//! `Quantity<Unit { m: 1, kg: 0, s: -1, A: 0, K: 0, mol: 0, cd: 0 }>`.
+//!
+//! Quantities of any two units can be multiplied and divided using
+//! [`Quantity::mul_quantity`] and [`Quantity::div_quantity`]. Computing the
+//! unit of the result in the type would also need `generic_const_exprs`,
+//! so it has to be written out, and a wrong unit fails to compile.
+//!
+//! The stable [`Quantity`](crate::Quantity) stays the default, and quantities
+//! convert from and into the stable quantity with the same exponents.
+//!
+//! ```
+//! #![feature(adt_const_params)]
+//! use tiny_uom::unit_param::values::{m, s};
+//! use tiny_uom::unit_param::Quantity;
+//! use tiny_uom::Unit;
+//!
+//! type Velocity = Quantity<{ Unit { m: 1, s: -1, ..Unit::DIMENSIONLESS } }>;
+//!
+//! let velocity: Velocity = (10.0 * m).div_quantity(2.0 * s);
+//! assert_eq!(velocity.to_string(), "5 m/s");
+//!
+//! let velocity: tiny_uom::Quantity<1, 0, -1, 0, 0, 0, 0> = velocity.into();
+//! assert_eq!(velocity, 5.0 * (tiny_uom::values::m / tiny_uom::values::s));
+//!
+//! let area: Quantity<{ Unit { m: 2, ..Unit::DIMENSIONLESS } }> = (2.0 * m).mul_quantity(3.0 * m);
+//! assert_eq!(area.raw(), 6.0);
+//! ```
+//!
+//! The checks of the units also compile in crates that enable
+//! `generic_const_exprs`, like the users of `tiny-uom-ops`:
+//!
+//! ```
+//! #![feature(adt_const_params, generic_const_exprs)]
+//! #![allow(incomplete_features)]
+//! use tiny_uom::unit_param::values::{m, s};
+//! use tiny_uom::unit_param::Quantity;
+//! use tiny_uom::Unit;
+//!
+//! type Velocity = Quantity<{ Unit { m: 1, s: -1, ..Unit::DIMENSIONLESS } }>;
+//!
+//! let velocity: Velocity = (10.0 * m).div_quantity(2.0 * s);
+//! let stable: tiny_uom::Quantity<1, 0, -1, 0, 0, 0, 0> = velocity.into();
+//! assert_eq!(Velocity::from(stable), velocity);
+//! ```
+//!
+//! ```compile_fail
+//! #![feature(adt_const_params)]
+//! use tiny_uom::unit_param::values::{m, s};
+//! use tiny_uom::unit_param::Quantity;
+//! use tiny_uom::Unit;
+//!
+//! let area: Quantity<{ Unit { m: 2, ..Unit::DIMENSIONLESS } }> = (2.0 * m).mul_quantity(3.0 * s);
+//! ```
+//!
+//! [proof-of-concept]: https://docs.rs/const_unit_poc
+
+#![allow(non_upper_case_globals)]
+
+use crate::{Dimensioned, ExponentCheck, Unit};
+use core::{fmt, marker::PhantomData};
+
+crate::quantity_impl!(f32, Quantity, Unit, U);
+
+impl<const U: Unit> crate::dimension::sealed::Sealed for Quantity<U> {}
+
+impl<const U: Unit> crate::Dimensioned for Quantity<U> {
+    type Scalar = f32;
+    type Exponents = [i8; 7];
+    type System = crate::Si;
+
+    const EXPONENTS: [i8; 7] = U.exponents();
+
+    #[inline]
+    fn from_raw(value: f32) -> Self {
+        Quantity::new(value)
+    }
+
+    #[inline]
+    fn raw(self) -> f32 {
+        self.value
+    }
+}
+
+/// The check of the exponents of the conversions between the quantities.
+///
+/// Like [`ExponentCheck`], this is an associated constant instead of an inline
+/// `const` block, so it also compiles when a dependent crate enables
+/// `generic_const_exprs`.
+struct DimensionCheck<L, R>(PhantomData<(L, R)>);
+
+impl<L: Dimensioned<Exponents = [i8; 7]>, R: Dimensioned<Exponents = [i8; 7]>>
+    DimensionCheck<L, R>
+{
+    const SAME: () = assert!(
+        crate::macro_support::same_dimension(&L::EXPONENTS, &R::EXPONENTS),
+        "the quantities have different dimensions"
+    );
+}
+
+impl<const U: Unit> Quantity<U> {
+    /// Multiply two quantities, adding their exponents.
+    ///
+    /// The unit of the result has to be written out, and a wrong unit fails to compile.
+    #[must_use]
+    #[inline]
+    pub fn mul_quantity<const R: Unit, const O: Unit>(self, rhs: Quantity<R>) -> Quantity<O> {
+        let () = ExponentCheck::<Self, Quantity<R>, Quantity<O>>::PRODUCT;
+        Quantity::new(self.value * rhs.value)
+    }
+
+    /// Divide two quantities, subtracting their exponents.
+    ///
+    /// The unit of the result has to be written out, and a wrong unit fails to compile.
+    #[must_use]
+    #[inline]
+    pub fn div_quantity<const R: Unit, const O: Unit>(self, rhs: Quantity<R>) -> Quantity<O> {
+        let () = ExponentCheck::<Self, Quantity<R>, Quantity<O>>::QUOTIENT;
+        Quantity::new(self.value / rhs.value)
+    }
+}
+
+impl<
+        const U: Unit,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > From<crate::Quantity<m, kg, s, A, K, mol, cd>> for Quantity<U>
+{
+    fn from(quantity: crate::Quantity<m, kg, s, A, K, mol, cd>) -> Self {
+        let () = DimensionCheck::<Quantity<U>, crate::Quantity<m, kg, s, A, K, mol, cd>>::SAME;
+        Quantity::new(quantity.value)
+    }
+}
+
+impl<
+        const U: Unit,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > From<Quantity<U>> for crate::Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn from(quantity: Quantity<U>) -> Self {
+        let () = DimensionCheck::<Quantity<U>, crate::Quantity<m, kg, s, A, K, mol, cd>>::SAME;
+        crate::Quantity::new(quantity.value)
+    }
+}
+
+impl<const U: Unit> fmt::Display for Quantity<U> {
+    /// Formats the value followed by the [`Unit`], like `9.81 m*kg/s^2`.
+    ///
+    /// The alternate flag (`{:#}`) uses the symbol of the named unit
+    /// from [`UnitInfo`](crate::UnitInfo) if there is one, like `9.81 N`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        match U.info() {
+            _ if U.is_dimensionless() => Ok(()),
+            Some(info) if f.alternate() => write!(f, " {}", info.symbol),
+            _ => write!(f, " {U}"),
+        }
+    }
+}
+
+/// Constants for the multiplicative identities of each unit
+pub mod values {
+    use super::Quantity;
+    use crate::Unit;
+
+    /// Time in seconds
+    pub const s: Quantity<
+        {
+            Unit {
+                s: 1,
+                ..Unit::DIMENSIONLESS
+            }
+        },
+    > = Quantity { value: 1.0 };
+    /// Length in metre
+    pub const m: Quantity<
+        {
+            Unit {
+                m: 1,
+                ..Unit::DIMENSIONLESS
+            }
+        },
+    > = Quantity { value: 1.0 };
+    /// Mass in kilogram
+    pub const kg: Quantity<
+        {
+            Unit {
+                kg: 1,
+                ..Unit::DIMENSIONLESS
+            }
+        },
+    > = Quantity { value: 1.0 };
+    /// Electric current in ampere
+    pub const A: Quantity<
+        {
+            Unit {
+                A: 1,
+                ..Unit::DIMENSIONLESS
+            }
+        },
+    > = Quantity { value: 1.0 };
+    /// Temperature in kelvin
+    pub const K: Quantity<
+        {
+            Unit {
+                K: 1,
+                ..Unit::DIMENSIONLESS
+            }
+        },
+    > = Quantity { value: 1.0 };
+    /// Amount of substance in mole
+    pub const mol: Quantity<
+        {
+            Unit {
+                mol: 1,
+                ..Unit::DIMENSIONLESS
+            }
+        },
+    > = Quantity { value: 1.0 };
+    /// Luminous intensity in candela
+    pub const cd: Quantity<
+        {
+            Unit {
+                cd: 1,
+                ..Unit::DIMENSIONLESS
+            }
+        },
+    > = Quantity { value: 1.0 };
+}
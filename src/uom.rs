@@ -0,0 +1,93 @@
+//! Conversions between quantities and the quantities of [`uom`].
+//!
+//! Every quantity can be converted into the `uom` quantity of the SI system
+//! with `f32` values and the same dimension, and back. Converting between
+//! quantities of different dimensions fails to compile.
+//!
+//! ```
+//! use tiny_uom::values::{m, s};
+//! use tiny_uom::Quantity;
+//! use uom::si::{f32::Velocity, velocity::kilometer_per_hour};
+//!
+//! let speed: Velocity = (10.0 * (m / s)).into();
+//! assert_eq!(speed.get::<kilometer_per_hour>(), 36.0);
+//!
+//! let speed: Quantity<1, 0, -1, 0, 0, 0, 0> = speed.into();
+//! assert_eq!(speed, 10.0 * (m / s));
+//! ```
+//!
+//! ```compile_fail
+//! use tiny_uom::values::m;
+//! use uom::si::f32::Time;
+//!
+//! let time: Time = (10.0 * m).into();
+//! ```
+//!
+//! [`uom`]: https://docs.rs/uom
+
+use crate::{macro_support, Quantity};
+use std::marker::PhantomData;
+use uom::si::{Dimension, SI};
+use uom::typenum::Integer;
+
+/// Returns the exponents of the `uom` dimension `D` in the order of the const
+/// parameters of [`Quantity`].
+const fn exponents<D: Dimension + ?Sized>() -> [i8; 7] {
+    [
+        D::L::I8,
+        D::M::I8,
+        D::T::I8,
+        D::I::I8,
+        D::Th::I8,
+        D::N::I8,
+        D::J::I8,
+    ]
+}
+
+impl<
+        D: Dimension + ?Sized,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > From<uom::si::Quantity<D, SI<f32>, f32>> for Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn from(quantity: uom::si::Quantity<D, SI<f32>, f32>) -> Self {
+        const {
+            assert!(
+                macro_support::same_dimension(&exponents::<D>(), &[m, kg, s, A, K, mol, cd]),
+                "the `uom` quantity has a different dimension"
+            );
+        }
+        Quantity::new(quantity.value)
+    }
+}
+
+impl<
+        D: Dimension + ?Sized,
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+    > From<Quantity<m, kg, s, A, K, mol, cd>> for uom::si::Quantity<D, SI<f32>, f32>
+{
+    fn from(quantity: Quantity<m, kg, s, A, K, mol, cd>) -> Self {
+        const {
+            assert!(
+                macro_support::same_dimension(&exponents::<D>(), &[m, kg, s, A, K, mol, cd]),
+                "the `uom` quantity has a different dimension"
+            );
+        }
+        uom::si::Quantity {
+            dimension: PhantomData,
+            units: PhantomData,
+            value: quantity.value,
+        }
+    }
+}
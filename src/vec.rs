@@ -0,0 +1,179 @@
+//! A growable array of quantities that stores their raw values.
+//!
+//! A [`QuantityVec`] keeps the unit at the level of the container and the
+//! values in a `Vec<f32>`, so millions of samples are stored compactly and
+//! can be processed in bulk, while every access is still typed.
+//!
+//! ```
+//! use tiny_uom::values::m;
+//! use tiny_uom::vec::QuantityVec;
+//!
+//! let mut offsets: QuantityVec<_> = [1.0 * m, 2.0 * m].into_iter().collect();
+//! offsets.push(3.0 * m);
+//! offsets.scale(2.0);
+//!
+//! let mut positions = QuantityVec::from_raw(vec![10.0, 10.0, 10.0]);
+//! positions += &offsets;
+//! assert_eq!(positions.get(2), Some(16.0 * m));
+//! assert_eq!(positions.as_raw(), [12.0, 14.0, 16.0]);
+//! ```
+
+use crate::Dimensioned;
+use core::marker::PhantomData;
+use core::ops::{AddAssign, SubAssign};
+
+/// A growable array of quantities of type `Q`, stored as their raw values.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QuantityVec<Q> {
+    values: Vec<f32>,
+    quantity: PhantomData<Q>,
+}
+
+impl<Q: Dimensioned<Scalar = f32>> QuantityVec<Q> {
+    /// Create an empty array.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self::from_raw(Vec::new())
+    }
+
+    /// Create an empty array with space for at least `capacity` quantities.
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_raw(Vec::with_capacity(capacity))
+    }
+
+    /// Create an array from the raw values of the quantities in SI units.
+    #[must_use]
+    #[inline]
+    pub const fn from_raw(values: Vec<f32>) -> Self {
+        Self {
+            values,
+            quantity: PhantomData,
+        }
+    }
+
+    /// Returns the raw values of the quantities in SI units.
+    #[must_use]
+    #[inline]
+    pub fn as_raw(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Returns the raw values of the quantities in SI units, for modifying them in bulk.
+    #[must_use]
+    #[inline]
+    pub fn as_raw_mut(&mut self) -> &mut [f32] {
+        &mut self.values
+    }
+
+    /// Convert this array into the raw values of the quantities in SI units.
+    #[must_use]
+    #[inline]
+    pub fn into_raw(self) -> Vec<f32> {
+        self.values
+    }
+
+    /// Returns the number of quantities.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if this array contains no quantities.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Append a quantity.
+    #[inline]
+    pub fn push(&mut self, quantity: Q) {
+        self.values.push(quantity.raw());
+    }
+
+    /// Remove the last quantity and return it, or `None` if the array is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<Q> {
+        self.values.pop().map(Q::from_raw)
+    }
+
+    /// Returns the quantity at `idx`, or `None` if it is out of bounds.
+    #[must_use]
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<Q> {
+        self.values.get(idx).copied().map(Q::from_raw)
+    }
+
+    /// Replace the quantity at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, idx: usize, quantity: Q) {
+        self.values[idx] = quantity.raw();
+    }
+
+    /// Returns an iterator over the quantities.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Q> + ExactSizeIterator + '_ {
+        self.values.iter().copied().map(Q::from_raw)
+    }
+
+    /// Multiply all quantities with a number.
+    pub fn scale(&mut self, factor: f32) {
+        self.values.iter_mut().for_each(|value| *value *= factor);
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> AddAssign<&QuantityVec<Q>> for QuantityVec<Q> {
+    /// Add the quantities of `rhs` to the quantities at the same index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths.
+    fn add_assign(&mut self, rhs: &QuantityVec<Q>) {
+        assert_eq!(
+            self.len(),
+            rhs.len(),
+            "the arrays must have the same length"
+        );
+        for (value, rhs) in self.values.iter_mut().zip(&rhs.values) {
+            *value += rhs;
+        }
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> SubAssign<&QuantityVec<Q>> for QuantityVec<Q> {
+    /// Subtract the quantities of `rhs` from the quantities at the same index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths.
+    fn sub_assign(&mut self, rhs: &QuantityVec<Q>) {
+        assert_eq!(
+            self.len(),
+            rhs.len(),
+            "the arrays must have the same length"
+        );
+        for (value, rhs) in self.values.iter_mut().zip(&rhs.values) {
+            *value -= rhs;
+        }
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> Extend<Q> for QuantityVec<Q> {
+    fn extend<I: IntoIterator<Item = Q>>(&mut self, quantities: I) {
+        self.values
+            .extend(quantities.into_iter().map(Dimensioned::raw));
+    }
+}
+
+impl<Q: Dimensioned<Scalar = f32>> FromIterator<Q> for QuantityVec<Q> {
+    fn from_iter<I: IntoIterator<Item = Q>>(quantities: I) -> Self {
+        Self::from_raw(quantities.into_iter().map(Dimensioned::raw).collect())
+    }
+}
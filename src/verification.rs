@@ -0,0 +1,160 @@
+//! [Kani](https://model-checking.github.io/kani/) proofs of the invariants of the
+//! arithmetic on quantities and units.
+//!
+//! Run them using `cargo kani`.
+
+use crate::{Quantity, Unit};
+
+type Length = Quantity<1, 0, 0, 0, 0, 0, 0>;
+type Time = Quantity<0, 0, 1, 0, 0, 0, 0>;
+
+/// Returns the [`Unit`] of the type of a quantity.
+fn unit_of<
+    const m: i8,
+    const kg: i8,
+    const s: i8,
+    const A: i8,
+    const K: i8,
+    const mol: i8,
+    const cd: i8,
+>(
+    _: Quantity<m, kg, s, A, K, mol, cd>,
+) -> Unit {
+    Quantity::<m, kg, s, A, K, mol, cd>::UNIT
+}
+
+fn any_unit() -> Unit {
+    Unit::from_exponents(kani::any())
+}
+
+#[kani::proof]
+fn same_unit_operations_keep_dimension_and_value() {
+    let (a, b): (f32, f32) = (kani::any(), kani::any());
+    let (x, y) = (Length::new(a), Length::new(b));
+
+    let results = [x + y, x - y, x % y, -x, x * b, b * x, x / b];
+    let expected = [a + b, a - b, a % b, -a, a * b, b * a, a / b];
+    for (result, expected) in results.into_iter().zip(expected) {
+        assert_eq!(unit_of(result), Length::UNIT);
+        assert_eq!(result.value.to_bits(), expected.to_bits());
+    }
+}
+
+#[kani::proof]
+fn division_subtracts_exponents() {
+    let velocity = Length::new(kani::any()) / Time::new(kani::any());
+    let expected = Length::UNIT.checked_mul(Time::UNIT.checked_powi(-1).unwrap());
+    assert_eq!(Some(unit_of(velocity)), expected);
+}
+
+#[kani::proof]
+#[kani::unwind(8)]
+fn mul_adds_every_exponent() {
+    let (a, b) = (any_unit(), any_unit());
+    let (x, y) = (a.exponents(), b.exponents());
+
+    match a.checked_mul(b) {
+        Some(product) => {
+            for i in 0..7 {
+                assert_eq!(
+                    i16::from(product.exponents()[i]),
+                    i16::from(x[i]) + i16::from(y[i])
+                );
+            }
+        }
+        None => assert!((0..7).any(|i| x[i].checked_add(y[i]).is_none())),
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(8)]
+fn recip_negates_every_exponent() {
+    let unit = any_unit();
+
+    match unit.checked_powi(-1) {
+        Some(recip) => {
+            for (exp, neg) in unit.exponents().into_iter().zip(recip.exponents()) {
+                assert_eq!(neg, -exp);
+            }
+            assert_eq!(unit.checked_mul(recip), Some(Unit::DIMENSIONLESS));
+        }
+        None => assert!(unit.exponents().contains(&i8::MIN)),
+    }
+}
+
+/// Symbols and factors of the lengths in the registry of [`registry_conversions_round_trip`].
+#[cfg(feature = "std")]
+const REGISTRY_LENGTHS: [(&str, f64); 4] = [
+    ("m", 1.0),
+    ("in", 0.0254),
+    ("mi", 1_609.344),
+    ("nmi", 1_852.0),
+];
+
+#[cfg(feature = "std")]
+#[kani::proof]
+#[kani::unwind(8)]
+fn registry_conversions_round_trip() {
+    use crate::registry::{UnitDef, UnitRegistry};
+
+    // a registry with few and short symbols, which bound the loops of the parser
+    let mut registry = UnitRegistry::empty();
+    for (symbol, factor) in REGISTRY_LENGTHS {
+        registry
+            .register(UnitDef {
+                symbol: symbol.to_string(),
+                factor,
+                dimension: Length::UNIT,
+                aliases: Vec::new(),
+                prefixes: false,
+            })
+            .unwrap();
+    }
+
+    let value: f64 = kani::any();
+    kani::assume(value.abs() >= 1e-100 && value.abs() <= 1e100);
+    let (from, to): (usize, usize) = (kani::any(), kani::any());
+    kani::assume(from < REGISTRY_LENGTHS.len() && to < REGISTRY_LENGTHS.len());
+    let (from, to) = (REGISTRY_LENGTHS[from].0, REGISTRY_LENGTHS[to].0);
+
+    let converted = registry.convert(value, from, to).unwrap();
+    let back = registry.convert(converted, to, from).unwrap();
+
+    // each conversion rounds twice in `f64`, by at most half an epsilon
+    assert!((back - value).abs() <= 4.0 * f64::EPSILON * value.abs());
+}
+
+/// UCUM codes of lengths that are not the metre.
+#[cfg(feature = "ucum")]
+const UCUM_LENGTHS: [&str; 5] = ["km", "[in_i]", "[ft_i]", "[mi_i]", "[nmi_i]"];
+
+#[cfg(feature = "ucum")]
+#[kani::proof]
+fn ucum_conversions_round_trip() {
+    let value: f32 = kani::any();
+    // values whose conversions into every code stay normal `f32`
+    kani::assume(value.abs() >= 1e-30 && value.abs() <= 1e30);
+    let idx: usize = kani::any();
+    kani::assume(idx < UCUM_LENGTHS.len());
+    let code = UCUM_LENGTHS[idx];
+
+    let converted = Length::new(value).to_ucum(code).unwrap();
+    let back = Length::from_ucum(converted, code).unwrap();
+
+    // each narrowing rounds by at most half an ulp, the `f64` arithmetic by far less
+    let error = (f64::from(back.value) - f64::from(value)).abs();
+    assert!(error <= 2.0 * f64::from(f32::EPSILON) * f64::from(value.abs()));
+}
+
+#[kani::proof]
+fn bytes_round_trip() {
+    let length = Length::new(kani::any());
+    assert_eq!(
+        Length::from_le_bytes(length.to_le_bytes()).value.to_bits(),
+        length.value.to_bits()
+    );
+    assert_eq!(
+        Length::from_be_bytes(length.to_be_bytes()).value.to_bits(),
+        length.value.to_bits()
+    );
+}
@@ -0,0 +1,156 @@
+//! JavaScript classes for common quantities, using [`wasm-bindgen`].
+//!
+//! Every class wraps a [`Quantity`] and can only be created from a value in
+//! one of its units, like `Length.fromKm(1.5)`, and read back in one of them,
+//! like `length.m`. Values handed from a Rust core to the JS side keep their
+//! dimension, so a `Time` cannot be passed where a `Length` is expected.
+//!
+//! ```text
+//! import { Length, Time } from "./pkg/tiny_uom.js";
+//!
+//! const distance = Length.fromKm(1.5).add(Length.fromM(20));
+//! console.log(distance.m, distance.toString()); // 1520 "1520 m"
+//! ```
+//!
+//! On the Rust side the classes convert from and into their quantities:
+//!
+//! ```
+//! use tiny_uom::values::m;
+//! use tiny_uom::wasm::Length;
+//!
+//! let length = Length::from(1520.0 * m);
+//! assert_eq!(length.km(), 1.52);
+//! assert_eq!(tiny_uom::Quantity::from(Length::from_km(1.52)), 1520.0 * m);
+//! ```
+//!
+//! [`wasm-bindgen`]: https://docs.rs/wasm-bindgen
+
+use crate::Quantity;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Define a class for a quantity, its `From` conversions
+/// and the constructors and getters for the given units.
+macro_rules! wasm_quantity {
+    (
+        $(#[$doc:meta])*
+        $name:ident($quantity:ty) {
+            $($unit:literal => $factor:literal: $from:ident($js_from:literal), $to:ident($js_to:literal);)+
+        }
+    ) => {
+        $(#[$doc])*
+        #[wasm_bindgen]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        pub struct $name($quantity);
+
+        impl From<$quantity> for $name {
+            fn from(quantity: $quantity) -> Self {
+                Self(quantity)
+            }
+        }
+
+        impl From<$name> for $quantity {
+            fn from(quantity: $name) -> Self {
+                quantity.0
+            }
+        }
+
+        #[wasm_bindgen]
+        impl $name {
+            $(
+                #[doc = concat!(" Create a `", stringify!($name), "` from a value in `", $unit, "`.")]
+                #[wasm_bindgen(js_name = $js_from)]
+                #[must_use]
+                pub fn $from(value: f32) -> $name {
+                    $name(Quantity::new(value * $factor))
+                }
+
+                #[doc = concat!(" Returns the value in `", $unit, "`.")]
+                #[wasm_bindgen(getter, js_name = $js_to)]
+                #[must_use]
+                pub fn $to(&self) -> f32 {
+                    self.0.value / $factor
+                }
+            )+
+
+            /// Add two values of the same quantity.
+            #[must_use]
+            pub fn add(&self, other: &$name) -> $name {
+                $name(self.0 + other.0)
+            }
+
+            /// Subtract two values of the same quantity.
+            #[must_use]
+            pub fn sub(&self, other: &$name) -> $name {
+                $name(self.0 - other.0)
+            }
+
+            /// Returns the value in SI units with the symbol of the unit, like `1520 m`.
+            #[wasm_bindgen(js_name = toString)]
+            #[must_use]
+            pub fn to_js_string(&self) -> String {
+                self.0.to_string()
+            }
+        }
+    };
+}
+
+wasm_quantity! {
+    /// A length.
+    Length(Quantity<1, 0, 0, 0, 0, 0, 0>) {
+        "m" => 1.0: from_m("fromM"), m("m");
+        "km" => 1000.0: from_km("fromKm"), km("km");
+        "mm" => 0.001: from_mm("fromMm"), mm("mm");
+    }
+}
+
+wasm_quantity! {
+    /// A time.
+    Time(Quantity<0, 0, 1, 0, 0, 0, 0>) {
+        "s" => 1.0: from_s("fromS"), s("s");
+        "ms" => 0.001: from_ms("fromMs"), ms("ms");
+        "h" => 3600.0: from_h("fromH"), h("h");
+    }
+}
+
+wasm_quantity! {
+    /// A mass.
+    Mass(Quantity<0, 1, 0, 0, 0, 0, 0>) {
+        "kg" => 1.0: from_kg("fromKg"), kg("kg");
+        "g" => 0.001: from_g("fromG"), g("g");
+    }
+}
+
+wasm_quantity! {
+    /// A velocity.
+    Velocity(Quantity<1, 0, -1, 0, 0, 0, 0>) {
+        "m/s" => 1.0: from_m_per_s("fromMPerS"), m_per_s("mPerS");
+        "km/h" => 0.277_777_8: from_km_per_h("fromKmPerH"), km_per_h("kmPerH");
+    }
+}
+
+wasm_quantity! {
+    /// A thermodynamic temperature.
+    Temperature(Quantity<0, 0, 0, 0, 1, 0, 0>) {
+        "K" => 1.0: from_k("fromK"), k("K");
+    }
+}
+
+/// The temperature in kelvin at zero degrees Celsius.
+const ZERO_CELSIUS: f32 = 273.15;
+
+#[wasm_bindgen]
+impl Temperature {
+    /// Create a `Temperature` from a value in degrees Celsius.
+    #[wasm_bindgen(js_name = fromCelsius)]
+    #[must_use]
+    pub fn from_celsius(value: f32) -> Temperature {
+        Temperature(Quantity::new(value + ZERO_CELSIUS))
+    }
+
+    /// Returns the value in degrees Celsius.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn celsius(&self) -> f32 {
+        self.0.value - ZERO_CELSIUS
+    }
+}
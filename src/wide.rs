@@ -0,0 +1,301 @@
+//! Quantities with `i16` exponents.
+//!
+//! The exponents of [`Quantity`](crate::Quantity) are `i8`, which is not
+//! enough for some derived quantities of signal processing or statistics that
+//! are squared repeatedly. The [`Quantity`] of this module has the same base
+//! units with exponents from `-32768` to `32767`.
+//!
+//! Quantities convert from and into the [`Quantity`](crate::Quantity) with
+//! the same exponents. Converting quantities of different dimensions,
+//! or with an exponent that does not fit into an `i8`, fails to compile.
+//!
+//! ```
+//! use tiny_uom::wide::{values::s, Quantity};
+//!
+//! let variance = Quantity::<0, 0, 256, 0, 0, 0, 0>::new(2.0);
+//! assert_eq!(variance.to_string(), "2 s^256");
+//!
+//! let time: tiny_uom::Quantity<0, 0, 1, 0, 0, 0, 0> = (3.0 * s).into();
+//! assert_eq!(time, 3.0 * tiny_uom::values::s);
+//! assert_eq!(Quantity::from(time), 3.0 * s);
+//! ```
+//!
+//! ```compile_fail
+//! use tiny_uom::wide::Quantity;
+//!
+//! let time: tiny_uom::Quantity<0, 0, 1, 0, 0, 0, 0> = Quantity::<0, 0, 256, 0, 0, 0, 0>::new(2.0).into();
+//! ```
+
+#![allow(non_upper_case_globals)]
+
+use crate::{macro_support, Dimensioned, Si};
+use core::fmt;
+use core::marker::PhantomData;
+
+crate::quantity_impl!(f32, Quantity, i16, m, kg, s, A, K, mol, cd);
+
+impl<
+        const m: i16,
+        const kg: i16,
+        const s: i16,
+        const A: i16,
+        const K: i16,
+        const mol: i16,
+        const cd: i16,
+    > Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// The exponents of the base units of this `Quantity`.
+    pub const EXPONENTS: [i16; 7] = [m, kg, s, A, K, mol, cd];
+
+    /// Multiply two quantities, adding their exponents.
+    ///
+    /// The type of the result has to be written out. A type with the wrong
+    /// exponents fails to compile, and so do exponents that overflow an `i16`
+    /// instead of wrapping around.
+    ///
+    /// ```
+    /// use tiny_uom::wide::Quantity;
+    ///
+    /// let variance = Quantity::<0, 0, 200, 0, 0, 0, 0>::new(2.0);
+    /// let squared: Quantity<0, 0, 400, 0, 0, 0, 0> = variance.mul_quantity(variance);
+    /// assert_eq!(squared.raw(), 4.0);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use tiny_uom::wide::Quantity;
+    ///
+    /// let variance = Quantity::<0, 0, 200, 0, 0, 0, 0>::new(2.0);
+    /// let squared: Quantity<0, 0, 200, 0, 0, 0, 0> = variance.mul_quantity(variance);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mul_quantity<
+        R: Dimensioned<Scalar = f32, Exponents = [i16; 7], System = Si>,
+        O: Dimensioned<Scalar = f32, Exponents = [i16; 7], System = Si>,
+    >(
+        self,
+        rhs: R,
+    ) -> O {
+        let () = ExponentCheck::<Self, R, O>::PRODUCT;
+        O::from_raw(self.value * rhs.raw())
+    }
+
+    /// Divide two quantities, subtracting their exponents.
+    ///
+    /// Like for [`mul_quantity`](Self::mul_quantity), the type of the result
+    /// has to be written out, and exponents that overflow fail to compile.
+    ///
+    /// ```compile_fail
+    /// use tiny_uom::wide::Quantity;
+    ///
+    /// let tiny = Quantity::<0, 0, -30000, 0, 0, 0, 0>::new(1.0);
+    /// let big = Quantity::<0, 0, 30000, 0, 0, 0, 0>::new(1.0);
+    /// let wrapped: Quantity<0, 0, 5536, 0, 0, 0, 0> = tiny.div_quantity(big);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn div_quantity<
+        R: Dimensioned<Scalar = f32, Exponents = [i16; 7], System = Si>,
+        O: Dimensioned<Scalar = f32, Exponents = [i16; 7], System = Si>,
+    >(
+        self,
+        rhs: R,
+    ) -> O {
+        let () = ExponentCheck::<Self, R, O>::QUOTIENT;
+        O::from_raw(self.value / rhs.raw())
+    }
+}
+
+/// The checks of the exponents of the results of `mul_quantity` and
+/// `div_quantity`, like the [`ExponentCheck`](crate::ExponentCheck) of the
+/// `i8` exponents.
+struct ExponentCheck<L, R, O>(PhantomData<(L, R, O)>);
+
+impl<L, R, O> ExponentCheck<L, R, O>
+where
+    L: Dimensioned<Exponents = [i16; 7], System = Si>,
+    R: Dimensioned<Exponents = [i16; 7], System = Si>,
+    O: Dimensioned<Exponents = [i16; 7], System = Si>,
+{
+    const PRODUCT: () = {
+        assert!(
+            sum_in_range(L::EXPONENTS, R::EXPONENTS, 1),
+            "the exponents of the result overflow an `i16`"
+        );
+        assert!(
+            is_sum(O::EXPONENTS, L::EXPONENTS, R::EXPONENTS, 1),
+            "the exponents of the result must be the sum of the exponents"
+        );
+    };
+
+    const QUOTIENT: () = {
+        assert!(
+            sum_in_range(L::EXPONENTS, R::EXPONENTS, -1),
+            "the exponents of the result overflow an `i16`"
+        );
+        assert!(
+            is_sum(O::EXPONENTS, L::EXPONENTS, R::EXPONENTS, -1),
+            "the exponents of the result must be the difference of the exponents"
+        );
+    };
+}
+
+/// Returns `true` if every sum of `lhs` and `factor` times `rhs` fits into an `i16`.
+const fn sum_in_range(lhs: [i16; 7], rhs: [i16; 7], factor: i32) -> bool {
+    let mut i = 0;
+    while i < 7 {
+        let sum = lhs[i] as i32 + factor * rhs[i] as i32;
+        if sum < i16::MIN as i32 || sum > i16::MAX as i32 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if `out` is the sum of `lhs` and `factor` times `rhs`.
+const fn is_sum(out: [i16; 7], lhs: [i16; 7], rhs: [i16; 7], factor: i32) -> bool {
+    let mut i = 0;
+    while i < 7 {
+        if out[i] as i32 != lhs[i] as i32 + factor * rhs[i] as i32 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl<
+        const m: i16,
+        const kg: i16,
+        const s: i16,
+        const A: i16,
+        const K: i16,
+        const mol: i16,
+        const cd: i16,
+    > crate::dimension::sealed::Sealed for Quantity<m, kg, s, A, K, mol, cd>
+{
+}
+
+impl<
+        const m: i16,
+        const kg: i16,
+        const s: i16,
+        const A: i16,
+        const K: i16,
+        const mol: i16,
+        const cd: i16,
+    > Dimensioned for Quantity<m, kg, s, A, K, mol, cd>
+{
+    type Scalar = f32;
+    type Exponents = [i16; 7];
+    type System = Si;
+
+    const EXPONENTS: [i16; 7] = [m, kg, s, A, K, mol, cd];
+
+    #[inline]
+    fn from_raw(value: f32) -> Self {
+        Quantity::new(value)
+    }
+
+    #[inline]
+    fn raw(self) -> f32 {
+        self.value
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+        const wm: i16,
+        const wkg: i16,
+        const ws: i16,
+        const wA: i16,
+        const wK: i16,
+        const wmol: i16,
+        const wcd: i16,
+    > From<crate::Quantity<m, kg, s, A, K, mol, cd>> for Quantity<wm, wkg, ws, wA, wK, wmol, wcd>
+{
+    fn from(quantity: crate::Quantity<m, kg, s, A, K, mol, cd>) -> Self {
+        const {
+            assert!(
+                macro_support::same_wide_dimension(&[m, kg, s, A, K, mol, cd], &Self::EXPONENTS),
+                "the quantities have different dimensions"
+            );
+        }
+        Quantity::new(quantity.value)
+    }
+}
+
+impl<
+        const m: i8,
+        const kg: i8,
+        const s: i8,
+        const A: i8,
+        const K: i8,
+        const mol: i8,
+        const cd: i8,
+        const wm: i16,
+        const wkg: i16,
+        const ws: i16,
+        const wA: i16,
+        const wK: i16,
+        const wmol: i16,
+        const wcd: i16,
+    > From<Quantity<wm, wkg, ws, wA, wK, wmol, wcd>> for crate::Quantity<m, kg, s, A, K, mol, cd>
+{
+    fn from(quantity: Quantity<wm, wkg, ws, wA, wK, wmol, wcd>) -> Self {
+        const {
+            assert!(
+                macro_support::same_wide_dimension(
+                    &[m, kg, s, A, K, mol, cd],
+                    &Quantity::<wm, wkg, ws, wA, wK, wmol, wcd>::EXPONENTS
+                ),
+                "the quantities have different dimensions"
+            );
+        }
+        crate::Quantity::new(quantity.value)
+    }
+}
+
+impl<
+        const m: i16,
+        const kg: i16,
+        const s: i16,
+        const A: i16,
+        const K: i16,
+        const mol: i16,
+        const cd: i16,
+    > fmt::Display for Quantity<m, kg, s, A, K, mol, cd>
+{
+    /// Formats the value followed by the symbols of the base units, like `2 s^256`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        crate::macro_support::write_unit(f, &crate::SYMBOLS, &Self::EXPONENTS.map(i32::from))
+    }
+}
+
+/// Constants for the multiplicative identities of each unit
+pub mod values {
+    use super::Quantity;
+
+    /// Time in seconds
+    pub const s: Quantity<0, 0, 1, 0, 0, 0, 0> = Quantity { value: 1.0 };
+    /// Length in metre
+    pub const m: Quantity<1, 0, 0, 0, 0, 0, 0> = Quantity { value: 1.0 };
+    /// Mass in kilogram
+    pub const kg: Quantity<0, 1, 0, 0, 0, 0, 0> = Quantity { value: 1.0 };
+    /// Electric current in ampere
+    pub const A: Quantity<0, 0, 0, 1, 0, 0, 0> = Quantity { value: 1.0 };
+    /// Temperature in kelvin
+    pub const K: Quantity<0, 0, 0, 0, 1, 0, 0> = Quantity { value: 1.0 };
+    /// Amount of substance in mole
+    pub const mol: Quantity<0, 0, 0, 0, 0, 1, 0> = Quantity { value: 1.0 };
+    /// Luminous intensity in candela
+    pub const cd: Quantity<0, 0, 0, 0, 0, 0, 1> = Quantity { value: 1.0 };
+}
@@ -0,0 +1,102 @@
+//! Checks that quantity arithmetic compiles to the same code as raw `f32`.
+//!
+//! The functions in `examples/codegen.rs` are compiled to LLVM IR in release
+//! mode. Every `quantity_*` function must either be merged into its `f32_*`
+//! twin by LLVM, or have a body that is identical to it.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Command;
+
+/// Returns the normalized bodies of the `#[no_mangle]` functions, and the aliases LLVM created.
+fn functions(ir: &str) -> (BTreeMap<String, Vec<String>>, BTreeMap<String, String>) {
+    let mut bodies = BTreeMap::new();
+    let mut aliases = BTreeMap::new();
+    let mut lines = ir.lines();
+
+    while let Some(line) = lines.next() {
+        if let Some(rest) = line.strip_prefix('@') {
+            if let Some((name, target)) = rest.split_once(" = ") {
+                if target.contains(" alias ") {
+                    let target = target.rsplit('@').next().unwrap_or_default();
+                    aliases.insert(name.to_string(), target.trim().to_string());
+                }
+            }
+        } else if line.starts_with("define ") {
+            let Some(name) = line
+                .split('@')
+                .nth(1)
+                .and_then(|rest| rest.split('(').next())
+            else {
+                continue;
+            };
+            let body = lines
+                .by_ref()
+                .take_while(|line| *line != "}")
+                .map(normalize)
+                .collect();
+            bodies.insert(name.to_string(), body);
+        }
+    }
+
+    (bodies, aliases)
+}
+
+/// Remove the metadata and attribute group references, which are numbered per module.
+fn normalize(line: &str) -> String {
+    line.split(", !")
+        .next()
+        .unwrap_or_default()
+        .split(" #")
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[test]
+fn quantity_arithmetic_compiles_like_f32() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("codegen");
+    let ir_path = out_dir.join("codegen.ll");
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    let status = Command::new(cargo)
+        .arg("rustc")
+        .arg("--quiet")
+        .arg("--release")
+        .args(["--example", "codegen"])
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .arg("--target-dir")
+        .arg(out_dir.join("target"))
+        .args(["--", "-C", "codegen-units=1", "--emit"])
+        .arg(format!("llvm-ir={}", ir_path.display()))
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "failed to compile examples/codegen.rs");
+
+    let ir = std::fs::read_to_string(&ir_path).expect("no LLVM IR was emitted");
+    let (bodies, aliases) = functions(&ir);
+
+    let mut checked = 0;
+    for name in bodies.keys().chain(aliases.keys()) {
+        let Some(op) = name.strip_prefix("quantity_") else {
+            continue;
+        };
+        let twin = format!("f32_{op}");
+        checked += 1;
+
+        if aliases.get(name) == Some(&twin) {
+            continue;
+        }
+        assert_eq!(
+            bodies.get(name),
+            bodies.get(&twin),
+            "`{name}` does not compile to the same code as `{twin}`"
+        );
+    }
+    assert!(
+        checked >= 7,
+        "only {checked} functions were found in the LLVM IR"
+    );
+}
@@ -0,0 +1,60 @@
+//! Checks the error messages of quantities with mismatched dimensions.
+//!
+//! A crate that adds two such quantities is written to a temporary directory
+//! and built, and its errors must name both dimensions.
+
+use std::path::Path;
+use std::process::Command;
+
+/// Builds a crate with `main` as its `main.rs` and returns its errors.
+fn build_errors(name: &str, main: &str) -> String {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("diagnostics");
+    let crate_dir = dir.join(name);
+    std::fs::create_dir_all(crate_dir.join("src")).expect("failed to create the crate");
+
+    let manifest = format!(
+        "[package]\nname = \"{name}\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+         [dependencies]\ntiny-uom = {{ path = {:?} }}\n\n[workspace]\n",
+        env!("CARGO_MANIFEST_DIR"),
+    );
+    std::fs::write(crate_dir.join("Cargo.toml"), manifest).expect("failed to write Cargo.toml");
+    std::fs::write(crate_dir.join("src/main.rs"), main).expect("failed to write main.rs");
+
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .arg("build")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(crate_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(dir.join("target"))
+        .env("CARGO_TERM_COLOR", "never")
+        .output()
+        .expect("failed to run cargo");
+    assert!(!output.status.success(), "{name} compiled");
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn mismatched_dimensions_are_named() {
+    let errors = build_errors(
+        "mismatched_dimensions",
+        "use tiny_uom::values::{m, s};\n\
+         use tiny_uom::Quantity;\n\
+         \n\
+         fn main() {\n\
+         \x20   let _ = 2.0 * m + 3.0 * s;\n\
+         \x20   let _ = Quantity::<1, 0, -2, 0, 0, 0, 0>::new(1.0) - Quantity::<2, 0, 0, -1, 0, 0, 0>::new(1.0);\n\
+         }\n",
+    );
+
+    for expected in [
+        "expected Length (m), found Time (s)",
+        "expected m/s^2, found m^2/A",
+    ] {
+        assert!(
+            errors.contains(expected),
+            "missing `{expected}` in:\n{errors}"
+        );
+    }
+}